@@ -0,0 +1,62 @@
+use day_count_conventions::Actual365Fixed;
+
+use itolib::{
+    currency::USD,
+    instruments::{EuropeanExercise, EuropeanOption, OptionType, VanillaPayoff},
+    models::HestonModel,
+    pricers::{AnalyticBlackScholesMerton, AnalyticHeston, Pricer},
+    term_structures::{ConstantVolTermStructureBuilder, FlatForwardTermStructureBuilder},
+    time::{DateTime, Duration},
+    Compounding, InterestRate, Money,
+};
+
+fn main() {
+    let reference_date = DateTime::now();
+    let exercise = EuropeanExercise::new(reference_date + Duration::new_from_days(365.0));
+
+    let underlying_spot_price: Money<USD> = Money::new(100.0);
+
+    let mut calls = Vec::new();
+    for strike in (70..=130).step_by(10) {
+        let payoff = VanillaPayoff::new(Money::new(f64::from(strike)), OptionType::CALL);
+        calls.push(EuropeanOption::new(payoff, exercise));
+    }
+
+    let spot_rate: InterestRate<USD, Actual365Fixed> =
+        InterestRate::new(0.05, Actual365Fixed, Compounding::Continuous);
+    let dividend_rate: InterestRate<USD, Actual365Fixed> =
+        InterestRate::new(0.0, Actual365Fixed, Compounding::Continuous);
+
+    let yield_curve = FlatForwardTermStructureBuilder::new()
+        .reference_date(reference_date)
+        .rate(spot_rate)
+        .build()
+        .unwrap();
+    let dividend_curve = FlatForwardTermStructureBuilder::new()
+        .reference_date(reference_date)
+        .rate(dividend_rate)
+        .build()
+        .unwrap();
+
+    // Flat volatility at the Heston long-run level, for comparison.
+    let vol_curve = ConstantVolTermStructureBuilder::new()
+        .reference_date(reference_date)
+        .volatility(0.2)
+        .build()
+        .unwrap();
+
+    let model = HestonModel::new(0.04, 1.5, 0.04, 0.5, -0.7);
+
+    let heston_pricer =
+        AnalyticHeston::new(underlying_spot_price, &model, &yield_curve, &dividend_curve);
+    let bsm_pricer =
+        AnalyticBlackScholesMerton::new(underlying_spot_price, &vol_curve, &yield_curve);
+
+    for ((call, heston_price), bsm_price) in calls
+        .iter()
+        .zip(heston_pricer.price_vec(&calls))
+        .zip(bsm_pricer.price_vec(&calls))
+    {
+        println!("{call}: Heston {heston_price}, Black-Scholes {bsm_price}");
+    }
+}
@@ -0,0 +1,469 @@
+use day_count_conventions::DayCounter;
+use num::Zero;
+
+use crate::cashflows::{year_fraction, CashFlow};
+use crate::compounding::{Compounding, CompoundingRegime};
+use crate::interest_rate::InterestRate;
+use crate::math::solvers::{Brent, SolverError};
+use crate::money::{Currency, Money};
+use crate::term_structures::{TermStructureError, YieldTermStructure, ZeroSpreadedTermStructure};
+use crate::time::DateTime;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// One basis point.
+const BASIS_POINT: f64 = 1e-4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CashFlowAnalyticsError {
+    /// No cash flows are paid after the settlement date.
+    NoCashFlows,
+
+    /// The yield or spread could not be solved for.
+    Solver(SolverError),
+
+    /// The curve could not discount the cash flows.
+    TermStructure(TermStructureError),
+}
+
+/// Cash flows of a leg paid after the settlement date.
+fn pending<'a, C>(
+    leg: &'a [Box<dyn CashFlow<C> + '_>],
+    settlement_date: DateTime,
+) -> impl Iterator<Item = &'a (dyn CashFlow<C> + 'a)>
+where
+    C: Currency,
+{
+    leg.iter()
+        .map(AsRef::as_ref)
+        .filter(move |cash_flow| cash_flow.payment_date() > settlement_date)
+}
+
+/// Present value of the cash flows paid after the settlement date, discounted on a curve to its
+/// reference date.
+///
+/// # Errors
+/// Will return a `TermStructureError` if the curve cannot discount to a payment date.
+pub fn npv<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    curve: &dyn YieldTermStructure<C, D>,
+    settlement_date: DateTime,
+) -> Result<Money<C>, TermStructureError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    pending(leg, settlement_date).try_fold(Money::zero(), |npv, cash_flow| {
+        Ok(npv + cash_flow.amount() * *curve.discount_factor(cash_flow.payment_date())?)
+    })
+}
+
+/// Basis point sensitivity: the change in present value of the coupons paid after the
+/// settlement date for a 1bp increase of their rates, $\sum_i N_i \tau_i D(t_i) \times 10^{-4}$.
+///
+/// # Errors
+/// Will return a `TermStructureError` if the curve cannot discount to a payment date.
+pub fn bps<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    curve: &dyn YieldTermStructure<C, D>,
+    settlement_date: DateTime,
+) -> Result<Money<C>, TermStructureError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    pending(leg, settlement_date)
+        .filter_map(CashFlow::as_coupon)
+        .try_fold(Money::zero(), |bps, coupon| {
+            let discount_factor = *curve.discount_factor(coupon.payment_date())?;
+            Ok(bps + coupon.nominal() * (coupon.accrual_period() * discount_factor * BASIS_POINT))
+        })
+}
+
+/// Interest accrued at a date on the coupons paid after it.
+#[must_use]
+pub fn accrued_amount<C>(leg: &[Box<dyn CashFlow<C> + '_>], date: DateTime) -> Money<C>
+where
+    C: Currency,
+{
+    pending(leg, date)
+        .filter_map(CashFlow::as_coupon)
+        .fold(Money::zero(), |accrued, coupon| {
+            accrued + coupon.accrued_amount(date)
+        })
+}
+
+/// Value at the settlement date of the cash flows paid after it, discounted at a flat yield.
+#[must_use]
+pub fn npv_from_yield<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    yield_rate: &InterestRate<C, D>,
+    settlement_date: DateTime,
+) -> Money<C>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    pending(leg, settlement_date).fold(Money::zero(), |npv, cash_flow| {
+        npv + cash_flow.amount()
+            * *yield_rate.discount_factor_between(settlement_date, cash_flow.payment_date())
+    })
+}
+
+/// Flat yield, in the given day count convention and compounding, at which the cash flows paid
+/// after the settlement date are worth `npv` at the settlement date.
+///
+/// # Errors
+/// Will return `NoCashFlows` if no cash flows are paid after the settlement date, and `Solver`
+/// if no yield between -50% and 100% matches the value.
+pub fn yield_rate<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    npv: Money<C>,
+    day_counter: D,
+    compounding: Compounding,
+    settlement_date: DateTime,
+) -> Result<InterestRate<C, D>, CashFlowAnalyticsError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    if pending(leg, settlement_date).next().is_none() {
+        return Err(CashFlowAnalyticsError::NoCashFlows);
+    }
+
+    let objective = |rate: f64| {
+        let yield_rate = InterestRate::new(rate, day_counter, compounding);
+        *npv_from_yield(leg, &yield_rate, settlement_date).amount() - *npv.amount()
+    };
+    let rate = Brent::default()
+        .solve(objective, -0.5, 1.0)
+        .map_err(CashFlowAnalyticsError::Solver)?;
+    Ok(InterestRate::new(rate, day_counter, compounding))
+}
+
+/// Parallel spread over the zero rates of a curve, in the given compounding, at which the cash
+/// flows paid after the settlement date are worth `npv` at the settlement date.
+///
+/// # Errors
+/// Will return `NoCashFlows` if no cash flows are paid after the settlement date, `TermStructure`
+/// if the curve cannot discount to the settlement date or a payment date, and `Solver` if no
+/// spread between -50% and 100% matches the value.
+pub fn spread_to_curve<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    npv: Money<C>,
+    curve: &dyn YieldTermStructure<C, D>,
+    compounding: Compounding,
+    settlement_date: DateTime,
+) -> Result<f64, CashFlowAnalyticsError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    if pending(leg, settlement_date).next().is_none() {
+        return Err(CashFlowAnalyticsError::NoCashFlows);
+    }
+
+    let value = |spread: f64| {
+        let spreaded = ZeroSpreadedTermStructure::new(curve, spread, compounding);
+        let settlement_discount = *spreaded.discount_factor(settlement_date)?;
+        Ok(*self::npv(leg, &spreaded, settlement_date)?.amount() / settlement_discount)
+    };
+    value(0.0).map_err(CashFlowAnalyticsError::TermStructure)?;
+
+    let objective =
+        |spread: f64| value(spread).map_or(f64::NAN, |value: f64| value - *npv.amount());
+    Brent::default()
+        .solve(objective, -0.5, 1.0)
+        .map_err(CashFlowAnalyticsError::Solver)
+}
+
+/// Discount factor at a yield over a year fraction, with its first and second derivatives with
+/// respect to the yield.
+fn discount_factor_derivatives(rate: f64, t: f64, compounding: Compounding) -> (f64, f64, f64) {
+    match compounding.regime(t) {
+        CompoundingRegime::Simple => {
+            let discount_factor = 1.0 / rate.mul_add(t, 1.0);
+            (
+                discount_factor,
+                -t * discount_factor.powi(2),
+                2.0 * t.powi(2) * discount_factor.powi(3),
+            )
+        }
+        CompoundingRegime::Compounded(n) => {
+            let base = 1.0 + rate / n;
+            let discount_factor = base.powf(-n * t);
+            (
+                discount_factor,
+                -t * discount_factor / base,
+                t * n.mul_add(t, 1.0) / n * discount_factor / base.powi(2),
+            )
+        }
+        CompoundingRegime::Continuous => {
+            let discount_factor = (-rate * t).exp();
+            (
+                discount_factor,
+                -t * discount_factor,
+                t.powi(2) * discount_factor,
+            )
+        }
+    }
+}
+
+/// Sums over the cash flows paid after the settlement date of $CF_i D_i$, $CF_i t_i D_i$,
+/// $CF_i D_i'$ and $CF_i D_i''$, for discount factors $D_i$ at a flat yield and their derivatives
+/// with respect to it.
+fn yield_sums<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    yield_rate: &InterestRate<C, D>,
+    settlement_date: DateTime,
+) -> [f64; 4]
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let rate = *yield_rate.get_rate();
+    pending(leg, settlement_date).fold([0.0; 4], |sums, cash_flow| {
+        let amount = *cash_flow.amount().amount();
+        let t = year_fraction(
+            yield_rate.get_day_counter(),
+            settlement_date,
+            cash_flow.payment_date(),
+        );
+        let (discount_factor, first, second) =
+            discount_factor_derivatives(rate, t, yield_rate.get_compounding());
+        [
+            amount.mul_add(discount_factor, sums[0]),
+            (amount * t).mul_add(discount_factor, sums[1]),
+            amount.mul_add(first, sums[2]),
+            amount.mul_add(second, sums[3]),
+        ]
+    })
+}
+
+/// Macaulay duration at a flat yield, the average time to the cash flows paid after the
+/// settlement date weighted by their present values, $\frac{1}{P} \sum_i t_i CF_i D(t_i)$.
+#[must_use]
+pub fn macaulay_duration<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    yield_rate: &InterestRate<C, D>,
+    settlement_date: DateTime,
+) -> f64
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let [value, time_weighted, ..] = yield_sums(leg, yield_rate, settlement_date);
+    time_weighted / value
+}
+
+/// Modified duration at a flat yield, the relative sensitivity of the value of the cash flows
+/// paid after the settlement date to the yield, $-\frac{1}{P} \frac{\partial P}{\partial y}$.
+#[must_use]
+pub fn modified_duration<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    yield_rate: &InterestRate<C, D>,
+    settlement_date: DateTime,
+) -> f64
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let [value, _, first, _] = yield_sums(leg, yield_rate, settlement_date);
+    -first / value
+}
+
+/// Convexity at a flat yield of the cash flows paid after the settlement date,
+/// $\frac{1}{P} \frac{\partial^2 P}{\partial y^2}$.
+#[must_use]
+pub fn convexity<C, D>(
+    leg: &[Box<dyn CashFlow<C> + '_>],
+    yield_rate: &InterestRate<C, D>,
+    settlement_date: DateTime,
+) -> f64
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let [value, _, _, second] = yield_sums(leg, yield_rate, settlement_date);
+    second / value
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual365Fixed, Thirty360};
+
+    use super::*;
+    use crate::{
+        cashflows::{fixed_rate_leg, Leg, SimpleCashFlow},
+        money::currency::USD,
+        term_structures::{flat_curve, FlatForwardTermStructure},
+        time::{Frequency, Schedule},
+    };
+
+    fn bond_leg(start: DateTime) -> Leg<'static, USD> {
+        let maturity = start.add_months(36);
+        let coupon_rate =
+            InterestRate::new(0.04, Thirty360, Compounding::Simple(Frequency::Annual));
+        let mut leg: Leg<USD> = fixed_rate_leg(
+            &Schedule::new(start, maturity, Frequency::Annual),
+            Money::new(100.0),
+            coupon_rate,
+        )
+        .into_iter()
+        .map(|coupon| Box::new(coupon) as Box<dyn CashFlow<USD>>)
+        .collect();
+        leg.push(Box::new(SimpleCashFlow::new(maturity, Money::new(100.0))));
+        leg
+    }
+
+    #[test]
+    fn test_leg_analytics() {
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let leg = bond_leg(start);
+        let curve: FlatForwardTermStructure<USD, Actual365Fixed> =
+            flat_curve(start, 0.04, Compounding::Compounding(Frequency::Annual));
+
+        // Coupons and discounting at the same annual rate on whole years price close to par.
+        let value = *npv(&leg, &curve, start).unwrap().amount();
+        assert_approx_eq!(value, 100.0, 10e-2);
+
+        // The yield that reprices the leg on the curve's conventions is the curve rate.
+        let ytm = yield_rate(
+            &leg,
+            Money::new(value),
+            Actual365Fixed,
+            Compounding::Compounding(Frequency::Annual),
+            start,
+        )
+        .unwrap();
+        assert_approx_eq!(*ytm.get_rate(), 0.04, 10e-10);
+        assert_approx_eq!(*npv_from_yield(&leg, &ytm, start).amount(), value, 10e-8);
+
+        // BPS of an annual coupon leg is the annuity.
+        let annuity: f64 = (1..=3)
+            .map(|year| *curve.discount_factor(start.add_months(12 * year)).unwrap())
+            .sum();
+        assert_approx_eq!(
+            *bps(&leg, &curve, start).unwrap().amount(),
+            100.0 * annuity * BASIS_POINT,
+            10e-10
+        );
+
+        // Accrued after a quarter of the second coupon period.
+        let date = start.add_months(15);
+        assert_approx_eq!(*accrued_amount(&leg, date).amount(), 1.0, 10e-10);
+
+        // Cash flows paid on or before the settlement date are excluded.
+        assert_eq!(
+            yield_rate(
+                &leg,
+                Money::new(100.0),
+                Actual365Fixed,
+                Compounding::Continuous,
+                start.add_months(36),
+            ),
+            Err(CashFlowAnalyticsError::NoCashFlows)
+        );
+    }
+
+    #[test]
+    fn test_spread_to_curve() {
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let leg = bond_leg(start);
+        let curve: FlatForwardTermStructure<USD, Actual365Fixed> =
+            flat_curve(start, 0.03, Compounding::Continuous);
+        let settlement_date = start.add_months(6);
+
+        // A value at a continuous yield of 3.5% is a spread of 50bp over the flat 3% curve.
+        let yield_rate = InterestRate::new(0.035, Actual365Fixed, Compounding::Continuous);
+        let value = npv_from_yield(&leg, &yield_rate, settlement_date);
+        let spread = spread_to_curve(
+            &leg,
+            value,
+            &curve,
+            Compounding::Continuous,
+            settlement_date,
+        )
+        .unwrap();
+        assert_approx_eq!(spread, 0.005, 10e-10);
+
+        assert_eq!(
+            spread_to_curve(
+                &leg,
+                value,
+                &curve,
+                Compounding::Continuous,
+                start.add_months(-1),
+            ),
+            Err(CashFlowAnalyticsError::TermStructure(
+                TermStructureError::InvalidDateTime
+            ))
+        );
+    }
+
+    #[test]
+    fn test_duration_and_convexity() {
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let leg = bond_leg(start);
+        let annual =
+            InterestRate::new(0.05, Thirty360, Compounding::Compounding(Frequency::Annual));
+
+        // Coupons of 4 and a redemption of 100 after 1, 2 and 3 years.
+        let flows = [(1.0, 4.0), (2.0, 4.0), (3.0, 104.0)];
+        let value: f64 = flows.iter().map(|(t, cf)| cf * 1.05f64.powf(-t)).sum();
+        let macaulay: f64 = flows
+            .iter()
+            .map(|(t, cf)| t * cf * 1.05f64.powf(-t))
+            .sum::<f64>()
+            / value;
+        let expected_convexity: f64 = flows
+            .iter()
+            .map(|(t, cf)| t * (t + 1.0) * cf * 1.05f64.powf(-t - 2.0))
+            .sum::<f64>()
+            / value;
+        assert_approx_eq!(macaulay_duration(&leg, &annual, start), macaulay, 10e-12);
+        assert_approx_eq!(
+            modified_duration(&leg, &annual, start),
+            macaulay / 1.05,
+            10e-12
+        );
+        assert_approx_eq!(convexity(&leg, &annual, start), expected_convexity, 10e-12);
+
+        // Finite differences of the value for the other compoundings.
+        let h = 1e-5;
+        for compounding in [
+            Compounding::Simple(Frequency::Annual),
+            Compounding::Compounding(Frequency::Semiannual),
+            Compounding::Continuous,
+            Compounding::SimpleThenCompounded(Frequency::Quarterly),
+        ] {
+            let value = |rate: f64| {
+                *npv_from_yield(
+                    &leg,
+                    &InterestRate::new(rate, Thirty360, compounding),
+                    start,
+                )
+                .amount()
+            };
+            let yield_rate = InterestRate::new(0.05, Thirty360, compounding);
+            let (down, mid, up) = (value(0.05 - h), value(0.05), value(0.05 + h));
+            assert_approx_eq!(
+                modified_duration(&leg, &yield_rate, start),
+                -(up - down) / (2.0 * h * mid),
+                10e-8
+            );
+            assert_approx_eq!(
+                convexity(&leg, &yield_rate, start),
+                (up - 2.0 * mid + down) / (h * h * mid),
+                10e-3
+            );
+        }
+    }
+}
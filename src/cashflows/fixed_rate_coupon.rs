@@ -0,0 +1,175 @@
+use day_count_conventions::DayCounter;
+use num::Zero;
+
+use crate::cashflows::{year_fraction, CashFlow, Coupon};
+use crate::interest_rate::InterestRate;
+use crate::money::{Currency, Money};
+use crate::time::{DateTime, Schedule};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Coupon paying a fixed interest rate on a nominal over an accrual period, with the amount
+/// $N (CF(t_s, t_e) - 1)$ for the compound factor $CF$ of the rate.
+#[derive(Debug, Copy, Clone)]
+pub struct FixedRateCoupon<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    payment_date: DateTime,
+    nominal: Money<C>,
+    rate: InterestRate<C, D>,
+    accrual_start_date: DateTime,
+    accrual_end_date: DateTime,
+}
+
+impl<C, D> FixedRateCoupon<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn new(
+        payment_date: DateTime,
+        nominal: Money<C>,
+        rate: InterestRate<C, D>,
+        accrual_start_date: DateTime,
+        accrual_end_date: DateTime,
+    ) -> Self {
+        Self {
+            payment_date,
+            nominal,
+            rate,
+            accrual_start_date,
+            accrual_end_date,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_interest_rate(&self) -> &InterestRate<C, D> {
+        &self.rate
+    }
+
+    /// Interest on the nominal between two dates.
+    fn interest(&self, start: DateTime, end: DateTime) -> Money<C> {
+        self.nominal * (*self.rate.compound_factor_between(start, end) - 1.0)
+    }
+}
+
+/// Fixed rate coupons paid at the end of each period of a schedule.
+#[must_use]
+pub fn fixed_rate_leg<C, D>(
+    schedule: &Schedule,
+    nominal: Money<C>,
+    rate: InterestRate<C, D>,
+) -> Vec<FixedRateCoupon<C, D>>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    schedule
+        .get_periods()
+        .into_iter()
+        .map(|(start, end)| FixedRateCoupon::new(end, nominal, rate, start, end))
+        .collect()
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D> CashFlow<C> for FixedRateCoupon<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn payment_date(&self) -> DateTime {
+        self.payment_date
+    }
+
+    fn amount(&self) -> Money<C> {
+        self.interest(self.accrual_start_date, self.accrual_end_date)
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon<C>> {
+        Some(self)
+    }
+}
+
+impl<C, D> Coupon<C> for FixedRateCoupon<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn nominal(&self) -> Money<C> {
+        self.nominal
+    }
+
+    fn rate(&self) -> f64 {
+        *self.rate.get_rate()
+    }
+
+    fn accrual_start_date(&self) -> DateTime {
+        self.accrual_start_date
+    }
+
+    fn accrual_end_date(&self) -> DateTime {
+        self.accrual_end_date
+    }
+
+    fn accrual_period(&self) -> f64 {
+        year_fraction(
+            self.rate.get_day_counter(),
+            self.accrual_start_date,
+            self.accrual_end_date,
+        )
+    }
+
+    fn accrued_amount(&self, date: DateTime) -> Money<C> {
+        if date <= self.accrual_start_date || date > self.accrual_end_date {
+            return Money::zero();
+        }
+        self.interest(self.accrual_start_date, date)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Thirty360;
+
+    use super::*;
+    use crate::{money::currency::USD, time::Frequency, Compounding};
+
+    #[test]
+    fn test_fixed_rate_coupon() {
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let end = DateTime::new_from_ymd(2024, 7, 15);
+        let rate = InterestRate::new(0.05, Thirty360, Compounding::Simple(Frequency::Semiannual));
+        let coupon = FixedRateCoupon::new(end, Money::<USD>::new(1_000_000.0), rate, start, end);
+
+        assert_approx_eq!(coupon.accrual_period(), 0.5);
+        assert_approx_eq!(*coupon.amount().amount(), 25_000.0, 10e-8);
+        assert_approx_eq!(
+            *coupon
+                .accrued_amount(DateTime::new_from_ymd(2024, 4, 15))
+                .amount(),
+            12_500.0,
+            10e-8
+        );
+        assert_approx_eq!(*coupon.accrued_amount(start).amount(), 0.0);
+        assert!(coupon.as_coupon().is_some());
+
+        let schedule = Schedule::new(start, start.add_months(24), Frequency::Semiannual);
+        let leg = fixed_rate_leg(&schedule, Money::<USD>::new(100.0), rate);
+        assert_eq!(leg.len(), 4);
+        assert_eq!(leg[3].payment_date(), start.add_months(24));
+        assert_approx_eq!(*leg[1].amount().amount(), 2.5, 10e-12);
+    }
+}
@@ -0,0 +1,492 @@
+use day_count_conventions::DayCounter;
+use num::Zero;
+
+use crate::cashflows::{CashFlow, Coupon};
+use crate::indices::RateIndex;
+use crate::instruments::OptionType;
+use crate::money::{Currency, Money};
+use crate::pricers::{bachelier_formula, black_formula};
+use crate::term_structures::YieldTermStructure;
+use crate::time::DateTime;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Volatility of the index rate up to its fixing date, from the projection curve's reference date,
+/// valuing the caplets and floorlets embedded in capped or floored coupons.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CapletVolatility {
+    /// Lognormal volatility, valued with the [Black formula](crate::pricers::black_formula).
+    ///
+    /// The lognormal model needs a positive index strike and forward: otherwise the options are
+    /// worth their intrinsic value, which is exact for a non-positive strike and a positive
+    /// forward.
+    Black(f64),
+
+    /// Normal volatility, valued with the [Bachelier formula](crate::pricers::bachelier_formula).
+    Normal(f64),
+}
+
+/// Coupon paying a floating index rate, with a gearing $g$ and a spread $s$, on a nominal over
+/// an accrual period: the rate is $g L + s$ and the amount $N (g L + s) \tau$, with the accrual
+/// period $\tau$ in the index's day count convention.
+///
+/// With a cap $K_c$ or a floor $K_f$ and a positive gearing, the rate is
+/// $$
+///     g L + s - g \\, \text{Caplet}\left(\frac{K_c - s}{g}\right)
+///         + g \\, \text{Floorlet}\left(\frac{K_f - s}{g}\right)
+/// $$
+/// where the caplet and floorlet on $L$ are valued at the payment date with the
+/// [`CapletVolatility`] to the fixing date. Without a volatility, or once the rate has fixed, the
+/// options are worth their intrinsic value and the rate is $\min(\max(g L + s, K_f), K_c)$.
+///
+/// The index rate $L$ is fixed on the index's fixing date for the start of the accrual period.
+/// It is taken from the index's fixing history, or forecast from the projection curve. If the
+/// fixing is missing and cannot be forecast, the rate is `NaN`.
+pub struct FloatingRateCoupon<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    payment_date: DateTime,
+    nominal: Money<C>,
+    accrual_start_date: DateTime,
+    accrual_end_date: DateTime,
+    index: &'a RateIndex<C, DC>,
+    projection_curve: &'a dyn YieldTermStructure<C, D>,
+    gearing: f64,
+    spread: f64,
+    cap: Option<f64>,
+    floor: Option<f64>,
+    caplet_volatility: Option<CapletVolatility>,
+}
+
+impl<'a, C, D, DC> FloatingRateCoupon<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Create a new `FloatingRateCoupon` with a gearing of one and no spread.
+    #[must_use]
+    pub const fn new(
+        payment_date: DateTime,
+        nominal: Money<C>,
+        accrual_start_date: DateTime,
+        accrual_end_date: DateTime,
+        index: &'a RateIndex<C, DC>,
+        projection_curve: &'a dyn YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            payment_date,
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            index,
+            projection_curve,
+            gearing: 1.0,
+            spread: 0.0,
+            cap: None,
+            floor: None,
+            caplet_volatility: None,
+        }
+    }
+
+    /// Set the gearing on the index rate.
+    ///
+    /// # Panics
+    /// if the gearing is not positive, as a cap on the coupon rate would then be a floor on the
+    /// index rate and the floor a cap.
+    #[must_use]
+    pub const fn with_gearing(mut self, gearing: f64) -> Self {
+        assert!(gearing > 0.0, "the gearing must be positive");
+        self.gearing = gearing;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set a maximum on the coupon rate.
+    #[must_use]
+    pub const fn with_cap(mut self, cap: f64) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Set a minimum on the coupon rate.
+    #[must_use]
+    pub const fn with_floor(mut self, floor: f64) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /// Set the volatility valuing the cap and the floor.
+    #[must_use]
+    pub const fn with_caplet_volatility(mut self, volatility: CapletVolatility) -> Self {
+        self.caplet_volatility = Some(volatility);
+        self
+    }
+
+    #[must_use]
+    pub const fn get_index(&self) -> &RateIndex<C, DC> {
+        self.index
+    }
+
+    #[must_use]
+    pub const fn get_gearing(&self) -> f64 {
+        self.gearing
+    }
+
+    #[must_use]
+    pub const fn get_spread(&self) -> f64 {
+        self.spread
+    }
+
+    #[must_use]
+    pub const fn get_cap(&self) -> Option<f64> {
+        self.cap
+    }
+
+    #[must_use]
+    pub const fn get_floor(&self) -> Option<f64> {
+        self.floor
+    }
+
+    #[must_use]
+    pub const fn get_caplet_volatility(&self) -> Option<CapletVolatility> {
+        self.caplet_volatility
+    }
+
+    /// The date on which the index rate is fixed.
+    #[must_use]
+    pub fn fixing_date(&self) -> DateTime {
+        self.index.fixing_date(self.accrual_start_date)
+    }
+
+    /// The index rate $L$: the past fixing, or the one forecast from the projection curve.
+    #[must_use]
+    pub fn index_fixing(&self) -> f64 {
+        self.index
+            .fixing(self.fixing_date(), self.projection_curve)
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Value at payment, as a rate, of an option on the coupon rate struck at `strike`.
+    fn optionlet(
+        &self,
+        option_type: OptionType,
+        strike: f64,
+        index_fixing: f64,
+        volatility: CapletVolatility,
+    ) -> f64 {
+        let fixing_date = self.fixing_date();
+        let time_to_fixing = if self.index.past_fixing(fixing_date).is_ok() {
+            0.0
+        } else {
+            self.projection_curve
+                .get_day_counter()
+                .day_count_fraction(&self.projection_curve.get_reference_date(), &fixing_date)
+                .get_fraction()
+                .max(0.0)
+        };
+        let index_strike = (strike - self.spread) / self.gearing;
+        let optionlet = match volatility {
+            // Without a positive strike and forward the lognormal model does not apply, and a zero
+            // standard deviation leaves the intrinsic value.
+            CapletVolatility::Black(_) if index_strike <= 0.0 || index_fixing <= 0.0 => {
+                black_formula(option_type, index_strike, index_fixing, 0.0, 1.0)
+            }
+            CapletVolatility::Black(vol) => black_formula(
+                option_type,
+                index_strike,
+                index_fixing,
+                vol * time_to_fixing.sqrt(),
+                1.0,
+            ),
+            CapletVolatility::Normal(vol) => bachelier_formula(
+                option_type,
+                index_strike,
+                index_fixing,
+                vol * time_to_fixing.sqrt(),
+                1.0,
+            ),
+        };
+        self.gearing * optionlet
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, DC> CashFlow<C> for FloatingRateCoupon<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn payment_date(&self) -> DateTime {
+        self.payment_date
+    }
+
+    fn amount(&self) -> Money<C> {
+        self.nominal * (self.rate() * self.accrual_period())
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon<C>> {
+        Some(self)
+    }
+}
+
+impl<C, D, DC> Coupon<C> for FloatingRateCoupon<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn nominal(&self) -> Money<C> {
+        self.nominal
+    }
+
+    fn rate(&self) -> f64 {
+        let index_fixing = self.index_fixing();
+        let rate = self.gearing.mul_add(index_fixing, self.spread);
+        let Some(volatility) = self.caplet_volatility else {
+            let floored = self.floor.map_or(rate, |floor| rate.max(floor));
+            return self.cap.map_or(floored, |cap| floored.min(cap));
+        };
+
+        let caplet = self.cap.map_or(0.0, |cap| {
+            self.optionlet(OptionType::CALL, cap, index_fixing, volatility)
+        });
+        let floorlet = self.floor.map_or(0.0, |floor| {
+            self.optionlet(OptionType::PUT, floor, index_fixing, volatility)
+        });
+        rate - caplet + floorlet
+    }
+
+    fn accrual_start_date(&self) -> DateTime {
+        self.accrual_start_date
+    }
+
+    fn accrual_end_date(&self) -> DateTime {
+        self.accrual_end_date
+    }
+
+    fn accrual_period(&self) -> f64 {
+        self.index
+            .year_fraction(self.accrual_start_date, self.accrual_end_date)
+    }
+
+    fn accrued_amount(&self, date: DateTime) -> Money<C> {
+        if date <= self.accrual_start_date || date > self.accrual_end_date {
+            return Money::zero();
+        }
+        self.nominal * (self.rate() * self.index.year_fraction(self.accrual_start_date, date))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        money::currency::EUR,
+        term_structures::{flat_curve, FlatForwardTermStructure},
+        Compounding,
+    };
+
+    #[test]
+    fn test_floating_rate_coupon() {
+        let curve: FlatForwardTermStructure<EUR, Actual365Fixed> = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let mut euribor = RateIndex::euribor_3m();
+        let start = DateTime::new_from_ymd(2024, 4, 15);
+        let end = DateTime::new_from_ymd(2024, 7, 15);
+        let tau = 91.0 / 360.0;
+        let forward = ((0.03f64 * 91.0 / 365.0).exp() - 1.0) / tau;
+
+        let coupon = FloatingRateCoupon::new(
+            end,
+            Money::<EUR>::new(1_000_000.0),
+            start,
+            end,
+            &euribor,
+            &curve,
+        )
+        .with_gearing(2.0)
+        .with_spread(0.001);
+        assert_eq!(coupon.fixing_date(), DateTime::new_from_ymd(2024, 4, 11));
+        assert_approx_eq!(coupon.index_fixing(), forward, 10e-12);
+        assert_approx_eq!(coupon.rate(), 2.0f64.mul_add(forward, 0.001), 10e-12);
+        assert_approx_eq!(
+            *coupon.amount().amount(),
+            1_000_000.0 * coupon.rate() * tau,
+            10e-6
+        );
+
+        // A fixing in the index's history replaces the forecast.
+        euribor
+            .add_fixing(DateTime::new_from_ymd(2024, 4, 11), 0.04)
+            .unwrap();
+        let fixed = FloatingRateCoupon::new(
+            end,
+            Money::<EUR>::new(1_000_000.0),
+            start,
+            end,
+            &euribor,
+            &curve,
+        )
+        .with_gearing(2.0)
+        .with_spread(0.001);
+        assert_approx_eq!(fixed.rate(), 0.081, 10e-12);
+        assert_approx_eq!(
+            *fixed
+                .accrued_amount(DateTime::new_from_ymd(2024, 5, 15))
+                .amount(),
+            1_000_000.0 * 0.081 * 30.0 / 360.0,
+            10e-6
+        );
+
+        // Caps and floors bound the coupon rate.
+        let floored = fixed.with_floor(0.09);
+        assert_approx_eq!(floored.rate(), 0.09, 10e-12);
+        assert_approx_eq!(floored.with_cap(0.06).rate(), 0.06, 10e-12);
+    }
+
+    #[test]
+    fn test_caplet_volatility() {
+        let curve: FlatForwardTermStructure<EUR, Actual365Fixed> = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let mut fixed = RateIndex::euribor_3m();
+        fixed
+            .add_fixing(DateTime::new_from_ymd(2024, 4, 11), 0.04)
+            .unwrap();
+        let coupon = |index| {
+            FloatingRateCoupon::new(
+                DateTime::new_from_ymd(2024, 7, 15),
+                Money::<EUR>::new(1_000_000.0),
+                DateTime::new_from_ymd(2024, 4, 15),
+                DateTime::new_from_ymd(2024, 7, 15),
+                index,
+                &curve,
+            )
+            .with_gearing(2.0)
+            .with_spread(0.001)
+        };
+        let forward = coupon(&euribor).index_fixing();
+        let rate = coupon(&euribor).rate();
+        let strike = 0.061;
+        let index_strike = (strike - 0.001) / 2.0;
+        // The fixing on the 11th of April 2024 is 100 days after the curve's reference date.
+        let time_to_fixing = 100.0f64 / 365.0;
+
+        // A cap is a short caplet on the index rate, valued with the Black formula.
+        let black = CapletVolatility::Black(0.2);
+        let capped = coupon(&euribor)
+            .with_cap(strike)
+            .with_caplet_volatility(black);
+        let caplet = black_formula(
+            OptionType::CALL,
+            index_strike,
+            forward,
+            0.2 * time_to_fixing.sqrt(),
+            1.0,
+        );
+        assert!(caplet > 0.0);
+        assert_eq!(capped.get_caplet_volatility(), Some(black));
+        assert_approx_eq!(capped.rate(), 2.0f64.mul_add(-caplet, rate), 10e-12);
+        // Below the intrinsic value, which the at-the-money cap does not change.
+        assert!(capped.rate() < coupon(&euribor).with_cap(strike).rate());
+
+        // A cap and a floor at the same strike pay the rate and the strike together, by put-call
+        // parity, for either volatility.
+        for volatility in [black, CapletVolatility::Normal(0.01)] {
+            let capped = coupon(&euribor)
+                .with_cap(strike)
+                .with_caplet_volatility(volatility);
+            let floored = coupon(&euribor)
+                .with_floor(strike)
+                .with_caplet_volatility(volatility);
+            assert!(floored.rate() > rate);
+            assert_approx_eq!(capped.rate() + floored.rate(), rate + strike, 10e-12);
+        }
+
+        // A fixed rate has no optionality left, whatever the volatility.
+        assert_approx_eq!(
+            coupon(&fixed)
+                .with_cap(strike)
+                .with_caplet_volatility(black)
+                .rate(),
+            strike,
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_black_non_positive_strike() {
+        let curve: FlatForwardTermStructure<EUR, Actual365Fixed> = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let coupon = || {
+            FloatingRateCoupon::new(
+                DateTime::new_from_ymd(2024, 7, 15),
+                Money::<EUR>::new(1_000_000.0),
+                DateTime::new_from_ymd(2024, 4, 15),
+                DateTime::new_from_ymd(2024, 7, 15),
+                &euribor,
+                &curve,
+            )
+            .with_spread(0.02)
+            .with_caplet_volatility(CapletVolatility::Black(0.2))
+        };
+        let rate = coupon().index_fixing() + 0.02;
+
+        // A cap and a floor below the spread are struck at a negative index rate: the caplet is
+        // certain to be exercised and the floorlet worthless.
+        assert_approx_eq!(coupon().with_cap(0.01).rate(), 0.01, 10e-12);
+        assert_approx_eq!(coupon().with_floor(0.01).rate(), rate, 10e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "the gearing must be positive")]
+    fn test_non_positive_gearing() {
+        let curve: FlatForwardTermStructure<EUR, Actual365Fixed> = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let _ = FloatingRateCoupon::new(
+            DateTime::new_from_ymd(2024, 7, 15),
+            Money::<EUR>::new(1_000_000.0),
+            DateTime::new_from_ymd(2024, 4, 15),
+            DateTime::new_from_ymd(2024, 7, 15),
+            &euribor,
+            &curve,
+        )
+        .with_gearing(-1.0);
+    }
+}
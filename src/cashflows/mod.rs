@@ -0,0 +1,70 @@
+use day_count_conventions::DayCounter;
+
+use crate::money::{Currency, Money};
+use crate::time::DateTime;
+
+/// A payment of an amount of money on a date.
+pub trait CashFlow<C>
+where
+    C: Currency,
+{
+    /// The date of the payment.
+    fn payment_date(&self) -> DateTime;
+
+    /// The amount paid, or forecast to be paid.
+    fn amount(&self) -> Money<C>;
+
+    /// The cash flow as a coupon, if it accrues interest over a period.
+    fn as_coupon(&self) -> Option<&dyn Coupon<C>> {
+        None
+    }
+}
+
+/// A cash flow paying the interest accrued on a nominal over an accrual period.
+pub trait Coupon<C>: CashFlow<C>
+where
+    C: Currency,
+{
+    /// The nominal the interest accrues on.
+    fn nominal(&self) -> Money<C>;
+
+    /// The rate the coupon pays, in its own compounding.
+    fn rate(&self) -> f64;
+
+    fn accrual_start_date(&self) -> DateTime;
+
+    fn accrual_end_date(&self) -> DateTime;
+
+    /// The accrual period as a year fraction, in the day count convention of the coupon.
+    fn accrual_period(&self) -> f64;
+
+    /// The interest accrued from the start of the accrual period up to a date, which is zero
+    /// outside the accrual period.
+    fn accrued_amount(&self, date: DateTime) -> Money<C>;
+}
+
+/// A sequence of cash flows, such as one side of a swap or the payments of a bond.
+pub type Leg<'a, C> = Vec<Box<dyn CashFlow<C> + 'a>>;
+
+/// Year fraction between two dates under a day count convention.
+fn year_fraction<D: DayCounter>(day_counter: D, start: DateTime, end: DateTime) -> f64 {
+    day_counter.day_count_fraction(&start, &end).get_fraction()
+}
+
+mod simple_cash_flow;
+pub use simple_cash_flow::SimpleCashFlow;
+
+mod fixed_rate_coupon;
+pub use fixed_rate_coupon::{fixed_rate_leg, FixedRateCoupon};
+
+mod floating_rate_coupon;
+pub use floating_rate_coupon::{CapletVolatility, FloatingRateCoupon};
+
+mod overnight_indexed_coupon;
+pub use overnight_indexed_coupon::{OvernightIndexedCoupon, RateAveraging};
+
+mod analytics;
+pub use analytics::{
+    accrued_amount, bps, convexity, macaulay_duration, modified_duration, npv, npv_from_yield,
+    spread_to_curve, yield_rate, CashFlowAnalyticsError,
+};
@@ -0,0 +1,545 @@
+use day_count_conventions::DayCounter;
+use num::Zero;
+
+use crate::cashflows::{CashFlow, Coupon};
+use crate::indices::RateIndex;
+use crate::money::{Currency, Money};
+use crate::term_structures::YieldTermStructure;
+use crate::time::DateTime;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// How the daily fixings of an overnight index are combined into the rate of a period.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RateAveraging {
+    /// Daily compounding, $\frac{1}{\tau} \left( \prod_i (1 + r_i \tau_i) - 1 \right)$.
+    Compound,
+
+    /// Arithmetic average weighted by the day count, $\frac{1}{\tau} \sum_i r_i \tau_i$.
+    Simple,
+}
+
+/// Coupon paying an overnight index rate compounded (or averaged) in arrears over an accrual
+/// period, with a gearing $g$ and a spread $s$: the rate is $g R + s$ for the compounded rate $R$,
+/// and the amount $N (g R + s) \tau$ with the accrual period $\tau$ in the index's day count
+/// convention.
+///
+/// Each business day $d_i$ of the index's calendar in the accrual period uses the fixing
+/// observed `lookback_days` business days earlier, weighted by the year fraction $\tau_i$ to the
+/// next business day. With an observation shift the weights are taken over the observation
+/// period instead, that is between the lookback dates. With a lockout the fixings of the last
+/// `lockout_days` days are replaced by the fixing before them.
+///
+/// Fixings are taken from the index's fixing history, or forecast from the projection curve. If
+/// a fixing is missing and cannot be forecast, the rate is `NaN`.
+pub struct OvernightIndexedCoupon<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    payment_date: DateTime,
+    nominal: Money<C>,
+    accrual_start_date: DateTime,
+    accrual_end_date: DateTime,
+    index: &'a RateIndex<C, DC>,
+    projection_curve: &'a dyn YieldTermStructure<C, D>,
+    gearing: f64,
+    spread: f64,
+    lookback_days: i32,
+    lockout_days: usize,
+    observation_shift: bool,
+    averaging: RateAveraging,
+}
+
+impl<'a, C, D, DC> OvernightIndexedCoupon<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Create a new `OvernightIndexedCoupon` compounding daily, with a gearing of one, no spread,
+    /// no lookback, no lockout and no observation shift.
+    #[must_use]
+    pub const fn new(
+        payment_date: DateTime,
+        nominal: Money<C>,
+        accrual_start_date: DateTime,
+        accrual_end_date: DateTime,
+        index: &'a RateIndex<C, DC>,
+        projection_curve: &'a dyn YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            payment_date,
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            index,
+            projection_curve,
+            gearing: 1.0,
+            spread: 0.0,
+            lookback_days: 0,
+            lockout_days: 0,
+            observation_shift: false,
+            averaging: RateAveraging::Compound,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_gearing(mut self, gearing: f64) -> Self {
+        self.gearing = gearing;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Observe each fixing a number of business days before the day it applies to.
+    #[must_use]
+    pub const fn with_lookback_days(mut self, lookback_days: i32) -> Self {
+        self.lookback_days = lookback_days;
+        self
+    }
+
+    /// Use the same fixing for the last days of the period.
+    #[must_use]
+    pub const fn with_lockout_days(mut self, lockout_days: usize) -> Self {
+        self.lockout_days = lockout_days;
+        self
+    }
+
+    /// Weight the fixings over the observation period rather than the accrual period.
+    #[must_use]
+    pub const fn with_observation_shift(mut self, observation_shift: bool) -> Self {
+        self.observation_shift = observation_shift;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_averaging(mut self, averaging: RateAveraging) -> Self {
+        self.averaging = averaging;
+        self
+    }
+
+    #[must_use]
+    pub const fn get_index(&self) -> &RateIndex<C, DC> {
+        self.index
+    }
+
+    #[must_use]
+    pub const fn get_gearing(&self) -> f64 {
+        self.gearing
+    }
+
+    #[must_use]
+    pub const fn get_spread(&self) -> f64 {
+        self.spread
+    }
+
+    #[must_use]
+    pub const fn get_lookback_days(&self) -> i32 {
+        self.lookback_days
+    }
+
+    #[must_use]
+    pub const fn get_lockout_days(&self) -> usize {
+        self.lockout_days
+    }
+
+    #[must_use]
+    pub const fn get_observation_shift(&self) -> bool {
+        self.observation_shift
+    }
+
+    #[must_use]
+    pub const fn get_averaging(&self) -> RateAveraging {
+        self.averaging
+    }
+
+    /// The accrual start date, the business days of the index's calendar in the accrual period,
+    /// and the accrual end date.
+    #[must_use]
+    pub fn value_dates(&self) -> Vec<DateTime> {
+        let calendar = self.index.get_fixing_calendar();
+        let mut dates = vec![self.accrual_start_date];
+        let mut date = calendar.advance(self.accrual_start_date, 1);
+        while date < self.accrual_end_date {
+            dates.push(date);
+            date = calendar.advance(date, 1);
+        }
+        dates.push(self.accrual_end_date);
+        dates
+    }
+
+    /// The date of the fixing used for each day of the accrual period, after lookback and
+    /// lockout.
+    #[must_use]
+    pub fn fixing_dates(&self) -> Vec<DateTime> {
+        self.observations().0
+    }
+
+    /// The fixing date for each day of the accrual period, after lookback and lockout, and the
+    /// weight of each day.
+    fn observations(&self) -> (Vec<DateTime>, Vec<f64>) {
+        let calendar = self.index.get_fixing_calendar();
+        let value_dates = self.value_dates();
+        let observation_dates: Vec<DateTime> = value_dates
+            .iter()
+            .map(|&date| calendar.advance(date, -self.lookback_days))
+            .collect();
+
+        let weight_dates = if self.observation_shift {
+            &observation_dates
+        } else {
+            &value_dates
+        };
+        let weights = weight_dates
+            .windows(2)
+            .map(|pair| self.index.year_fraction(pair[0], pair[1]))
+            .collect();
+
+        let mut fixing_dates = observation_dates;
+        fixing_dates.pop();
+        let days = fixing_dates.len();
+        if self.lockout_days > 0 && days > self.lockout_days {
+            let locked = fixing_dates[days - self.lockout_days - 1];
+            fixing_dates[days - self.lockout_days..].fill(locked);
+        }
+        (fixing_dates, weights)
+    }
+
+    /// The compounded or averaged index rate $R$ over the period.
+    #[must_use]
+    pub fn index_fixing(&self) -> f64 {
+        let (fixing_dates, weights) = self.observations();
+        let fixings: Result<Vec<f64>, _> = fixing_dates
+            .into_iter()
+            .map(|date| self.index.fixing(date, self.projection_curve))
+            .collect();
+        let Ok(fixings) = fixings else {
+            return f64::NAN;
+        };
+
+        let period: f64 = weights.iter().sum();
+        let observations = fixings.iter().zip(&weights);
+        match self.averaging {
+            RateAveraging::Compound => {
+                let compound_factor = observations.fold(1.0, |product, (fixing, weight)| {
+                    product * fixing.mul_add(*weight, 1.0)
+                });
+                (compound_factor - 1.0) / period
+            }
+            RateAveraging::Simple => {
+                observations
+                    .map(|(fixing, weight)| fixing * weight)
+                    .sum::<f64>()
+                    / period
+            }
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, DC> CashFlow<C> for OvernightIndexedCoupon<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn payment_date(&self) -> DateTime {
+        self.payment_date
+    }
+
+    fn amount(&self) -> Money<C> {
+        self.nominal * (self.rate() * self.accrual_period())
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon<C>> {
+        Some(self)
+    }
+}
+
+impl<C, D, DC> Coupon<C> for OvernightIndexedCoupon<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn nominal(&self) -> Money<C> {
+        self.nominal
+    }
+
+    fn rate(&self) -> f64 {
+        self.gearing.mul_add(self.index_fixing(), self.spread)
+    }
+
+    fn accrual_start_date(&self) -> DateTime {
+        self.accrual_start_date
+    }
+
+    fn accrual_end_date(&self) -> DateTime {
+        self.accrual_end_date
+    }
+
+    fn accrual_period(&self) -> f64 {
+        self.index
+            .year_fraction(self.accrual_start_date, self.accrual_end_date)
+    }
+
+    /// The interest accrued at the rate of the whole period.
+    fn accrued_amount(&self, date: DateTime) -> Money<C> {
+        if date <= self.accrual_start_date || date > self.accrual_end_date {
+            return Money::zero();
+        }
+        self.nominal * (self.rate() * self.index.year_fraction(self.accrual_start_date, date))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed};
+
+    use super::*;
+    use crate::{money::currency::USD, term_structures::flat_curve, Compounding};
+
+    /// SOFR with fixings of 5% from the 2nd of January 2024 up to a date, except 4% on the 12th
+    /// and 6% on the 19th.
+    fn sofr_with_fixings(last: DateTime) -> RateIndex<USD, Actual360> {
+        let mut sofr = RateIndex::sofr();
+        let calendar = sofr.get_fixing_calendar();
+        let mut fixings = vec![];
+        let mut date = DateTime::new_from_ymd(2024, 1, 2);
+        while date <= last {
+            let fixing = if date == DateTime::new_from_ymd(2024, 1, 12) {
+                0.04
+            } else if date == DateTime::new_from_ymd(2024, 1, 19) {
+                0.06
+            } else {
+                0.05
+            };
+            fixings.push((date, fixing));
+            date = calendar.advance(date, 1);
+        }
+        sofr.add_fixings(fixings).unwrap();
+        sofr
+    }
+
+    /// Compounded rate of fixings with weights in days on an Actual/360 basis.
+    fn compounded(observations: &[(f64, f64)]) -> f64 {
+        let days: f64 = observations.iter().map(|(_, days)| days).sum();
+        let product: f64 = observations
+            .iter()
+            .map(|(fixing, days)| 1.0 + fixing * days / 360.0)
+            .product();
+        (product - 1.0) * 360.0 / days
+    }
+
+    // The period runs over Martin Luther King Jr. Day, the 15th of January 2024.
+    fn start() -> DateTime {
+        DateTime::new_from_ymd(2024, 1, 8)
+    }
+
+    fn end() -> DateTime {
+        DateTime::new_from_ymd(2024, 1, 22)
+    }
+
+    #[test]
+    fn test_forecast_compounded_rate() {
+        let sofr = RateIndex::sofr();
+        let curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let coupon = OvernightIndexedCoupon::new(
+            end(),
+            Money::<USD>::new(1_000_000.0),
+            start(),
+            end(),
+            &sofr,
+            &curve,
+        );
+
+        assert_eq!(coupon.value_dates().len(), 10);
+        assert_eq!(coupon.fixing_dates().len(), 9);
+
+        // Daily forecasts compound to the forward rate over the period.
+        let tau = 14.0 / 360.0;
+        let forward = ((0.03f64 * 14.0 / 365.0).exp() - 1.0) / tau;
+        assert_approx_eq!(coupon.index_fixing(), forward, 10e-12);
+        assert_approx_eq!(
+            *coupon.amount().amount(),
+            1_000_000.0 * forward * tau,
+            10e-6
+        );
+
+        let spreaded = coupon.with_gearing(2.0).with_spread(0.001);
+        assert_approx_eq!(spreaded.rate(), 2.0f64.mul_add(forward, 0.001), 10e-12);
+    }
+
+    #[test]
+    fn test_past_and_forecast_fixings() {
+        let sofr = sofr_with_fixings(DateTime::new_from_ymd(2024, 1, 12));
+        let reference_date = DateTime::new_from_ymd(2024, 1, 16);
+        let curve =
+            flat_curve::<USD, Actual365Fixed>(reference_date, 0.03, Compounding::Continuous);
+        let coupon = OvernightIndexedCoupon::new(
+            end(),
+            Money::<USD>::new(1_000_000.0),
+            start(),
+            end(),
+            &sofr,
+            &curve,
+        );
+
+        // Known fixings up to the holiday weekend, then the forward rate to the end.
+        let forecast = *curve.discount_factor(reference_date).unwrap()
+            / *curve.discount_factor(end()).unwrap();
+        let past = 0.04f64.mul_add(4.0 / 360.0, 1.0) * (1.0f64 + 0.05 / 360.0).powi(4);
+        assert_approx_eq!(
+            coupon.index_fixing(),
+            (past * forecast - 1.0) * 360.0 / 14.0,
+            10e-12
+        );
+
+        // A missing past fixing cannot be forecast.
+        let sofr = RateIndex::sofr();
+        let missing = OvernightIndexedCoupon::new(
+            end(),
+            Money::<USD>::new(1_000_000.0),
+            start(),
+            end(),
+            &sofr,
+            &curve,
+        );
+        assert!(missing.rate().is_nan());
+    }
+
+    #[test]
+    fn test_lookback_lockout_and_observation_shift() {
+        let sofr = sofr_with_fixings(end());
+        let curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2024, 2, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+        let coupon = || {
+            OvernightIndexedCoupon::new(
+                end(),
+                Money::<USD>::new(1_000_000.0),
+                start(),
+                end(),
+                &sofr,
+                &curve,
+            )
+        };
+
+        let plain = coupon();
+        assert_approx_eq!(
+            plain.index_fixing(),
+            compounded(&[
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.04, 4.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.06, 3.0),
+            ]),
+            10e-14
+        );
+
+        // The last two days use the fixing of the 17th.
+        let lockout = coupon().with_lockout_days(2);
+        assert_eq!(
+            lockout.fixing_dates()[6..],
+            [DateTime::new_from_ymd(2024, 1, 17); 3]
+        );
+        assert_approx_eq!(
+            lockout.index_fixing(),
+            compounded(&[
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.04, 4.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 3.0),
+            ]),
+            10e-14
+        );
+
+        // Two business days of lookback: the fixing of the 12th applies to the 17th.
+        let lookback = coupon().with_lookback_days(2);
+        assert_eq!(
+            lookback.fixing_dates()[0],
+            DateTime::new_from_ymd(2024, 1, 4)
+        );
+        assert_approx_eq!(
+            lookback.index_fixing(),
+            compounded(&[
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 4.0),
+                (0.05, 1.0),
+                (0.04, 1.0),
+                (0.05, 1.0),
+                (0.05, 3.0),
+            ]),
+            10e-14
+        );
+
+        // With an observation shift the weights follow the observation period, from the 4th to
+        // the 18th.
+        let shifted = coupon().with_lookback_days(2).with_observation_shift(true);
+        assert_approx_eq!(
+            shifted.index_fixing(),
+            compounded(&[
+                (0.05, 1.0),
+                (0.05, 3.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+                (0.04, 4.0),
+                (0.05, 1.0),
+                (0.05, 1.0),
+            ]),
+            10e-14
+        );
+
+        // Averaging weights the fixings by their days.
+        let averaged = coupon().with_averaging(RateAveraging::Simple);
+        assert_approx_eq!(
+            averaged.index_fixing(),
+            0.06f64.mul_add(3.0, 0.05f64.mul_add(7.0, 0.04 * 4.0)) / 14.0,
+            10e-14
+        );
+        assert_approx_eq!(
+            *averaged
+                .accrued_amount(DateTime::new_from_ymd(2024, 1, 15))
+                .amount(),
+            1_000_000.0 * averaged.rate() * 7.0 / 360.0,
+            10e-8
+        );
+    }
+}
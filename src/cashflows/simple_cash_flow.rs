@@ -0,0 +1,47 @@
+use crate::cashflows::CashFlow;
+use crate::money::{Currency, Money};
+use crate::time::DateTime;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// A fixed amount paid on a date, such as a notional exchange or a bond redemption.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SimpleCashFlow<C>
+where
+    C: Currency,
+{
+    payment_date: DateTime,
+    amount: Money<C>,
+}
+
+impl<C> SimpleCashFlow<C>
+where
+    C: Currency,
+{
+    #[must_use]
+    pub const fn new(payment_date: DateTime, amount: Money<C>) -> Self {
+        Self {
+            payment_date,
+            amount,
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C> CashFlow<C> for SimpleCashFlow<C>
+where
+    C: Currency,
+{
+    fn payment_date(&self) -> DateTime {
+        self.payment_date
+    }
+
+    fn amount(&self) -> Money<C> {
+        self.amount
+    }
+}
@@ -13,6 +13,43 @@ pub enum Compounding {
 
     /// Continuous interest: $e^{r \tau}$
     Continuous,
+
+    /// Simple interest up to the first compounding period, $\tau \leq \frac{1}{n}$, and
+    /// compound interest after.
+    SimpleThenCompounded(Frequency),
+}
+
+/// How interest accrues over a given year fraction, see [`Compounding::regime`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum CompoundingRegime {
+    /// Simple interest, $1 + r \tau$.
+    Simple,
+
+    /// Compound interest $n$ times a year, $(1 + \frac{r}{n})^{n \tau}$.
+    Compounded(f64),
+
+    /// Continuous interest, $e^{r \tau}$.
+    Continuous,
+}
+
+impl Compounding {
+    /// How interest accrues over a year fraction `t`. Compounding [`Frequency::Once`] is
+    /// compounding at the end of the period, so simple, as is simple then compounded interest up
+    /// to the first compounding period.
+    pub(crate) fn regime(self, t: f64) -> CompoundingRegime {
+        match self {
+            Self::Simple(_) | Self::Compounding(Frequency::Once) => CompoundingRegime::Simple,
+            Self::Compounding(freq) => CompoundingRegime::Compounded(f64::from(freq as i32)),
+            Self::Continuous => CompoundingRegime::Continuous,
+            Self::SimpleThenCompounded(freq) => {
+                if t * f64::from(freq as i32) <= 1.0 {
+                    CompoundingRegime::Simple
+                } else {
+                    CompoundingRegime::Compounded(f64::from(freq as i32))
+                }
+            }
+        }
+    }
 }
 
 impl Display for Compounding {
@@ -21,6 +58,7 @@ impl Display for Compounding {
             Self::Simple(f) => &format!("Simple({f})"),
             Self::Compounding(f) => &format!("Compounding({f})"),
             Self::Continuous => "Continuous",
+            Self::SimpleThenCompounded(f) => &format!("SimpleThenCompounded({f})"),
         };
         write!(f, "{output}")
     }
@@ -39,10 +77,33 @@ mod tests {
                 "Compounding(Quarterly)",
             ),
             (Compounding::Continuous, "Continuous"),
+            (
+                Compounding::SimpleThenCompounded(Frequency::Semiannual),
+                "SimpleThenCompounded(SemiAnnual)",
+            ),
         ];
 
         for (compounding, expected_output) in test_cases {
             assert_eq!(format!("{compounding}"), expected_output);
         }
     }
+
+    #[test]
+    fn test_compounding_regime() {
+        let quarterly = Compounding::SimpleThenCompounded(Frequency::Quarterly);
+        assert_eq!(quarterly.regime(0.25), CompoundingRegime::Simple);
+        assert_eq!(quarterly.regime(0.5), CompoundingRegime::Compounded(4.0));
+        assert_eq!(
+            Compounding::Compounding(Frequency::Once).regime(2.0),
+            CompoundingRegime::Simple
+        );
+        assert_eq!(
+            Compounding::Compounding(Frequency::Monthly).regime(0.01),
+            CompoundingRegime::Compounded(12.0)
+        );
+        assert_eq!(
+            Compounding::Continuous.regime(1.0),
+            CompoundingRegime::Continuous
+        );
+    }
 }
@@ -0,0 +1,2 @@
+mod rate_index;
+pub use rate_index::{RateIndex, RateIndexError};
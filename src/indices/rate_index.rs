@@ -0,0 +1,499 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use day_count_conventions::{Actual360, Actual365Fixed, DayCounter};
+
+use crate::money::currency::{EUR, GBP, JPY, USD};
+use crate::money::Currency;
+use crate::term_structures::{TermStructureError, YieldTermStructure};
+use crate::time::{
+    BusinessDayConvention, Calendar, DateTime, Japan, Target, Tenor, UnitedKingdom, UnitedStates,
+};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RateIndexError {
+    /// The date is not a business day of the index's fixing calendar.
+    InvalidFixingDate(DateTime),
+
+    /// The fixing is in the past and not in the fixing history.
+    MissingFixing(DateTime),
+
+    /// The fixing could not be forecast from the projection curve.
+    TermStructure(TermStructureError),
+}
+
+/// Interest rate index, such as an overnight rate (SOFR, €STR, SONIA, TONA) or a term rate
+/// (EURIBOR, term SOFR).
+///
+/// The rate fixed on a fixing date applies, simply compounded in the index's day count
+/// convention, from the value date `fixing_days` business days later to the maturity date a tenor
+/// after it. Overnight indices have a tenor of one day, and mature on the next business day.
+///
+/// Past fixings are looked up in the index's fixing history, and future ones are forecast from a
+/// projection curve.
+pub struct RateIndex<C, DC>
+where
+    C: Currency,
+    DC: DayCounter,
+{
+    name: String,
+    tenor: Tenor,
+    fixing_days: i32,
+    fixing_calendar: Box<dyn Calendar>,
+    convention: BusinessDayConvention,
+    day_counter: DC,
+    fixings: BTreeMap<DateTime, f64>,
+    currency: PhantomData<C>,
+}
+
+impl<C, DC> RateIndex<C, DC>
+where
+    C: Currency,
+    DC: DayCounter,
+{
+    /// Create a new `RateIndex` with an empty fixing history.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        tenor: Tenor,
+        fixing_days: i32,
+        fixing_calendar: impl Calendar + 'static,
+        convention: BusinessDayConvention,
+        day_counter: DC,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            tenor,
+            fixing_days,
+            fixing_calendar: Box::new(fixing_calendar),
+            convention,
+            day_counter,
+            fixings: BTreeMap::new(),
+            currency: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn get_tenor(&self) -> Tenor {
+        self.tenor
+    }
+
+    /// The number of business days between the fixing date and the value date.
+    #[must_use]
+    pub const fn get_fixing_days(&self) -> i32 {
+        self.fixing_days
+    }
+
+    #[must_use]
+    pub fn get_fixing_calendar(&self) -> &dyn Calendar {
+        self.fixing_calendar.as_ref()
+    }
+
+    #[must_use]
+    pub const fn get_business_day_convention(&self) -> BusinessDayConvention {
+        self.convention
+    }
+
+    #[must_use]
+    pub const fn get_day_counter(&self) -> DC {
+        self.day_counter
+    }
+
+    #[must_use]
+    pub fn is_overnight(&self) -> bool {
+        self.tenor == Tenor::Days(1)
+    }
+
+    #[must_use]
+    pub fn is_valid_fixing_date(&self, date: DateTime) -> bool {
+        self.fixing_calendar.is_business_day(date)
+    }
+
+    /// The fixing date of the rate applying from a value date.
+    #[must_use]
+    pub fn fixing_date(&self, value_date: DateTime) -> DateTime {
+        self.fixing_calendar.advance(value_date, -self.fixing_days)
+    }
+
+    /// The date from which the rate fixed on a fixing date applies.
+    #[must_use]
+    pub fn value_date(&self, fixing_date: DateTime) -> DateTime {
+        self.fixing_calendar.advance(fixing_date, self.fixing_days)
+    }
+
+    /// The date until which the rate applying from a value date applies.
+    #[must_use]
+    pub fn maturity_date(&self, value_date: DateTime) -> DateTime {
+        if self.is_overnight() {
+            self.fixing_calendar.advance(value_date, 1)
+        } else {
+            self.fixing_calendar
+                .adjust(self.tenor.add_to(value_date), self.convention)
+        }
+    }
+
+    /// The year fraction between two dates in the index's day count convention.
+    #[must_use]
+    pub fn year_fraction(&self, start: DateTime, end: DateTime) -> f64 {
+        self.day_counter
+            .day_count_fraction(&start, &end)
+            .get_fraction()
+    }
+
+    /// Add a fixing to the history, replacing any fixing on the same date.
+    ///
+    /// # Errors
+    /// Will return `InvalidFixingDate` if the date is not a business day of the fixing calendar.
+    pub fn add_fixing(&mut self, fixing_date: DateTime, fixing: f64) -> Result<(), RateIndexError> {
+        if !self.is_valid_fixing_date(fixing_date) {
+            return Err(RateIndexError::InvalidFixingDate(fixing_date));
+        }
+        self.fixings.insert(fixing_date, fixing);
+        Ok(())
+    }
+
+    /// Add several fixings to the history.
+    ///
+    /// # Errors
+    /// Will return `InvalidFixingDate` for the first date that is not a business day of the fixing
+    /// calendar; the fixings before it are kept.
+    pub fn add_fixings(
+        &mut self,
+        fixings: impl IntoIterator<Item = (DateTime, f64)>,
+    ) -> Result<(), RateIndexError> {
+        fixings
+            .into_iter()
+            .try_for_each(|(fixing_date, fixing)| self.add_fixing(fixing_date, fixing))
+    }
+
+    /// The fixing history, by fixing date.
+    #[must_use]
+    pub const fn get_fixings(&self) -> &BTreeMap<DateTime, f64> {
+        &self.fixings
+    }
+
+    pub fn clear_fixings(&mut self) {
+        self.fixings.clear();
+    }
+
+    /// The fixing on a date from the fixing history.
+    ///
+    /// # Errors
+    /// Will return `InvalidFixingDate` if the date is not a business day of the fixing calendar,
+    /// and `MissingFixing` if the history has no fixing on that date.
+    pub fn past_fixing(&self, fixing_date: DateTime) -> Result<f64, RateIndexError> {
+        if !self.is_valid_fixing_date(fixing_date) {
+            return Err(RateIndexError::InvalidFixingDate(fixing_date));
+        }
+        self.fixings
+            .get(&fixing_date)
+            .copied()
+            .ok_or(RateIndexError::MissingFixing(fixing_date))
+    }
+
+    /// Forecast of the fixing on a date: the simply-compounded forward rate of the projection
+    /// curve from the value date to the maturity date,
+    /// $\frac{1}{\tau} \left( \frac{P(t_v)}{P(t_m)} - 1 \right)$.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to the value or maturity
+    /// date.
+    pub fn forecast_fixing<D>(
+        &self,
+        fixing_date: DateTime,
+        projection_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError>
+    where
+        D: DayCounter,
+    {
+        let value_date = self.value_date(fixing_date);
+        let maturity_date = self.maturity_date(value_date);
+        let start_discount = *projection_curve.discount_factor(value_date)?;
+        let end_discount = *projection_curve.discount_factor(maturity_date)?;
+        Ok((start_discount / end_discount - 1.0) / self.year_fraction(value_date, maturity_date))
+    }
+
+    /// The fixing on a date: from the fixing history if it is there, and otherwise forecast from
+    /// the projection curve if the date is not before the curve's reference date.
+    ///
+    /// # Errors
+    /// Will return `InvalidFixingDate` if the date is not a business day of the fixing calendar,
+    /// `MissingFixing` if a fixing before the curve's reference date is not in the history, and
+    /// `TermStructure` if the fixing cannot be forecast.
+    pub fn fixing<D>(
+        &self,
+        fixing_date: DateTime,
+        projection_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, RateIndexError>
+    where
+        D: DayCounter,
+    {
+        match self.past_fixing(fixing_date) {
+            Err(RateIndexError::MissingFixing(_))
+                if fixing_date >= projection_curve.get_reference_date() =>
+            {
+                self.forecast_fixing(fixing_date, projection_curve)
+                    .map_err(RateIndexError::TermStructure)
+            }
+            result => result,
+        }
+    }
+}
+
+impl RateIndex<USD, Actual360> {
+    /// Secured Overnight Financing Rate.
+    #[must_use]
+    pub fn sofr() -> Self {
+        Self::new(
+            "SOFR",
+            Tenor::Days(1),
+            0,
+            UnitedStates,
+            BusinessDayConvention::Following,
+            Actual360,
+        )
+    }
+
+    /// CME Term SOFR of a tenor, such as 1, 3, 6 or 12 months.
+    #[must_use]
+    pub fn term_sofr(tenor: Tenor) -> Self {
+        Self::new(
+            &format!("Term SOFR {tenor}"),
+            tenor,
+            2,
+            UnitedStates,
+            BusinessDayConvention::ModifiedFollowing,
+            Actual360,
+        )
+    }
+}
+
+impl RateIndex<EUR, Actual360> {
+    /// Euro short-term rate.
+    #[must_use]
+    pub fn estr() -> Self {
+        Self::new(
+            "ESTR",
+            Tenor::Days(1),
+            0,
+            Target,
+            BusinessDayConvention::Following,
+            Actual360,
+        )
+    }
+
+    /// Euro Interbank Offered Rate of a tenor, such as 3 or 6 months.
+    #[must_use]
+    pub fn euribor(tenor: Tenor) -> Self {
+        Self::new(
+            &format!("EURIBOR {tenor}"),
+            tenor,
+            2,
+            Target,
+            BusinessDayConvention::ModifiedFollowing,
+            Actual360,
+        )
+    }
+
+    #[must_use]
+    pub fn euribor_3m() -> Self {
+        Self::euribor(Tenor::Months(3))
+    }
+
+    #[must_use]
+    pub fn euribor_6m() -> Self {
+        Self::euribor(Tenor::Months(6))
+    }
+}
+
+impl RateIndex<GBP, Actual365Fixed> {
+    /// Sterling Overnight Index Average.
+    #[must_use]
+    pub fn sonia() -> Self {
+        Self::new(
+            "SONIA",
+            Tenor::Days(1),
+            0,
+            UnitedKingdom,
+            BusinessDayConvention::Following,
+            Actual365Fixed,
+        )
+    }
+}
+
+impl RateIndex<JPY, Actual365Fixed> {
+    /// Tokyo Overnight Average Rate.
+    #[must_use]
+    pub fn tona() -> Self {
+        Self::new(
+            "TONA",
+            Tenor::Days(1),
+            0,
+            Japan,
+            BusinessDayConvention::Following,
+            Actual365Fixed,
+        )
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, DC> std::fmt::Debug for RateIndex<C, DC>
+where
+    C: Currency,
+    DC: DayCounter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateIndex")
+            .field("name", &self.name)
+            .field("tenor", &self.tenor)
+            .field("fixing_days", &self.fixing_days)
+            .field("fixing_calendar", &self.fixing_calendar.name())
+            .field("convention", &self.convention)
+            .field("day_counter", &self.day_counter.to_string())
+            .field("fixings", &self.fixings.len())
+            .finish()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::{
+        term_structures::{flat_curve, TermStructure},
+        Compounding,
+    };
+
+    #[test]
+    fn test_overnight_index() {
+        let sofr = RateIndex::sofr();
+        assert!(sofr.is_overnight());
+        assert_eq!(sofr.get_name(), "SOFR");
+
+        // The rate fixed before Independence Day runs over the holiday.
+        let fixing_date = DateTime::new_from_ymd(2024, 7, 3);
+        assert_eq!(sofr.value_date(fixing_date), fixing_date);
+        assert_eq!(
+            sofr.maturity_date(fixing_date),
+            DateTime::new_from_ymd(2024, 7, 5)
+        );
+
+        let forward = ((0.03f64 * 2.0 / 365.0).exp() - 1.0) / (2.0 / 360.0);
+        assert_approx_eq!(
+            sofr.forecast_fixing(
+                fixing_date,
+                &flat_curve::<USD, Actual365Fixed>(
+                    DateTime::new_from_ymd(2024, 1, 2),
+                    0.03,
+                    Compounding::Continuous
+                )
+            )
+            .unwrap(),
+            forward,
+            10e-12
+        );
+        assert_approx_eq!(
+            sofr.fixing(
+                fixing_date,
+                &flat_curve::<USD, Actual365Fixed>(
+                    DateTime::new_from_ymd(2024, 1, 2),
+                    0.03,
+                    Compounding::Continuous
+                )
+            )
+            .unwrap(),
+            forward,
+            10e-12
+        );
+
+        let sonia = RateIndex::sonia();
+        let tona = RateIndex::tona();
+        let estr = RateIndex::estr();
+        assert!(sonia.is_overnight() && tona.is_overnight() && estr.is_overnight());
+        assert_eq!(tona.get_fixing_calendar().name(), "Japan");
+    }
+
+    #[test]
+    fn test_term_index() {
+        let euribor = RateIndex::euribor_6m();
+        assert_eq!(euribor.get_name(), "EURIBOR 6M");
+        assert_eq!(
+            RateIndex::term_sofr(Tenor::Months(3)).get_name(),
+            "Term SOFR 3M"
+        );
+
+        // Fixed two TARGET days before the value date, which skip Easter.
+        let fixing_date = DateTime::new_from_ymd(2024, 3, 27);
+        let value_date = DateTime::new_from_ymd(2024, 4, 2);
+        assert_eq!(euribor.value_date(fixing_date), value_date);
+        assert_eq!(euribor.fixing_date(value_date), fixing_date);
+        assert_eq!(
+            euribor.maturity_date(value_date),
+            DateTime::new_from_ymd(2024, 10, 2)
+        );
+        // Six months later is a Saturday, and the following business day is in December.
+        assert_eq!(
+            euribor.maturity_date(DateTime::new_from_ymd(2024, 5, 31)),
+            DateTime::new_from_ymd(2024, 11, 29)
+        );
+    }
+
+    #[test]
+    fn test_fixing_history() {
+        let mut euribor = RateIndex::euribor_3m();
+        let curve = flat_curve::<EUR, Actual365Fixed>(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let past = DateTime::new_from_ymd(2023, 12, 28);
+        let weekend = DateTime::new_from_ymd(2023, 12, 30);
+
+        assert_eq!(
+            euribor.fixing(past, &curve),
+            Err(RateIndexError::MissingFixing(past))
+        );
+        assert_eq!(
+            euribor.add_fixing(weekend, 0.039),
+            Err(RateIndexError::InvalidFixingDate(weekend))
+        );
+        euribor
+            .add_fixings([
+                (past, 0.0391),
+                (DateTime::new_from_ymd(2023, 12, 29), 0.0391),
+            ])
+            .unwrap();
+        assert_eq!(euribor.get_fixings().len(), 2);
+        assert_approx_eq!(euribor.fixing(past, &curve).unwrap(), 0.0391);
+
+        // A fixing in the history takes precedence over the forecast.
+        let today = curve.get_reference_date();
+        let forecast = euribor.fixing(today, &curve).unwrap();
+        assert_approx_eq!(forecast, euribor.forecast_fixing(today, &curve).unwrap());
+        euribor.add_fixing(today, 0.04).unwrap();
+        assert_approx_eq!(euribor.fixing(today, &curve).unwrap(), 0.04);
+
+        euribor.clear_fixings();
+        assert_eq!(
+            euribor.past_fixing(past),
+            Err(RateIndexError::MissingFixing(past))
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use day_count_conventions::DayCounter;
+use num::Zero;
+
+use super::Bond;
+use crate::cashflows::{CashFlow, Coupon, FixedRateCoupon, Leg, SimpleCashFlow};
+use crate::interest_rate::InterestRate;
+use crate::money::{Currency, Money};
+use crate::time::{BusinessDayConvention, Calendar, DateTime, Schedule};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Bond paying fixed rate coupons on a notional repaid over the periods of a schedule, such as a
+/// sinking fund bond.
+///
+/// The coupon of period $i$ accrues on the notional $N_i$ outstanding over the period, and the
+/// principal $N_i - N_{i+1}$ is repaid with it, with $N_{n+1} = 0$. Payments are made on the
+/// following business day of the calendar. Prices are quoted per 100 of the notional outstanding
+/// at the settlement date.
+pub struct AmortizingFixedRateBond<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    schedule: Schedule,
+    coupon_rate: InterestRate<C, D>,
+    notionals: Vec<Money<C>>,
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    coupons: Vec<FixedRateCoupon<C, D>>,
+    redemptions: Vec<SimpleCashFlow<C>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AmortizingBondError {
+    /// The number of notionals is not the number of schedule periods.
+    NotionalsMismatch,
+
+    /// A notional is above the one of the previous period.
+    IncreasingNotionals,
+}
+
+impl<C, D> AmortizingFixedRateBond<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `AmortizingFixedRateBond` with the notional outstanding over each period of
+    /// the schedule.
+    ///
+    /// # Errors
+    /// Will return an `AmortizingBondError` if there is not one notional per period, or the
+    /// notionals increase.
+    pub fn new(
+        schedule: Schedule,
+        coupon_rate: InterestRate<C, D>,
+        notionals: Vec<Money<C>>,
+        settlement_days: i32,
+        calendar: impl Calendar + 'static,
+    ) -> Result<Self, AmortizingBondError> {
+        let periods = schedule.get_periods();
+        if notionals.len() != periods.len() {
+            return Err(AmortizingBondError::NotionalsMismatch);
+        }
+        if notionals
+            .windows(2)
+            .any(|pair| pair[1].amount() > pair[0].amount())
+        {
+            return Err(AmortizingBondError::IncreasingNotionals);
+        }
+
+        let payment_date = |date| calendar.adjust(date, BusinessDayConvention::Following);
+        let coupons = periods
+            .iter()
+            .zip(&notionals)
+            .map(|(&(start, end), &notional)| {
+                FixedRateCoupon::new(payment_date(end), notional, coupon_rate, start, end)
+            })
+            .collect();
+        let redemptions = periods
+            .iter()
+            .zip(&notionals)
+            .zip(notionals.iter().skip(1).chain([&Money::zero()]))
+            .map(|((&(_, end), &notional), &next)| {
+                SimpleCashFlow::new(payment_date(end), notional - next)
+            })
+            .collect();
+
+        Ok(Self {
+            schedule,
+            coupon_rate,
+            notionals,
+            settlement_days,
+            calendar: Box::new(calendar),
+            coupons,
+            redemptions,
+        })
+    }
+
+    /// Create a new `AmortizingFixedRateBond` repaying an equal part of the face amount with each
+    /// coupon.
+    #[must_use]
+    pub fn new_linear(
+        schedule: Schedule,
+        coupon_rate: InterestRate<C, D>,
+        face_amount: Money<C>,
+        settlement_days: i32,
+        calendar: impl Calendar + 'static,
+    ) -> Self {
+        let periods = schedule.get_periods().len();
+        let notionals = (0..periods)
+            .map(|i| face_amount * ((periods - i) as f64 / periods as f64))
+            .collect();
+        Self::new(schedule, coupon_rate, notionals, settlement_days, calendar)
+            .expect("linear notionals decrease")
+    }
+
+    #[must_use]
+    pub const fn get_schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    #[must_use]
+    pub const fn get_coupon_rate(&self) -> &InterestRate<C, D> {
+        &self.coupon_rate
+    }
+
+    /// The notionals outstanding over the periods of the schedule.
+    #[must_use]
+    pub fn get_notionals(&self) -> &[Money<C>] {
+        &self.notionals
+    }
+
+    #[must_use]
+    pub fn get_coupons(&self) -> &[FixedRateCoupon<C, D>] {
+        &self.coupons
+    }
+
+    /// The principal repayments, one per period.
+    #[must_use]
+    pub fn get_redemptions(&self) -> &[SimpleCashFlow<C>] {
+        &self.redemptions
+    }
+
+    /// The payment date of the last redemption.
+    #[must_use]
+    pub fn get_maturity_date(&self) -> DateTime {
+        self.redemptions
+            .last()
+            .expect("a bond has at least one period")
+            .payment_date()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D> Bond<C> for AmortizingFixedRateBond<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn cash_flows(&self) -> Leg<'_, C> {
+        self.coupons
+            .iter()
+            .zip(&self.redemptions)
+            .flat_map(|(&coupon, &redemption)| {
+                [
+                    Box::new(coupon) as Box<dyn CashFlow<C> + '_>,
+                    Box::new(redemption),
+                ]
+            })
+            .collect()
+    }
+
+    fn get_calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+
+    fn get_settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+
+    /// The notional of the period the date is in, the first one before the bond starts and zero
+    /// once it has matured.
+    fn notional(&self, date: DateTime) -> Money<C> {
+        self.coupons
+            .iter()
+            .zip(&self.notionals)
+            .find(|(coupon, _)| date < coupon.accrual_end_date())
+            .map_or_else(Money::zero, |(_, &notional)| notional)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Thirty360;
+
+    use super::*;
+    use crate::{
+        money::currency::USD,
+        time::{Frequency, UnitedStates},
+        Compounding,
+    };
+
+    fn schedule() -> Schedule {
+        Schedule::new(
+            DateTime::new_from_ymd(2023, 5, 15),
+            DateTime::new_from_ymd(2026, 5, 15),
+            Frequency::Annual,
+        )
+    }
+
+    fn coupon_rate() -> InterestRate<USD, Thirty360> {
+        InterestRate::new(0.05, Thirty360, Compounding::Simple(Frequency::Annual))
+    }
+
+    #[test]
+    fn test_notionals() {
+        let bond = AmortizingFixedRateBond::new_linear(
+            schedule(),
+            coupon_rate(),
+            Money::new(300.0),
+            1,
+            UnitedStates,
+        );
+        let amounts = |cash_flows: Vec<Money<USD>>| {
+            cash_flows
+                .iter()
+                .map(|amount| *amount.amount())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            amounts(bond.get_notionals().to_vec()),
+            vec![300.0, 200.0, 100.0]
+        );
+        assert_eq!(
+            amounts(
+                bond.get_redemptions()
+                    .iter()
+                    .map(CashFlow::amount)
+                    .collect()
+            ),
+            vec![100.0, 100.0, 100.0]
+        );
+        assert_approx_eq!(*bond.get_coupons()[1].amount().amount(), 10.0, 10e-12);
+        assert_eq!(bond.get_coupons()[1].nominal(), Money::new(200.0));
+        assert_eq!(bond.cash_flows().len(), 6);
+        assert_eq!(
+            bond.get_maturity_date(),
+            DateTime::new_from_ymd(2026, 5, 15)
+        );
+
+        assert_eq!(
+            bond.notional(DateTime::new_from_ymd(2023, 1, 1)),
+            Money::new(300.0)
+        );
+        assert_eq!(
+            bond.notional(DateTime::new_from_ymd(2024, 5, 15)),
+            Money::new(200.0)
+        );
+        assert_eq!(
+            bond.notional(DateTime::new_from_ymd(2026, 5, 15)),
+            Money::zero()
+        );
+
+        let notionals = |amounts: &[f64]| amounts.iter().map(|&a| Money::new(a)).collect();
+        assert_eq!(
+            AmortizingFixedRateBond::new(
+                schedule(),
+                coupon_rate(),
+                notionals(&[300.0, 200.0]),
+                1,
+                UnitedStates
+            )
+            .err(),
+            Some(AmortizingBondError::NotionalsMismatch)
+        );
+        assert_eq!(
+            AmortizingFixedRateBond::new(
+                schedule(),
+                coupon_rate(),
+                notionals(&[300.0, 200.0, 250.0]),
+                1,
+                UnitedStates
+            )
+            .err(),
+            Some(AmortizingBondError::IncreasingNotionals)
+        );
+    }
+
+    #[test]
+    fn test_price_and_yield() {
+        let bond = AmortizingFixedRateBond::new_linear(
+            schedule(),
+            coupon_rate(),
+            Money::new(300.0),
+            1,
+            UnitedStates,
+        );
+        let annual =
+            InterestRate::new(0.05, Thirty360, Compounding::Compounding(Frequency::Annual));
+
+        // At the coupon rate the bond prices at par, whatever the notional outstanding.
+        let issue_date = DateTime::new_from_ymd(2023, 5, 15);
+        assert_approx_eq!(bond.clean_price(&annual, issue_date), 100.0, 10e-10);
+        for settlement_date in [
+            DateTime::new_from_ymd(2023, 11, 15),
+            DateTime::new_from_ymd(2024, 11, 15),
+        ] {
+            assert_approx_eq!(bond.accrued_interest(settlement_date), 2.5, 10e-10);
+            let clean_price = 100.0 * 1.05f64.sqrt() - 2.5;
+            assert_approx_eq!(
+                bond.clean_price(&annual, settlement_date),
+                clean_price,
+                10e-10
+            );
+            let ytm = bond
+                .yield_to_maturity(
+                    clean_price,
+                    Thirty360,
+                    Compounding::Compounding(Frequency::Annual),
+                    settlement_date,
+                )
+                .unwrap();
+            assert_approx_eq!(*ytm.get_rate(), 0.05, 10e-10);
+        }
+
+        // Each payment is the coupon on the outstanding notional and a third of the face amount.
+        let macaulay =
+            (115.0 / 1.05 + 2.0 * 110.0 / 1.05f64.powi(2) + 3.0 * 105.0 / 1.05f64.powi(3)) / 300.0;
+        assert_approx_eq!(
+            bond.macaulay_duration(&annual, issue_date),
+            macaulay,
+            10e-12
+        );
+    }
+}
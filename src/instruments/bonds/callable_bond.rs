@@ -0,0 +1,431 @@
+use day_count_conventions::DayCounter;
+
+use super::Bond;
+use crate::cashflows::CashFlowAnalyticsError;
+use crate::math::solvers::Brent;
+use crate::models::{HullWhiteModel, HullWhiteTree};
+use crate::money::Currency;
+use crate::term_structures::{TermStructureError, YieldTermStructure};
+use crate::time::DateTime;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Date on which the issuer may redeem a bond, at a clean price per 100 of the notional
+/// outstanding.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Callability {
+    date: DateTime,
+    price: f64,
+}
+
+/// Bond which the issuer may redeem before maturity, on the dates of a call schedule.
+///
+/// The bond is valued by backward induction on a [`HullWhiteTree`] fitted to a curve, discounting
+/// at the short rate plus a continuously compounded spread: on a call date, the value of the cash
+/// flows paid after it is capped at the call price plus the interest accrued. Prices are quoted
+/// at the settlement date as for the underlying [`Bond`].
+pub struct CallableBond<B> {
+    bond: B,
+    call_schedule: Vec<Callability>,
+    time_steps: usize,
+}
+
+/// Cash flows paid and amounts at which the bond is called at each step of a tree.
+struct Lattice {
+    tree: HullWhiteTree,
+    payments: Vec<f64>,
+    calls: Vec<Option<f64>>,
+    settlement_time: f64,
+    settlement_discount: f64,
+    notional: f64,
+}
+
+impl Callability {
+    /// Create a new `Callability`.
+    #[must_use]
+    pub const fn new(date: DateTime, price: f64) -> Self {
+        Self { date, price }
+    }
+
+    #[must_use]
+    pub const fn get_date(&self) -> DateTime {
+        self.date
+    }
+
+    /// The clean price per 100 of notional.
+    #[must_use]
+    pub const fn get_price(&self) -> f64 {
+        self.price
+    }
+}
+
+impl<B> CallableBond<B> {
+    /// Create a new `CallableBond`, valued on trees of at least 100 time steps.
+    #[must_use]
+    pub const fn new(bond: B, call_schedule: Vec<Callability>) -> Self {
+        Self {
+            bond,
+            call_schedule,
+            time_steps: 100,
+        }
+    }
+
+    /// Set the minimum number of time steps of the tree.
+    #[must_use]
+    pub const fn with_time_steps(mut self, time_steps: usize) -> Self {
+        self.time_steps = time_steps;
+        self
+    }
+
+    /// The bond without the calls.
+    #[must_use]
+    pub const fn get_bond(&self) -> &B {
+        &self.bond
+    }
+
+    #[must_use]
+    pub fn get_call_schedule(&self) -> &[Callability] {
+        &self.call_schedule
+    }
+
+    #[must_use]
+    pub const fn get_time_steps(&self) -> usize {
+        self.time_steps
+    }
+
+    /// Dirty price at the settlement date on a Hull-White model fitted to a curve, discounting at
+    /// the short rate plus a continuously compounded spread.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to the settlement date, a
+    /// payment date or a call date.
+    pub fn dirty_price_from_model<C, D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        model: &HullWhiteModel,
+        spread: f64,
+        settlement_date: DateTime,
+    ) -> Result<f64, TermStructureError>
+    where
+        C: Currency,
+        D: DayCounter,
+        B: Bond<C>,
+    {
+        Ok(self
+            .lattice(curve, model, settlement_date)?
+            .dirty_price(spread))
+    }
+
+    /// Clean price at the settlement date on a Hull-White model fitted to a curve, see
+    /// [`CallableBond::dirty_price_from_model`].
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to the settlement date, a
+    /// payment date or a call date.
+    pub fn clean_price_from_model<C, D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        model: &HullWhiteModel,
+        spread: f64,
+        settlement_date: DateTime,
+    ) -> Result<f64, TermStructureError>
+    where
+        C: Currency,
+        D: DayCounter,
+        B: Bond<C>,
+    {
+        Ok(
+            self.dirty_price_from_model(curve, model, spread, settlement_date)?
+                - self.bond.accrued_interest(settlement_date),
+        )
+    }
+
+    /// Option-adjusted spread: the continuously compounded spread over the short rate of a
+    /// Hull-White model fitted to a curve at which the bond, with its calls, is worth a clean
+    /// price at the settlement date.
+    ///
+    /// Without calls, it is the [Z-spread](Bond::z_spread) of the bond.
+    ///
+    /// # Errors
+    /// Will return `NoCashFlows` if no cash flows are paid after the settlement date,
+    /// `TermStructure` if the curve cannot discount to the settlement date, a payment date or a
+    /// call date, and `Solver` if no spread between -50% and 100% matches the price.
+    pub fn option_adjusted_spread<C, D>(
+        &self,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure<C, D>,
+        model: &HullWhiteModel,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        C: Currency,
+        D: DayCounter,
+        B: Bond<C>,
+    {
+        let has_cash_flows = self
+            .bond
+            .cash_flows()
+            .iter()
+            .any(|cash_flow| cash_flow.payment_date() > settlement_date);
+        if !has_cash_flows {
+            return Err(CashFlowAnalyticsError::NoCashFlows);
+        }
+
+        let lattice = self
+            .lattice(curve, model, settlement_date)
+            .map_err(CashFlowAnalyticsError::TermStructure)?;
+        let dirty_price = clean_price + self.bond.accrued_interest(settlement_date);
+        Brent::default()
+            .solve(
+                |spread| lattice.dirty_price(spread) - dirty_price,
+                -0.5,
+                1.0,
+            )
+            .map_err(CashFlowAnalyticsError::Solver)
+    }
+
+    /// The cash flows paid after the settlement date, and the calls before the last of them, on a
+    /// tree fitted to a curve.
+    fn lattice<C, D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        model: &HullWhiteModel,
+        settlement_date: DateTime,
+    ) -> Result<Lattice, TermStructureError>
+    where
+        C: Currency,
+        D: DayCounter,
+        B: Bond<C>,
+    {
+        let payments: Vec<(DateTime, f64)> = self
+            .bond
+            .cash_flows()
+            .iter()
+            .filter(|cash_flow| cash_flow.payment_date() > settlement_date)
+            .map(|cash_flow| (cash_flow.payment_date(), *cash_flow.amount().amount()))
+            .collect();
+        let maturity_date = payments.iter().map(|&(date, _)| date).max();
+        let calls: Vec<(DateTime, f64)> = self
+            .call_schedule
+            .iter()
+            .filter(|call| call.date > settlement_date && Some(call.date) < maturity_date)
+            .map(|call| {
+                let amount = *self.bond.notional(call.date).amount() * call.price / 100.0
+                    + *self.bond.accrued_amount(call.date).amount();
+                (call.date, amount)
+            })
+            .collect();
+
+        let settlement_discount = *curve.discount_factor(settlement_date)?;
+        let dates: Vec<DateTime> = payments
+            .iter()
+            .chain(&calls)
+            .map(|&(date, _)| date)
+            .collect();
+        let tree = model.tree(curve, &dates, self.time_steps)?;
+        let step = |date| {
+            tree.step_at(curve.time_from_reference(date))
+                .ok_or(TermStructureError::InvalidDateTime)
+        };
+
+        let steps = tree.get_times().len();
+        let mut payment_amounts = vec![0.0; steps];
+        for &(date, amount) in &payments {
+            payment_amounts[step(date)?] += amount;
+        }
+        let mut call_amounts = vec![None; steps];
+        for &(date, amount) in &calls {
+            let call = &mut call_amounts[step(date)?];
+            *call = Some(call.map_or(amount, |call: f64| call.min(amount)));
+        }
+
+        Ok(Lattice {
+            tree,
+            payments: payment_amounts,
+            calls: call_amounts,
+            settlement_time: curve.time_from_reference(settlement_date),
+            settlement_discount,
+            notional: *self.bond.notional(settlement_date).amount(),
+        })
+    }
+}
+
+impl Lattice {
+    /// Dirty price at the settlement date, discounting at the short rate plus a spread.
+    fn dirty_price(&self, spread: f64) -> f64 {
+        let last = self.tree.get_times().len() - 1;
+        let mut values = vec![0.0; self.tree.size(last)];
+        for step in (0..=last).rev() {
+            if step < last {
+                values = self.tree.rollback(step, &values, spread);
+            }
+            // The call is on the cash flows paid after the call date.
+            if let Some(call) = self.calls[step] {
+                values.iter_mut().for_each(|value| *value = value.min(call));
+            }
+            values
+                .iter_mut()
+                .for_each(|value| *value += self.payments[step]);
+        }
+
+        let settlement_discount = self.settlement_discount * (-spread * self.settlement_time).exp();
+        100.0 * values[0] / settlement_discount / self.notional
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual365Fixed, Thirty360};
+
+    use super::*;
+    use crate::{
+        instruments::FixedRateBond,
+        interest_rate::InterestRate,
+        money::{currency::USD, Money},
+        term_structures::{flat_curve, FlatForwardTermStructure},
+        time::{Frequency, Schedule, UnitedStates},
+        Compounding,
+    };
+
+    /// Five year bond paying 5% annually, issued on Wednesday the 15th of May 2024 and callable
+    /// at par.
+    fn bond(call_dates: &[DateTime]) -> CallableBond<FixedRateBond<'static, USD, Thirty360>> {
+        let bond = FixedRateBond::new(
+            Schedule::new(
+                DateTime::new_from_ymd(2024, 5, 15),
+                DateTime::new_from_ymd(2029, 5, 15),
+                Frequency::Annual,
+            ),
+            InterestRate::new(0.05, Thirty360, Compounding::Simple(Frequency::Annual)),
+            Money::new(1_000_000.0),
+            0,
+            UnitedStates,
+        );
+        let call_schedule = call_dates
+            .iter()
+            .map(|&date| Callability::new(date, 100.0))
+            .collect();
+        CallableBond::new(bond, call_schedule)
+    }
+
+    fn curve(rate: f64) -> FlatForwardTermStructure<USD, Actual365Fixed> {
+        flat_curve(
+            DateTime::new_from_ymd(2024, 5, 15),
+            rate,
+            Compounding::Continuous,
+        )
+    }
+
+    #[test]
+    fn test_callable_bond() {
+        let curve = curve(0.04);
+        let model = HullWhiteModel::new(0.05, 0.01);
+        let settlement_date = DateTime::new_from_ymd(2024, 5, 15);
+        let call_dates: Vec<DateTime> = (2026..2029)
+            .map(|year| DateTime::new_from_ymd(year, 5, 15))
+            .collect();
+        let callable = bond(&call_dates);
+        assert_eq!(callable.get_call_schedule().len(), 3);
+        assert_eq!(callable.get_call_schedule()[0].get_price(), 100.0);
+        assert_eq!(callable.get_time_steps(), 100);
+        let straight = callable.get_bond();
+        let straight_price = straight
+            .clean_price_from_curve(&curve, settlement_date)
+            .unwrap();
+
+        // Without calls, the tree reprices the bond on the curve and the option-adjusted spread
+        // is the Z-spread.
+        let uncallable = bond(&[]);
+        assert_approx_eq!(
+            uncallable
+                .clean_price_from_model(&curve, &model, 0.0, settlement_date)
+                .unwrap(),
+            straight_price,
+            10e-10
+        );
+        let z_spread = straight
+            .z_spread(straight_price - 2.0, &curve, settlement_date)
+            .unwrap();
+        assert_approx_eq!(
+            uncallable
+                .option_adjusted_spread(straight_price - 2.0, &curve, &model, settlement_date)
+                .unwrap(),
+            z_spread,
+            10e-10
+        );
+
+        // The investor is short the call, which is worth more with a higher volatility.
+        let price = |model: &HullWhiteModel| {
+            callable
+                .clean_price_from_model(&curve, model, 0.0, settlement_date)
+                .unwrap()
+        };
+        assert!(price(&model) < straight_price);
+        assert!(price(&HullWhiteModel::new(0.05, 0.02)) < price(&model));
+
+        // At the same price, the spread of the callable bond is lower than its Z-spread by the
+        // value of the call.
+        let oas = callable
+            .option_adjusted_spread(straight_price - 2.0, &curve, &model, settlement_date)
+            .unwrap();
+        assert!(oas < z_spread);
+        assert_approx_eq!(
+            callable
+                .clean_price_from_model(&curve, &model, oas, settlement_date)
+                .unwrap(),
+            straight_price - 2.0,
+            10e-8
+        );
+
+        assert_eq!(
+            callable.option_adjusted_spread(
+                100.0,
+                &curve,
+                &model,
+                DateTime::new_from_ymd(2029, 5, 15)
+            ),
+            Err(CashFlowAnalyticsError::NoCashFlows)
+        );
+    }
+
+    #[test]
+    fn test_call_exercise() {
+        // At 1% rates and without volatility, the bond is certain to be called at the first call
+        // date, on Thursday the 15th of May 2025, after paying its first coupon.
+        let curve = curve(0.01);
+        let model = HullWhiteModel::new(0.05, 0.0);
+        let settlement_date = DateTime::new_from_ymd(2024, 5, 15);
+        let callable = bond(&[DateTime::new_from_ymd(2025, 5, 15)]);
+        assert_approx_eq!(
+            callable
+                .clean_price_from_model(&curve, &model, 0.0, settlement_date)
+                .unwrap(),
+            105.0 * (-0.01f64).exp(),
+            10e-10
+        );
+
+        // A call above the value of the bond is never exercised.
+        let above = CallableBond::new(
+            bond(&[]).bond,
+            vec![Callability::new(DateTime::new_from_ymd(2025, 5, 15), 150.0)],
+        )
+        .with_time_steps(20);
+        assert_approx_eq!(
+            above
+                .clean_price_from_model(&curve, &model, 0.0, settlement_date)
+                .unwrap(),
+            above
+                .get_bond()
+                .clean_price_from_curve(&curve, settlement_date)
+                .unwrap(),
+            10e-10
+        );
+    }
+}
@@ -0,0 +1,489 @@
+use day_count_conventions::DayCounter;
+
+use super::Bond;
+use crate::cashflows::{CashFlow, FixedRateCoupon, Leg, SimpleCashFlow};
+use crate::instruments::Instrument;
+use crate::interest_rate::InterestRate;
+use crate::money::{Currency, Money};
+use crate::term_structures::YieldTermStructure;
+use crate::time::{BusinessDayConvention, Calendar, DateTime, Schedule};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Bond paying fixed rate coupons on a face amount over the periods of a schedule, and the face
+/// amount at maturity.
+///
+/// Coupons accrue over the unadjusted schedule dates and are paid on the following business day
+/// of the calendar. Prices are quoted per 100 of face amount, and the clean price excludes the
+/// interest accrued at the settlement date, `settlement_days` business days after the trade date.
+///
+/// The bond is priced as an [`Instrument`] on its discount curve, of day counter `DC`, once one is
+/// set with [`FixedRateBond::with_discount_curve`].
+pub struct FixedRateBond<'a, C, D, DC = D>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    schedule: Schedule,
+    coupon_rate: InterestRate<C, D>,
+    face_amount: Money<C>,
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    coupons: Vec<FixedRateCoupon<C, D>>,
+    redemption: SimpleCashFlow<C>,
+    discount_curve: Option<&'a dyn YieldTermStructure<C, DC>>,
+}
+
+impl<C, D> FixedRateBond<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `FixedRateBond`, without a discount curve.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        coupon_rate: InterestRate<C, D>,
+        face_amount: Money<C>,
+        settlement_days: i32,
+        calendar: impl Calendar + 'static,
+    ) -> Self {
+        let payment_date = |date| calendar.adjust(date, BusinessDayConvention::Following);
+        let coupons = schedule
+            .get_periods()
+            .into_iter()
+            .map(|(start, end)| {
+                FixedRateCoupon::new(payment_date(end), face_amount, coupon_rate, start, end)
+            })
+            .collect();
+        let maturity_date = *schedule
+            .get_dates()
+            .last()
+            .expect("a schedule has at least one date");
+        let redemption = SimpleCashFlow::new(payment_date(maturity_date), face_amount);
+
+        Self {
+            schedule,
+            coupon_rate,
+            face_amount,
+            settlement_days,
+            calendar: Box::new(calendar),
+            coupons,
+            redemption,
+            discount_curve: None,
+        }
+    }
+}
+
+impl<'a, C, D, DC> FixedRateBond<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Set the curve the bond is priced on as an [`Instrument`].
+    #[must_use]
+    pub fn with_discount_curve<DC2>(
+        self,
+        discount_curve: &'a dyn YieldTermStructure<C, DC2>,
+    ) -> FixedRateBond<'a, C, D, DC2>
+    where
+        DC2: DayCounter,
+    {
+        FixedRateBond {
+            schedule: self.schedule,
+            coupon_rate: self.coupon_rate,
+            face_amount: self.face_amount,
+            settlement_days: self.settlement_days,
+            calendar: self.calendar,
+            coupons: self.coupons,
+            redemption: self.redemption,
+            discount_curve: Some(discount_curve),
+        }
+    }
+
+    #[must_use]
+    pub const fn get_schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    #[must_use]
+    pub const fn get_coupon_rate(&self) -> &InterestRate<C, D> {
+        &self.coupon_rate
+    }
+
+    #[must_use]
+    pub const fn get_face_amount(&self) -> Money<C> {
+        self.face_amount
+    }
+
+    #[must_use]
+    pub fn get_coupons(&self) -> &[FixedRateCoupon<C, D>] {
+        &self.coupons
+    }
+
+    /// The payment date of the redemption.
+    #[must_use]
+    pub fn get_maturity_date(&self) -> DateTime {
+        self.redemption.payment_date()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, DC> Bond<C> for FixedRateBond<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn cash_flows(&self) -> Leg<'_, C> {
+        let mut leg: Leg<C> = self
+            .coupons
+            .iter()
+            .map(|&coupon| Box::new(coupon) as Box<dyn CashFlow<C> + '_>)
+            .collect();
+        leg.push(Box::new(self.redemption));
+        leg
+    }
+
+    fn get_calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+
+    fn get_settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+
+    /// The face amount, which is outstanding until maturity.
+    fn notional(&self, _date: DateTime) -> Money<C> {
+        self.face_amount
+    }
+}
+
+impl<C, D, DC> Instrument<C> for FixedRateBond<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Value on the discount curve, see [`Bond::npv_from_curve`], or `NaN` if no curve
+    /// is set or it cannot discount the cash flows.
+    fn npv(&self) -> Money<C> {
+        self.discount_curve
+            .and_then(|curve| self.npv_from_curve(curve).ok())
+            .unwrap_or_else(|| Money::new(f64::NAN))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual365Fixed, Thirty360};
+
+    use super::*;
+    use crate::{
+        cashflows::CashFlowAnalyticsError,
+        money::currency::USD,
+        math::interpolation::LinearInterpolator,
+        term_structures::{
+            flat_curve, FlatForwardTermStructure, InterpolatedZeroCurve,
+            InterpolatedZeroCurveBuilder, ZeroSpreadedTermStructure,
+        },
+        time::{Frequency, UnitedStates},
+        Compounding,
+    };
+
+    /// Three year bond paying 5% annually, issued on Monday the 15th of May 2023.
+    fn bond() -> FixedRateBond<'static, USD, Thirty360> {
+        FixedRateBond::new(
+            Schedule::new(
+                DateTime::new_from_ymd(2023, 5, 15),
+                DateTime::new_from_ymd(2026, 5, 15),
+                Frequency::Annual,
+            ),
+            InterestRate::new(0.05, Thirty360, Compounding::Simple(Frequency::Annual)),
+            Money::new(1_000_000.0),
+            1,
+            UnitedStates,
+        )
+    }
+
+    fn annual(rate: f64) -> InterestRate<USD, Thirty360> {
+        InterestRate::new(rate, Thirty360, Compounding::Compounding(Frequency::Annual))
+    }
+
+    #[test]
+    fn test_price_and_yield() {
+        let bond = bond();
+        let issue_date = DateTime::new_from_ymd(2023, 5, 15);
+        assert_eq!(
+            bond.settlement_date(DateTime::new_from_ymd(2023, 5, 12)),
+            issue_date
+        );
+        assert_eq!(
+            bond.get_maturity_date(),
+            DateTime::new_from_ymd(2026, 5, 15)
+        );
+        assert_eq!(bond.cash_flows().len(), 4);
+
+        // At the coupon rate the bond prices at par.
+        assert_approx_eq!(bond.clean_price(&annual(0.05), issue_date), 100.0, 10e-10);
+        let ytm = bond
+            .yield_to_maturity(
+                100.0,
+                Thirty360,
+                Compounding::Compounding(Frequency::Annual),
+                issue_date,
+            )
+            .unwrap();
+        assert_approx_eq!(*ytm.get_rate(), 0.05, 10e-10);
+
+        // Half way through the first coupon period.
+        let settlement_date = DateTime::new_from_ymd(2023, 11, 15);
+        assert_approx_eq!(bond.accrued_interest(settlement_date), 2.5, 10e-10);
+        assert_approx_eq!(
+            *bond.accrued_amount(settlement_date).amount(),
+            25_000.0,
+            10e-6
+        );
+        let dirty_price = 100.0 * 1.05f64.sqrt();
+        assert_approx_eq!(
+            bond.dirty_price(&annual(0.05), settlement_date),
+            dirty_price,
+            10e-10
+        );
+        assert_approx_eq!(
+            bond.clean_price(&annual(0.05), settlement_date),
+            dirty_price - 2.5,
+            10e-10
+        );
+        let ytm = bond
+            .yield_to_maturity(
+                dirty_price - 2.5,
+                Thirty360,
+                Compounding::Compounding(Frequency::Annual),
+                settlement_date,
+            )
+            .unwrap();
+        assert_approx_eq!(*ytm.get_rate(), 0.05, 10e-10);
+
+        // A higher yield gives a lower price.
+        assert!(bond.clean_price(&annual(0.06), settlement_date) < dirty_price - 2.5);
+
+        assert_eq!(
+            bond.yield_to_maturity(
+                100.0,
+                Thirty360,
+                Compounding::Continuous,
+                DateTime::new_from_ymd(2026, 5, 15)
+            ),
+            Err(CashFlowAnalyticsError::NoCashFlows)
+        );
+    }
+
+    #[test]
+    fn test_duration_and_convexity() {
+        let bond = bond();
+        let issue_date = DateTime::new_from_ymd(2023, 5, 15);
+        let yield_rate = annual(0.05);
+
+        let macaulay = (5.0 / 1.05 + 10.0 / 1.05f64.powi(2) + 315.0 / 1.05f64.powi(3)) / 100.0;
+        assert_approx_eq!(
+            bond.macaulay_duration(&yield_rate, issue_date),
+            macaulay,
+            10e-12
+        );
+        assert_approx_eq!(
+            bond.modified_duration(&yield_rate, issue_date),
+            macaulay / 1.05,
+            10e-12
+        );
+        let convexity = (2.0 * 5.0 / 1.05f64.powi(3)
+            + 6.0 * 5.0 / 1.05f64.powi(4)
+            + 12.0 * 105.0 / 1.05f64.powi(5))
+            / 100.0;
+        assert_approx_eq!(bond.convexity(&yield_rate, issue_date), convexity, 10e-12);
+
+        // Under continuous compounding the durations are equal.
+        let continuous = InterestRate::new(0.05, Thirty360, Compounding::Continuous);
+        assert_approx_eq!(
+            bond.macaulay_duration(&continuous, issue_date),
+            bond.modified_duration(&continuous, issue_date),
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_curve_pricing() {
+        let trade_date = DateTime::new_from_ymd(2023, 5, 12);
+        let rate = InterestRate::new(0.04, Actual365Fixed, Compounding::Continuous);
+        let curve: FlatForwardTermStructure<USD, Actual365Fixed> =
+            flat_curve(trade_date, *rate.get_rate(), rate.get_compounding());
+
+        let bond = bond();
+        assert!(bond.npv().amount().is_nan());
+
+        // Discounting on a flat curve is discounting at its rate from the settlement date.
+        let settlement_date = bond.settlement_date(trade_date);
+        let dirty_price = bond
+            .dirty_price_from_curve(&curve, settlement_date)
+            .unwrap();
+        assert_approx_eq!(
+            dirty_price,
+            bond.dirty_price(&rate, settlement_date),
+            10e-10
+        );
+        assert_approx_eq!(
+            bond.clean_price_from_curve(&curve, settlement_date)
+                .unwrap(),
+            bond.clean_price(&rate, settlement_date),
+            10e-10
+        );
+
+        let bond = bond.with_discount_curve(&curve);
+        let discount_factor = *curve.discount_factor(settlement_date).unwrap();
+        assert_approx_eq!(
+            *bond.npv().amount(),
+            dirty_price * 10_000.0 * discount_factor,
+            10e-6
+        );
+        assert_approx_eq!(
+            *bond.npv().amount(),
+            *bond.npv_from_curve(&curve).unwrap().amount(),
+            10e-8
+        );
+    }
+
+    #[test]
+    fn test_par_yield_spreads() {
+        let trade_date = DateTime::new_from_ymd(2023, 5, 12);
+        let bond = bond();
+        let settlement_date = bond.settlement_date(trade_date);
+        let upward_curve = |rates: [f64; 3]| -> InterpolatedZeroCurve<USD, Actual365Fixed, _> {
+            InterpolatedZeroCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[
+                    (DateTime::new_from_ymd(2024, 5, 15), rates[0]),
+                    (DateTime::new_from_ymd(2025, 5, 15), rates[1]),
+                    (DateTime::new_from_ymd(2026, 5, 15), rates[2]),
+                ])
+                .reference_date(trade_date)
+                .build()
+                .unwrap()
+        };
+        let government_curve = upward_curve([0.03, 0.04, 0.05]);
+        let swap_curve = upward_curve([0.035, 0.045, 0.055]);
+
+        // Par yield of annual coupons on 30/360 from the settlement date to each anniversary.
+        let par_yield = |curve: &InterpolatedZeroCurve<USD, Actual365Fixed, _>| {
+            let discount = |year| {
+                *curve
+                    .discount_factor(DateTime::new_from_ymd(year, 5, 15))
+                    .unwrap()
+            };
+            (discount(2023) - discount(2026)) / (discount(2024) + discount(2025) + discount(2026))
+        };
+        let clean_price = bond.clean_price(&annual(0.06), settlement_date);
+        let spread = |curve| {
+            bond.g_spread(
+                clean_price,
+                curve,
+                Thirty360,
+                Compounding::Compounding(Frequency::Annual),
+                settlement_date,
+            )
+            .unwrap()
+        };
+        let g_spread = spread(&government_curve);
+        assert_approx_eq!(g_spread, 0.06 - par_yield(&government_curve), 10e-10);
+        assert_approx_eq!(
+            bond.i_spread(
+                clean_price,
+                &swap_curve,
+                Thirty360,
+                Compounding::Compounding(Frequency::Annual),
+                settlement_date
+            )
+            .unwrap(),
+            0.06 - par_yield(&swap_curve),
+            10e-10
+        );
+
+        // On an upward sloping curve the par yield is below the zero yield to maturity, as the
+        // coupons are discounted at the lower short rates.
+        let zero_yield = *government_curve
+            .zero_rate(DateTime::new_from_ymd(2026, 5, 15))
+            .unwrap()
+            .get_rate();
+        assert!(g_spread > 0.06 - zero_yield.exp_m1());
+    }
+
+    #[test]
+    fn test_spreads() {
+        let trade_date = DateTime::new_from_ymd(2023, 5, 12);
+        let curve = flat_curve::<USD, Actual365Fixed>(trade_date, 0.04, Compounding::Continuous);
+        let bond = bond();
+        let settlement_date = bond.settlement_date(trade_date);
+
+        // Priced on the curve itself, the bond has no spread to it.
+        let curve_price = bond
+            .clean_price_from_curve(&curve, settlement_date)
+            .unwrap();
+        assert_approx_eq!(
+            bond.z_spread(curve_price, &curve, settlement_date).unwrap(),
+            0.0,
+            10e-10
+        );
+        assert_approx_eq!(
+            bond.asset_swap_spread(curve_price, &curve, settlement_date)
+                .unwrap(),
+            0.0,
+            10e-12
+        );
+
+        // Priced on the curve shifted by 100bp, the Z-spread is 100bp.
+        let spreaded = ZeroSpreadedTermStructure::new(&curve, 0.01, Compounding::Continuous);
+        let clean_price = bond
+            .clean_price_from_curve(&spreaded, settlement_date)
+            .unwrap();
+        assert_approx_eq!(
+            bond.z_spread(clean_price, &curve, settlement_date).unwrap(),
+            0.01,
+            10e-10
+        );
+
+        // Priced at a yield above the curve, the bond has a positive asset swap spread.
+        let yield_at = |rate| InterestRate::new(rate, Actual365Fixed, Compounding::Continuous);
+        let clean_price = bond.clean_price(&yield_at(0.05), settlement_date);
+
+        // The asset swap spread spreads the price difference over the annuity of the coupons.
+        let discount = |year| {
+            *curve
+                .discount_factor(DateTime::new_from_ymd(year, 5, 15))
+                .unwrap()
+        };
+        let annuity = (discount(2024) + discount(2025) + discount(2026)) / discount(2023);
+        let asset_swap_spread = bond
+            .asset_swap_spread(clean_price, &curve, settlement_date)
+            .unwrap();
+        assert_approx_eq!(
+            asset_swap_spread,
+            (curve_price - clean_price) / (100.0 * annuity),
+            10e-12
+        );
+        assert!(asset_swap_spread > 0.0);
+        assert_eq!(
+            bond.asset_swap_spread(100.0, &curve, DateTime::new_from_ymd(2026, 5, 15)),
+            Err(CashFlowAnalyticsError::NoCashFlows)
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use day_count_conventions::DayCounter;
+
+use super::Bond;
+use crate::cashflows::{
+    CapletVolatility, CashFlow, CashFlowAnalyticsError, FloatingRateCoupon, Leg, SimpleCashFlow,
+};
+use crate::indices::RateIndex;
+use crate::money::{Currency, Money};
+use crate::term_structures::YieldTermStructure;
+use crate::time::{BusinessDayConvention, Calendar, DateTime, Schedule};
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Bond paying floating rate coupons on a face amount over the periods of a schedule, and the
+/// face amount at maturity.
+///
+/// Each coupon pays the index rate plus a quoted spread, optionally capped and floored, see
+/// [`FloatingRateCoupon`]. The cap and floor are valued with the caplet volatility, or at their
+/// intrinsic value without one. Index rates missing from the index's fixing history are forecast on
+/// the projection curve. Payments are made on the following business day of the calendar.
+pub struct FloatingRateNote<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    schedule: Schedule,
+    index: &'a RateIndex<C, DC>,
+    projection_curve: &'a dyn YieldTermStructure<C, D>,
+    face_amount: Money<C>,
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    spread: f64,
+    cap: Option<f64>,
+    floor: Option<f64>,
+    caplet_volatility: Option<CapletVolatility>,
+}
+
+impl<'a, C, D, DC> FloatingRateNote<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Create a new `FloatingRateNote` paying the index rate flat.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        index: &'a RateIndex<C, DC>,
+        projection_curve: &'a dyn YieldTermStructure<C, D>,
+        face_amount: Money<C>,
+        settlement_days: i32,
+        calendar: impl Calendar + 'static,
+    ) -> Self {
+        Self {
+            schedule,
+            index,
+            projection_curve,
+            face_amount,
+            settlement_days,
+            calendar: Box::new(calendar),
+            spread: 0.0,
+            cap: None,
+            floor: None,
+            caplet_volatility: None,
+        }
+    }
+
+    /// Set the quoted spread over the index rate.
+    #[must_use]
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the maximum coupon rate.
+    #[must_use]
+    pub fn with_cap(mut self, cap: f64) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Set the minimum coupon rate.
+    #[must_use]
+    pub fn with_floor(mut self, floor: f64) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /// Set the volatility of the index rate valuing the cap and the floor.
+    #[must_use]
+    pub fn with_caplet_volatility(mut self, volatility: CapletVolatility) -> Self {
+        self.caplet_volatility = Some(volatility);
+        self
+    }
+
+    #[must_use]
+    pub const fn get_schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    #[must_use]
+    pub const fn get_index(&self) -> &RateIndex<C, DC> {
+        self.index
+    }
+
+    #[must_use]
+    pub const fn get_face_amount(&self) -> Money<C> {
+        self.face_amount
+    }
+
+    #[must_use]
+    pub const fn get_spread(&self) -> f64 {
+        self.spread
+    }
+
+    #[must_use]
+    pub const fn get_cap(&self) -> Option<f64> {
+        self.cap
+    }
+
+    #[must_use]
+    pub const fn get_floor(&self) -> Option<f64> {
+        self.floor
+    }
+
+    #[must_use]
+    pub const fn get_caplet_volatility(&self) -> Option<CapletVolatility> {
+        self.caplet_volatility
+    }
+
+    /// The payment date of the redemption.
+    #[must_use]
+    pub fn get_maturity_date(&self) -> DateTime {
+        self.payment_date(
+            *self
+                .schedule
+                .get_dates()
+                .last()
+                .expect("a schedule has at least one date"),
+        )
+    }
+
+    /// The coupons over the periods of the schedule.
+    #[must_use]
+    pub fn coupons(&self) -> Vec<FloatingRateCoupon<'a, C, D, DC>> {
+        self.schedule
+            .get_periods()
+            .into_iter()
+            .map(|(start, end)| {
+                let coupon = FloatingRateCoupon::new(
+                    self.payment_date(end),
+                    self.face_amount,
+                    start,
+                    end,
+                    self.index,
+                    self.projection_curve,
+                )
+                .with_spread(self.spread);
+                let coupon = match self.cap {
+                    Some(cap) => coupon.with_cap(cap),
+                    None => coupon,
+                };
+                let coupon = match self.floor {
+                    Some(floor) => coupon.with_floor(floor),
+                    None => coupon,
+                };
+                match self.caplet_volatility {
+                    Some(volatility) => coupon.with_caplet_volatility(volatility),
+                    None => coupon,
+                }
+            })
+            .collect()
+    }
+
+    /// Discount margin: the parallel spread over the continuously compounded zero rates of the
+    /// projection curve at which the note is worth a clean price at the settlement date.
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the note has matured, the projection curve cannot
+    /// discount the cash flows or no spread matches the price.
+    pub fn discount_margin(
+        &self,
+        clean_price: f64,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError> {
+        self.spread_to_curve(
+            clean_price,
+            self.projection_curve,
+            Compounding::Continuous,
+            settlement_date,
+        )
+    }
+
+    fn payment_date(&self, date: DateTime) -> DateTime {
+        self.calendar.adjust(date, BusinessDayConvention::Following)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, DC> Bond<C> for FloatingRateNote<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn cash_flows(&self) -> Leg<'_, C> {
+        let mut leg: Leg<C> = self
+            .coupons()
+            .into_iter()
+            .map(|coupon| Box::new(coupon) as Box<dyn CashFlow<C> + '_>)
+            .collect();
+        leg.push(Box::new(SimpleCashFlow::new(
+            self.get_maturity_date(),
+            self.face_amount,
+        )));
+        leg
+    }
+
+    fn get_calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+
+    fn get_settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+
+    /// The face amount, which is outstanding until maturity.
+    fn notional(&self, _date: DateTime) -> Money<C> {
+        self.face_amount
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed};
+
+    use super::*;
+    use crate::{
+        cashflows::Coupon,
+        money::currency::EUR,
+        term_structures::{flat_curve, FlatForwardTermStructure, ZeroSpreadedTermStructure},
+        time::{Frequency, Target},
+    };
+
+    /// One year note paying EURIBOR 3M quarterly, from Monday the 15th of April 2024.
+    fn note<'a>(
+        index: &'a RateIndex<EUR, Actual360>,
+        curve: &'a FlatForwardTermStructure<EUR, Actual365Fixed>,
+    ) -> FloatingRateNote<'a, EUR, Actual365Fixed, Actual360> {
+        FloatingRateNote::new(
+            Schedule::new(
+                DateTime::new_from_ymd(2024, 4, 15),
+                DateTime::new_from_ymd(2025, 4, 15),
+                Frequency::Quarterly,
+            ),
+            index,
+            curve,
+            Money::new(1_000_000.0),
+            2,
+            Target,
+        )
+    }
+
+    #[test]
+    fn test_par_pricing() {
+        let curve = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let note = note(&euribor, &curve);
+        let settlement_date = note.settlement_date(DateTime::new_from_ymd(2024, 4, 11));
+        assert_eq!(settlement_date, DateTime::new_from_ymd(2024, 4, 15));
+        assert_eq!(note.coupons().len(), 4);
+        assert_eq!(note.cash_flows().len(), 5);
+
+        // Coupons forecast on the discount curve reprice the note to par at its start.
+        assert_approx_eq!(
+            note.clean_price_from_curve(&curve, settlement_date)
+                .unwrap(),
+            100.0,
+            10e-10
+        );
+        assert_approx_eq!(
+            note.discount_margin(100.0, settlement_date).unwrap(),
+            0.0,
+            10e-10
+        );
+
+        // A positive quoted spread prices the note above par.
+        let note = note.with_spread(0.001);
+        assert!(note
+            .coupons()
+            .iter()
+            .all(|coupon| coupon.get_spread() == 0.001));
+        assert!(
+            note.clean_price_from_curve(&curve, settlement_date)
+                .unwrap()
+                > 100.0
+        );
+    }
+
+    #[test]
+    fn test_discount_margin() {
+        let curve = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let note = note(&euribor, &curve).with_spread(0.002);
+        let settlement_date = DateTime::new_from_ymd(2024, 5, 15);
+
+        // Discount on the projection curve shifted by a margin of 50bp.
+        let discount_curve = ZeroSpreadedTermStructure::new(&curve, 0.005, Compounding::Continuous);
+        let clean_price = note
+            .clean_price_from_curve(&discount_curve, settlement_date)
+            .unwrap();
+        assert!(clean_price < 100.0);
+        assert_approx_eq!(
+            note.discount_margin(clean_price, settlement_date).unwrap(),
+            0.005,
+            10e-10
+        );
+    }
+
+    #[test]
+    fn test_cap_and_floor() {
+        let curve = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let euribor = RateIndex::euribor_3m();
+        let settlement_date = DateTime::new_from_ymd(2024, 4, 15);
+        let price = |note: &FloatingRateNote<EUR, Actual365Fixed, Actual360>| {
+            note.clean_price_from_curve(&curve, settlement_date)
+                .unwrap()
+        };
+
+        // The forward rates are about 3%, so a 1% cap lowers the coupons and a 5% floor raises
+        // them, while a 1% floor and a 5% cap have no effect.
+        let capped = note(&euribor, &curve).with_cap(0.01);
+        for coupon in capped.coupons() {
+            assert_approx_eq!(coupon.rate(), 0.01, 10e-15);
+        }
+        assert!(price(&capped) < 100.0);
+        let floored = note(&euribor, &curve).with_floor(0.05);
+        for coupon in floored.coupons() {
+            assert_approx_eq!(coupon.rate(), 0.05, 10e-15);
+        }
+        assert!(price(&floored) > 100.0);
+        let collared = note(&euribor, &curve).with_floor(0.01).with_cap(0.05);
+        assert_eq!(collared.get_cap(), Some(0.05));
+        assert_eq!(collared.get_floor(), Some(0.01));
+        assert_approx_eq!(price(&collared), 100.0, 10e-10);
+
+        // With a volatility, at-the-money caps and floors have time value on top of their
+        // intrinsic value: the note is short the caplets and long the floorlets.
+        for volatility in [CapletVolatility::Normal(0.01), CapletVolatility::Black(0.3)] {
+            let capped = note(&euribor, &curve).with_cap(0.03);
+            let intrinsic = price(&capped);
+            let capped = capped.with_caplet_volatility(volatility);
+            assert_eq!(capped.get_caplet_volatility(), Some(volatility));
+            assert!(price(&capped) < intrinsic - 0.1);
+            let floored = note(&euribor, &curve).with_floor(0.03);
+            let intrinsic = price(&floored);
+            assert!(price(&floored.with_caplet_volatility(volatility)) > intrinsic + 0.1);
+        }
+    }
+}
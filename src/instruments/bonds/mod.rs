@@ -0,0 +1,419 @@
+use day_count_conventions::DayCounter;
+
+use crate::cashflows::{self, CashFlowAnalyticsError, Leg};
+use crate::interest_rate::InterestRate;
+use crate::money::{Currency, Money};
+use crate::term_structures::{TermStructureError, YieldTermStructure};
+use crate::time::{Calendar, DateTime};
+use crate::Compounding;
+
+/// Price, yield and spread analytics common to bonds, from their cash flows.
+///
+/// Prices are quoted per 100 of the notional outstanding at the settlement date, and clean prices
+/// exclude the interest accrued at the settlement date. Only the cash flows paid after the
+/// settlement date are included.
+///
+/// The option-adjusted spread of bonds the issuer may call is measured on a short rate model, see
+/// [`CallableBond::option_adjusted_spread`].
+pub trait Bond<C>
+where
+    C: Currency,
+{
+    /// The coupons and redemptions, in payment order.
+    #[must_use]
+    fn cash_flows(&self) -> Leg<'_, C>;
+
+    /// The calendar of settlement and payment dates.
+    #[must_use]
+    fn get_calendar(&self) -> &dyn Calendar;
+
+    /// The number of business days between the trade date and the settlement date.
+    #[must_use]
+    fn get_settlement_days(&self) -> i32;
+
+    /// The notional outstanding at a date, which prices are quoted per 100 of.
+    #[must_use]
+    fn notional(&self, date: DateTime) -> Money<C>;
+
+    /// The settlement date of a trade on a date.
+    #[must_use]
+    fn settlement_date(&self, trade_date: DateTime) -> DateTime {
+        self.get_calendar()
+            .advance(trade_date, self.get_settlement_days())
+    }
+
+    /// The interest accrued at the settlement date.
+    #[must_use]
+    fn accrued_amount(&self, settlement_date: DateTime) -> Money<C> {
+        cashflows::accrued_amount(&self.cash_flows(), settlement_date)
+    }
+
+    /// The interest accrued at the settlement date, per 100 of notional.
+    #[must_use]
+    fn accrued_interest(&self, settlement_date: DateTime) -> f64 {
+        to_price(
+            self.accrued_amount(settlement_date),
+            self.notional(settlement_date),
+        )
+    }
+
+    /// Value at a curve's reference date of the cash flows paid after the settlement date of a
+    /// trade on that date.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to a payment date.
+    fn npv_from_curve<D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<Money<C>, TermStructureError>
+    where
+        D: DayCounter,
+    {
+        let settlement_date = self.settlement_date(curve.get_reference_date());
+        cashflows::npv(&self.cash_flows(), curve, settlement_date)
+    }
+
+    /// Dirty price at the settlement date, discounting on a curve.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to the settlement date or a
+    /// payment date.
+    fn dirty_price_from_curve<D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        settlement_date: DateTime,
+    ) -> Result<f64, TermStructureError>
+    where
+        D: DayCounter,
+    {
+        let value = cashflows::npv(&self.cash_flows(), curve, settlement_date)?;
+        Ok(to_price(value, self.notional(settlement_date))
+            / *curve.discount_factor(settlement_date)?)
+    }
+
+    /// Clean price at the settlement date, discounting on a curve.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to the settlement date or a
+    /// payment date.
+    fn clean_price_from_curve<D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        settlement_date: DateTime,
+    ) -> Result<f64, TermStructureError>
+    where
+        D: DayCounter,
+    {
+        Ok(self.dirty_price_from_curve(curve, settlement_date)?
+            - self.accrued_interest(settlement_date))
+    }
+
+    /// Dirty price at the settlement date, discounting at a yield.
+    #[must_use]
+    fn dirty_price<D>(&self, yield_rate: &InterestRate<C, D>, settlement_date: DateTime) -> f64
+    where
+        D: DayCounter,
+    {
+        to_price(
+            cashflows::npv_from_yield(&self.cash_flows(), yield_rate, settlement_date),
+            self.notional(settlement_date),
+        )
+    }
+
+    /// Clean price at the settlement date, discounting at a yield.
+    #[must_use]
+    fn clean_price<D>(&self, yield_rate: &InterestRate<C, D>, settlement_date: DateTime) -> f64
+    where
+        D: DayCounter,
+    {
+        self.dirty_price(yield_rate, settlement_date) - self.accrued_interest(settlement_date)
+    }
+
+    /// Yield to maturity, in the given day count convention and compounding, at which the bond is
+    /// worth a clean price at the settlement date.
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the bond has matured or no yield matches the
+    /// price.
+    fn yield_to_maturity<D>(
+        &self,
+        clean_price: f64,
+        day_counter: D,
+        compounding: Compounding,
+        settlement_date: DateTime,
+    ) -> Result<InterestRate<C, D>, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+    {
+        cashflows::yield_rate(
+            &self.cash_flows(),
+            self.dirty_value(clean_price, settlement_date),
+            day_counter,
+            compounding,
+            settlement_date,
+        )
+    }
+
+    /// Parallel spread over the zero rates of a curve, in the given compounding, at which the bond
+    /// is worth a clean price at the settlement date.
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the bond has matured, the curve cannot discount
+    /// the cash flows or no spread matches the price.
+    fn spread_to_curve<D>(
+        &self,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure<C, D>,
+        compounding: Compounding,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+    {
+        cashflows::spread_to_curve(
+            &self.cash_flows(),
+            self.dirty_value(clean_price, settlement_date),
+            curve,
+            compounding,
+            settlement_date,
+        )
+    }
+
+    /// Z-spread: the parallel spread over the continuously compounded zero rates of a curve at
+    /// which the bond is worth a clean price at the settlement date.
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the bond has matured, the curve cannot discount
+    /// the cash flows or no spread matches the price.
+    fn z_spread<D>(
+        &self,
+        clean_price: f64,
+        curve: &dyn YieldTermStructure<C, D>,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+    {
+        self.spread_to_curve(clean_price, curve, Compounding::Continuous, settlement_date)
+    }
+
+    /// G-spread: the yield to maturity, in the given day count convention and compounding, less
+    /// the par yield of an interpolated government curve over the bond's remaining coupon dates,
+    /// see [`Bond::i_spread`].
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the bond has matured, the curve cannot discount
+    /// to the settlement or a payment date or no yield matches the price.
+    fn g_spread<D, DG>(
+        &self,
+        clean_price: f64,
+        government_curve: &dyn YieldTermStructure<C, DG>,
+        day_counter: D,
+        compounding: Compounding,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+        DG: DayCounter,
+    {
+        par_yield_spread(
+            self,
+            clean_price,
+            government_curve,
+            day_counter,
+            compounding,
+            settlement_date,
+        )
+    }
+
+    /// I-spread: the yield to maturity, in the given day count convention and compounding, less
+    /// the interpolated par swap rate of a swap curve over the bond's remaining coupon dates
+    /// $t_1 < \dots < t_n = T$,
+    /// $$
+    ///     c = \frac{D(t_s) - D(T)}{\sum_{i=1}^{n} \tau_i D(t_i)}
+    /// $$
+    /// where $\tau_i$ is the year fraction from $t_{i-1}$ to $t_i$ in the given day count
+    /// convention, from $t_0 = t_s$ the settlement date. The par rate is paid on the coupon
+    /// frequency, so the yield is compared like for like when compounded on it too.
+    ///
+    /// # Errors
+    /// Will return a `CashFlowAnalyticsError` if the bond has matured, the curve cannot discount
+    /// to the settlement or a payment date or no yield matches the price.
+    fn i_spread<D, DS>(
+        &self,
+        clean_price: f64,
+        swap_curve: &dyn YieldTermStructure<C, DS>,
+        day_counter: D,
+        compounding: Compounding,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+        DS: DayCounter,
+    {
+        par_yield_spread(
+            self,
+            clean_price,
+            swap_curve,
+            day_counter,
+            compounding,
+            settlement_date,
+        )
+    }
+
+    /// Par asset swap spread: the spread over the floating rate at which swapping the bond's
+    /// coupons for floating payments on the swap curve, over the coupon periods, makes up the
+    /// difference between the bond's value on the swap curve $P_{swap}$ and its dirty market
+    /// price $P$, $(P_{swap} - P) / (100 A)$, with the annuity $A = \sum_i \tau_i D(t_i) / D(t_s)$
+    /// of the coupons paid after the settlement date $t_s$.
+    ///
+    /// # Errors
+    /// Will return `NoCashFlows` if no coupons are paid after the settlement date, and
+    /// `TermStructure` if the swap curve cannot discount to the settlement date or a payment date.
+    fn asset_swap_spread<D>(
+        &self,
+        clean_price: f64,
+        swap_curve: &dyn YieldTermStructure<C, D>,
+        settlement_date: DateTime,
+    ) -> Result<f64, CashFlowAnalyticsError>
+    where
+        D: DayCounter,
+    {
+        let settlement_discount = *swap_curve
+            .discount_factor(settlement_date)
+            .map_err(CashFlowAnalyticsError::TermStructure)?;
+        let bps = cashflows::bps(&self.cash_flows(), swap_curve, settlement_date)
+            .map_err(CashFlowAnalyticsError::TermStructure)?;
+        let annuity =
+            10_000.0 * to_price(bps, self.notional(settlement_date)) / settlement_discount;
+        if annuity == 0.0 {
+            return Err(CashFlowAnalyticsError::NoCashFlows);
+        }
+
+        let swap_price = self
+            .dirty_price_from_curve(swap_curve, settlement_date)
+            .map_err(CashFlowAnalyticsError::TermStructure)?;
+        let dirty_price = clean_price + self.accrued_interest(settlement_date);
+        Ok((swap_price - dirty_price) / annuity)
+    }
+
+    /// The value at the settlement date of the bond quoted at a clean price.
+    #[must_use]
+    fn dirty_value(&self, clean_price: f64, settlement_date: DateTime) -> Money<C> {
+        let dirty_price = clean_price + self.accrued_interest(settlement_date);
+        self.notional(settlement_date) * (dirty_price / 100.0)
+    }
+
+    /// Macaulay duration at a yield, see [`cashflows::macaulay_duration`].
+    #[must_use]
+    fn macaulay_duration<D>(
+        &self,
+        yield_rate: &InterestRate<C, D>,
+        settlement_date: DateTime,
+    ) -> f64
+    where
+        D: DayCounter,
+    {
+        cashflows::macaulay_duration(&self.cash_flows(), yield_rate, settlement_date)
+    }
+
+    /// Modified duration at a yield, see [`cashflows::modified_duration`].
+    #[must_use]
+    fn modified_duration<D>(
+        &self,
+        yield_rate: &InterestRate<C, D>,
+        settlement_date: DateTime,
+    ) -> f64
+    where
+        D: DayCounter,
+    {
+        cashflows::modified_duration(&self.cash_flows(), yield_rate, settlement_date)
+    }
+
+    /// Convexity at a yield, see [`cashflows::convexity`].
+    #[must_use]
+    fn convexity<D>(&self, yield_rate: &InterestRate<C, D>, settlement_date: DateTime) -> f64
+    where
+        D: DayCounter,
+    {
+        cashflows::convexity(&self.cash_flows(), yield_rate, settlement_date)
+    }
+}
+
+/// Yield to maturity of a bond less the par yield of a benchmark curve over the bond's coupon
+/// dates after the settlement date, see [`Bond::i_spread`].
+fn par_yield_spread<C, B, D, DB>(
+    bond: &B,
+    clean_price: f64,
+    benchmark_curve: &dyn YieldTermStructure<C, DB>,
+    day_counter: D,
+    compounding: Compounding,
+    settlement_date: DateTime,
+) -> Result<f64, CashFlowAnalyticsError>
+where
+    C: Currency,
+    B: Bond<C> + ?Sized,
+    D: DayCounter,
+    DB: DayCounter,
+{
+    let yield_rate =
+        bond.yield_to_maturity(clean_price, day_counter, compounding, settlement_date)?;
+    let cash_flows = bond.cash_flows();
+    let maturity_date = cash_flows
+        .iter()
+        .map(|cash_flow| cash_flow.payment_date())
+        .max()
+        .expect("a bond with a yield has cash flows");
+
+    // The coupon dates, and the maturity date for bonds without coupons left.
+    let mut payment_dates: Vec<DateTime> = cash_flows
+        .iter()
+        .filter(|cash_flow| cash_flow.as_coupon().is_some())
+        .map(|cash_flow| cash_flow.payment_date())
+        .filter(|&date| date > settlement_date)
+        .chain(std::iter::once(maturity_date))
+        .collect();
+    payment_dates.sort();
+    payment_dates.dedup();
+
+    let discount_factor = |date| {
+        benchmark_curve
+            .discount_factor(date)
+            .map(|discount_factor| *discount_factor)
+            .map_err(CashFlowAnalyticsError::TermStructure)
+    };
+    let mut annuity = 0.0;
+    let mut start_date = settlement_date;
+    for date in payment_dates {
+        let year_fraction = day_counter
+            .day_count_fraction(&start_date, &date)
+            .get_fraction();
+        annuity += year_fraction * discount_factor(date)?;
+        start_date = date;
+    }
+    let par_yield = (discount_factor(settlement_date)? - discount_factor(maturity_date)?) / annuity;
+    Ok(*yield_rate.get_rate() - par_yield)
+}
+
+/// An amount as a price per 100 of notional.
+fn to_price<C>(amount: Money<C>, notional: Money<C>) -> f64
+where
+    C: Currency,
+{
+    100.0 * *amount.amount() / *notional.amount()
+}
+
+mod fixed_rate_bond;
+pub use fixed_rate_bond::FixedRateBond;
+
+mod zero_coupon_bond;
+pub use zero_coupon_bond::ZeroCouponBond;
+
+mod floating_rate_note;
+pub use floating_rate_note::FloatingRateNote;
+
+mod callable_bond;
+pub use callable_bond::{Callability, CallableBond};
+
+mod amortizing_fixed_rate_bond;
+pub use amortizing_fixed_rate_bond::{AmortizingBondError, AmortizingFixedRateBond};
@@ -0,0 +1,219 @@
+use day_count_conventions::DayCounter;
+
+use super::Bond;
+use crate::cashflows::{CashFlow, Leg, SimpleCashFlow};
+use crate::money::{Currency, Money};
+use crate::time::{BusinessDayConvention, Calendar, DateTime};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Bond paying only its face amount at maturity, such as a treasury bill.
+///
+/// The face amount is paid on the following business day of the calendar from the maturity date.
+/// Besides the yields of [`Bond`], bills are quoted on a discount yield $d$, with price
+/// $100 (1 - d \tau)$, or on a money market yield $y$, with price $100 / (1 + y \tau)$, where
+/// $\tau$ is the year fraction from the settlement date to the payment date, usually in the
+/// Actual/360 convention.
+pub struct ZeroCouponBond<C>
+where
+    C: Currency,
+{
+    face_amount: Money<C>,
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    redemption: SimpleCashFlow<C>,
+}
+
+impl<C> ZeroCouponBond<C>
+where
+    C: Currency,
+{
+    /// Create a new `ZeroCouponBond`.
+    #[must_use]
+    pub fn new(
+        maturity_date: DateTime,
+        face_amount: Money<C>,
+        settlement_days: i32,
+        calendar: impl Calendar + 'static,
+    ) -> Self {
+        let payment_date = calendar.adjust(maturity_date, BusinessDayConvention::Following);
+
+        Self {
+            face_amount,
+            settlement_days,
+            calendar: Box::new(calendar),
+            redemption: SimpleCashFlow::new(payment_date, face_amount),
+        }
+    }
+
+    #[must_use]
+    pub const fn get_face_amount(&self) -> Money<C> {
+        self.face_amount
+    }
+
+    /// The payment date of the redemption.
+    #[must_use]
+    pub fn get_maturity_date(&self) -> DateTime {
+        self.redemption.payment_date()
+    }
+
+    /// Price at the settlement date quoted on a discount yield, $100 (1 - d \tau)$.
+    #[must_use]
+    pub fn price_from_discount_yield<D>(
+        &self,
+        discount_yield: f64,
+        day_counter: D,
+        settlement_date: DateTime,
+    ) -> f64
+    where
+        D: DayCounter,
+    {
+        100.0 * discount_yield.mul_add(-self.time_to_maturity(day_counter, settlement_date), 1.0)
+    }
+
+    /// Discount yield of a price at the settlement date, $(1 - P / 100) / \tau$.
+    #[must_use]
+    pub fn discount_yield<D>(&self, price: f64, day_counter: D, settlement_date: DateTime) -> f64
+    where
+        D: DayCounter,
+    {
+        (1.0 - price / 100.0) / self.time_to_maturity(day_counter, settlement_date)
+    }
+
+    /// Price at the settlement date quoted on a money market yield, $100 / (1 + y \tau)$.
+    #[must_use]
+    pub fn price_from_money_market_yield<D>(
+        &self,
+        money_market_yield: f64,
+        day_counter: D,
+        settlement_date: DateTime,
+    ) -> f64
+    where
+        D: DayCounter,
+    {
+        100.0 / money_market_yield.mul_add(self.time_to_maturity(day_counter, settlement_date), 1.0)
+    }
+
+    /// Money market yield of a price at the settlement date, $(100 / P - 1) / \tau$.
+    #[must_use]
+    pub fn money_market_yield<D>(
+        &self,
+        price: f64,
+        day_counter: D,
+        settlement_date: DateTime,
+    ) -> f64
+    where
+        D: DayCounter,
+    {
+        (100.0 / price - 1.0) / self.time_to_maturity(day_counter, settlement_date)
+    }
+
+    /// Year fraction from the settlement date to the payment date.
+    fn time_to_maturity<D>(&self, day_counter: D, settlement_date: DateTime) -> f64
+    where
+        D: DayCounter,
+    {
+        day_counter
+            .day_count_fraction(&settlement_date, &self.get_maturity_date())
+            .get_fraction()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C> Bond<C> for ZeroCouponBond<C>
+where
+    C: Currency,
+{
+    fn cash_flows(&self) -> Leg<'_, C> {
+        vec![Box::new(self.redemption)]
+    }
+
+    fn get_calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+
+    fn get_settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+
+    /// The face amount, which is outstanding until maturity.
+    fn notional(&self, _date: DateTime) -> Money<C> {
+        self.face_amount
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual360;
+
+    use super::*;
+    use crate::{
+        interest_rate::InterestRate,
+        money::currency::USD,
+        time::{Frequency, UnitedStates},
+        Compounding,
+    };
+
+    #[test]
+    fn test_bill_yields() {
+        // 91 day bill, settling on Thursday the 4th of January 2024.
+        let settlement_date = DateTime::new_from_ymd(2024, 1, 4);
+        let bill: ZeroCouponBond<USD> = ZeroCouponBond::new(
+            DateTime::new_from_ymd(2024, 4, 4),
+            Money::new(1_000_000.0),
+            1,
+            UnitedStates,
+        );
+        assert_eq!(
+            bill.settlement_date(DateTime::new_from_ymd(2024, 1, 3)),
+            settlement_date
+        );
+        assert_eq!(bill.cash_flows().len(), 1);
+        assert_approx_eq!(bill.accrued_interest(settlement_date), 0.0, 10e-12);
+
+        let tau = 91.0 / 360.0;
+        let price = bill.price_from_discount_yield(0.05, Actual360, settlement_date);
+        assert_approx_eq!(price, 100.0 * (1.0 - 0.05 * tau), 10e-10);
+        assert_approx_eq!(
+            bill.discount_yield(price, Actual360, settlement_date),
+            0.05,
+            10e-12
+        );
+
+        // The money market yield of a price is above its discount yield.
+        let money_market_yield = bill.money_market_yield(price, Actual360, settlement_date);
+        assert_approx_eq!(money_market_yield, 0.05 / (1.0 - 0.05 * tau), 10e-12);
+        assert_approx_eq!(
+            bill.price_from_money_market_yield(money_market_yield, Actual360, settlement_date),
+            price,
+            10e-10
+        );
+
+        // The money market yield is the simply compounded yield to maturity.
+        let simple = InterestRate::new(
+            money_market_yield,
+            Actual360,
+            Compounding::Simple(Frequency::Annual),
+        );
+        assert_approx_eq!(bill.clean_price(&simple, settlement_date), price, 10e-10);
+        let ytm = bill
+            .yield_to_maturity(
+                price,
+                Actual360,
+                Compounding::Simple(Frequency::Annual),
+                settlement_date,
+            )
+            .unwrap();
+        assert_approx_eq!(*ytm.get_rate(), money_market_yield, 10e-10);
+    }
+}
@@ -15,13 +15,11 @@ impl AmericanExercise {
 
 impl Exercise for AmericanExercise {
     #[inline]
-    #[must_use]
     fn get_dates(&self) -> Vec<DateTime> {
         vec![self.date]
     }
 
     #[inline]
-    #[must_use]
     fn get_last_date(&self) -> DateTime {
         self.date
     }
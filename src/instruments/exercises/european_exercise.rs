@@ -14,13 +14,11 @@ impl EuropeanExercise {
 
 impl Exercise for EuropeanExercise {
     #[inline]
-    #[must_use]
     fn get_dates(&self) -> Vec<DateTime> {
         vec![self.date]
     }
 
     #[inline]
-    #[must_use]
     fn get_last_date(&self) -> DateTime {
         self.date
     }
@@ -8,7 +8,9 @@ use crate::time::DateTime;
 
 pub trait Exercise {
     // This implementation is to allow for Bermudan options down the road.
+    #[must_use]
     fn get_dates(&self) -> Vec<DateTime>;
 
+    #[must_use]
     fn get_last_date(&self) -> DateTime;
 }
@@ -9,3 +9,9 @@ pub use exercises::{AmericanExercise, EuropeanExercise, Exercise};
 
 mod payoffs;
 pub use payoffs::{Payoff, StrikedPayoff, VanillaPayoff};
+
+mod bonds;
+pub use bonds::{
+    AmortizingBondError, AmortizingFixedRateBond, Bond, Callability, CallableBond, FixedRateBond,
+    FloatingRateNote, ZeroCouponBond,
+};
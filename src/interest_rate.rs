@@ -1,11 +1,11 @@
 use std::marker::PhantomData;
 
-use crate::compounding::Compounding;
+use crate::compounding::{Compounding, CompoundingRegime};
 use crate::money::Currency;
+use crate::time::{ActualActualIsma, DateTime};
 use crate::types::{CompoundFactor, DiscountFactor, Percentage};
 
 use day_count_conventions::{DayCountFraction, DayCounter};
-use num::Float;
 use ordered_float::OrderedFloat;
 
 /// Interest Rate
@@ -82,24 +82,103 @@ where
     ///
     /// Simple: $1 + rt$
     ///
-    /// Compounding: $(1 + \frac{r}{n})^{nt}$
+    /// Compounding: $(1 + \frac{r}{n})^{nt}$, or $1 + rt$ when compounding
+    /// [`Frequency::Once`](crate::time::Frequency::Once)
     ///
     /// Continuous: $e^{rt}$
+    ///
+    /// Simple then compounded: $1 + rt$ if $t \leq \frac{1}{n}$, $(1 + \frac{r}{n})^{nt}$ otherwise
     #[must_use]
     pub fn compound_factor(&self, year_fraction: &DayCountFraction<D>) -> CompoundFactor {
-        OrderedFloat(match self.compounding {
-            Compounding::Simple(_) => *self.rate.mul_add(
-                OrderedFloat(year_fraction.get_fraction()),
-                OrderedFloat(1.0),
-            ),
-            Compounding::Compounding(freq) => {
-                *(OrderedFloat(1.0) + self.rate / f64::from(freq as i32)).powf(OrderedFloat(
-                    f64::from(freq as i32) * year_fraction.get_fraction(),
-                ))
-            }
-            Compounding::Continuous => *(self.rate * year_fraction.get_fraction()).exp(),
+        let t = year_fraction.get_fraction();
+        let r = *self.rate;
+        OrderedFloat(match self.compounding.regime(t) {
+            CompoundingRegime::Simple => r.mul_add(t, 1.0),
+            CompoundingRegime::Compounded(n) => (1.0 + r / n).powf(n * t),
+            CompoundingRegime::Continuous => (r * t).exp(),
         })
     }
+
+    /// Compound factor implied by the rate between two dates, counting days with the day
+    /// counter of the rate.
+    ///
+    /// Day counters that need the reference period of an irregular coupon are counted on it by
+    /// [`InterestRate::compound_factor_in_period`].
+    #[must_use]
+    pub fn compound_factor_between(&self, start: DateTime, end: DateTime) -> CompoundFactor {
+        self.compound_factor(&self.day_counter.day_count_fraction(&start, &end))
+    }
+
+    /// Discount factor implied by the rate between two dates, see
+    /// [`InterestRate::compound_factor_between`].
+    #[must_use]
+    pub fn discount_factor_between(&self, start: DateTime, end: DateTime) -> DiscountFactor {
+        OrderedFloat(1.0) / self.compound_factor_between(start, end)
+    }
+
+    /// Economically equivalent rate under another compounding and day counting convention, with
+    /// the same compound factor between `start` and `end`.
+    ///
+    /// Returns `None` if either convention gives a zero year fraction between the dates.
+    #[must_use]
+    pub fn equivalent_rate<D2>(
+        &self,
+        compounding: Compounding,
+        day_counter: D2,
+        start: DateTime,
+        end: DateTime,
+    ) -> Option<InterestRate<C, D2>>
+    where
+        D2: DayCounter,
+    {
+        let year_fraction = self.day_counter.day_count_fraction(&start, &end);
+        let other_year_fraction = day_counter.day_count_fraction(&start, &end);
+        if year_fraction.get_fraction() == 0.0 || other_year_fraction.get_fraction() == 0.0 {
+            return None;
+        }
+
+        implied_rate_from_compound_factor(
+            self.compound_factor(&year_fraction),
+            &other_year_fraction,
+            day_counter,
+            compounding,
+        )
+    }
+}
+
+impl<C> InterestRate<C, ActualActualIsma>
+where
+    C: Currency,
+{
+    /// Compound factor implied by the rate between two dates in the regular coupon period
+    /// `ref_start` to `ref_end`, see [`ActualActualIsma`].
+    #[must_use]
+    pub fn compound_factor_in_period(
+        &self,
+        start: DateTime,
+        end: DateTime,
+        ref_start: DateTime,
+        ref_end: DateTime,
+    ) -> CompoundFactor {
+        self.compound_factor(
+            &self
+                .day_counter
+                .day_count_fraction_in_period(start, end, ref_start, ref_end),
+        )
+    }
+
+    /// Discount factor implied by the rate between two dates in the regular coupon period
+    /// `ref_start` to `ref_end`, see [`InterestRate::compound_factor_in_period`].
+    #[must_use]
+    pub fn discount_factor_in_period(
+        &self,
+        start: DateTime,
+        end: DateTime,
+        ref_start: DateTime,
+        ref_end: DateTime,
+    ) -> DiscountFactor {
+        OrderedFloat(1.0) / self.compound_factor_in_period(start, end, ref_start, ref_end)
+    }
 }
 
 impl<C, D> PartialEq for InterestRate<C, D>
@@ -128,6 +207,8 @@ where
 /// $$
 ///     CF = e^{r \tau} \implies r = \frac{\ln{C}}{\tau}
 /// $$
+///
+/// Simple then compounded: as simple if $\tau \leq \frac{1}{n}$, as compounding otherwise.
 pub fn implied_rate_from_compound_factor<C, D>(
     compound_factor: impl Into<CompoundFactor>,
     day_count_fraction: &DayCountFraction<D>,
@@ -138,22 +219,14 @@ where
     C: Currency,
     D: DayCounter,
 {
-    let implied_rate: OrderedFloat<f64> = match compounding {
-        Compounding::Simple(_) => {
-            (compound_factor.into() - 1.0) / day_count_fraction.get_fraction()
-        }
-        Compounding::Compounding(freq) => {
-            let freq = f64::from(freq as u32);
-            OrderedFloat::from(freq)
-                * (OrderedFloat::powf(
-                    compound_factor.into(),
-                    OrderedFloat(1.0 / (freq * day_count_fraction.get_fraction())),
-                ) - 1.0)
-        }
-        Compounding::Continuous => {
-            OrderedFloat::ln(compound_factor.into()) / day_count_fraction.get_fraction()
-        }
-    };
+    let compound_factor: CompoundFactor = compound_factor.into();
+    let t = day_count_fraction.get_fraction();
+    let compound_factor = *compound_factor;
+    let implied_rate = OrderedFloat(match compounding.regime(t) {
+        CompoundingRegime::Simple => (compound_factor - 1.0) / t,
+        CompoundingRegime::Compounded(n) => n * (compound_factor.powf(1.0 / (n * t)) - 1.0),
+        CompoundingRegime::Continuous => compound_factor.ln() / t,
+    });
     Some(InterestRate {
         rate: implied_rate,
         day_counter: day_count_convention,
@@ -243,4 +316,190 @@ mod tests {
             10e-8
         );
     }
+
+    #[test]
+    fn test_simple_then_compounded() {
+        let rate: InterestRate<USD, Actual360> = InterestRate::new(
+            0.05,
+            Actual360,
+            Compounding::SimpleThenCompounded(Frequency::Semiannual),
+        );
+        assert_approx_eq!(
+            *rate.compound_factor(&DayCountFraction::new(0.25)),
+            1.0125,
+            10e-12
+        );
+        assert_approx_eq!(
+            *rate.compound_factor(&DayCountFraction::new(2.0)),
+            1.025f64.powi(4),
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_equivalent_rate() {
+        use crate::time::DateTime;
+        use day_count_conventions::{Actual365Fixed, Thirty360};
+
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let compoundings = [
+            Compounding::Simple(Frequency::Annual),
+            Compounding::Compounding(Frequency::Annual),
+            Compounding::Compounding(Frequency::Semiannual),
+            Compounding::Compounding(Frequency::EveryFourthMonth),
+            Compounding::Compounding(Frequency::Quarterly),
+            Compounding::Compounding(Frequency::Bimonthly),
+            Compounding::Compounding(Frequency::Monthly),
+            Compounding::Compounding(Frequency::EveryFourthWeek),
+            Compounding::Compounding(Frequency::Biweekly),
+            Compounding::Compounding(Frequency::Weekly),
+            Compounding::Compounding(Frequency::Daily),
+            Compounding::Continuous,
+            Compounding::SimpleThenCompounded(Frequency::Quarterly),
+        ];
+
+        for end in [
+            start.add_months(2),
+            start.add_months(18),
+            start.add_months(120),
+        ] {
+            let rate: InterestRate<USD, Actual360> =
+                InterestRate::new(0.045, Actual360, Compounding::Continuous);
+            let compound_factor =
+                *rate.compound_factor(&Actual360.day_count_fraction(&start, &end));
+
+            for compounding in compoundings {
+                // Same compound factor under the new conventions.
+                let equivalent = rate
+                    .equivalent_rate(compounding, Actual365Fixed, start, end)
+                    .unwrap();
+                assert_eq!(equivalent.get_compounding(), compounding);
+                assert_approx_eq!(
+                    *equivalent.compound_factor(&Actual365Fixed.day_count_fraction(&start, &end)),
+                    compound_factor,
+                    10e-12
+                );
+
+                // Round trip through another day counter.
+                let round_trip = equivalent
+                    .equivalent_rate(
+                        Compounding::Compounding(Frequency::Monthly),
+                        Thirty360,
+                        start,
+                        end,
+                    )
+                    .unwrap()
+                    .equivalent_rate(Compounding::Continuous, Actual360, start, end)
+                    .unwrap();
+                assert_approx_eq!(*round_trip.get_rate(), 0.045, 10e-12);
+            }
+        }
+
+        // Known conversion: 5% annual is ln(1.05) continuous.
+        let annual: InterestRate<USD, Actual365Fixed> = InterestRate::new(
+            0.05,
+            Actual365Fixed,
+            Compounding::Compounding(Frequency::Annual),
+        );
+        assert_approx_eq!(
+            *annual
+                .equivalent_rate(
+                    Compounding::Continuous,
+                    Actual365Fixed,
+                    start,
+                    start.add_months(36)
+                )
+                .unwrap()
+                .get_rate(),
+            0.05f64.ln_1p(),
+            10e-12
+        );
+        assert!(annual
+            .equivalent_rate(Compounding::Continuous, Actual365Fixed, start, start)
+            .is_none());
+    }
+
+    #[test]
+    fn test_factors_between_dates() {
+        use crate::time::DateTime;
+
+        let start = DateTime::new_from_ymd(2024, 1, 15);
+        let end = DateTime::new_from_ymd(2024, 7, 15);
+        let rate: InterestRate<USD, Actual360> = InterestRate::new(
+            0.05,
+            Actual360,
+            Compounding::Compounding(Frequency::Quarterly),
+        );
+        let year_fraction = Actual360.day_count_fraction(&start, &end);
+        assert_approx_eq!(
+            *rate.compound_factor_between(start, end),
+            *rate.compound_factor(&year_fraction),
+            10e-15
+        );
+        assert_approx_eq!(
+            *rate.discount_factor_between(start, end),
+            *rate.discount_factor(&year_fraction),
+            10e-15
+        );
+        assert_approx_eq!(*rate.discount_factor_between(start, start), 1.0);
+
+        // Compounding once is simple interest over the period.
+        let once: InterestRate<USD, Actual360> =
+            InterestRate::new(0.05, Actual360, Compounding::Compounding(Frequency::Once));
+        let compound_factor = *once.compound_factor_between(start, end);
+        assert!(compound_factor.is_finite());
+        assert_approx_eq!(compound_factor, 0.05f64.mul_add(182.0 / 360.0, 1.0), 10e-15);
+        assert_approx_eq!(
+            *implied_rate_from_compound_factor::<USD, _>(
+                compound_factor,
+                &year_fraction,
+                Actual360,
+                Compounding::Compounding(Frequency::Once)
+            )
+            .unwrap()
+            .get_rate(),
+            0.05,
+            10e-15
+        );
+    }
+
+    #[test]
+    fn test_factors_in_reference_period() {
+        use crate::time::{ActualActualIsma, DateTime};
+
+        // Long first coupon of a semi-annual bond, from the 15th of August 2002 to the first
+        // regular period from the 15th of January to the 15th of July 2003.
+        let (start, end) = (
+            DateTime::new_from_ymd(2002, 8, 15),
+            DateTime::new_from_ymd(2003, 7, 15),
+        );
+        let (ref_start, ref_end) = (
+            DateTime::new_from_ymd(2003, 1, 15),
+            DateTime::new_from_ymd(2003, 7, 15),
+        );
+        let rate: InterestRate<USD, ActualActualIsma> = InterestRate::new(
+            0.04,
+            ActualActualIsma,
+            Compounding::Simple(Frequency::Semiannual),
+        );
+
+        let year_fraction = 0.5f64.mul_add(153.0 / 184.0, 0.5);
+        assert_approx_eq!(
+            *rate.compound_factor_in_period(start, end, ref_start, ref_end),
+            0.04f64.mul_add(year_fraction, 1.0),
+            10e-15
+        );
+        assert_approx_eq!(
+            *rate.discount_factor_in_period(start, end, ref_start, ref_end),
+            1.0 / 0.04f64.mul_add(year_fraction, 1.0),
+            10e-15
+        );
+
+        // Without the reference period the days are counted on the year from the start.
+        assert_approx_eq!(
+            *rate.compound_factor_between(start, end),
+            0.04f64.mul_add(334.0 / 365.0, 1.0),
+            10e-15
+        );
+    }
 }
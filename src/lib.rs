@@ -6,6 +6,10 @@
 
 pub mod instruments;
 
+pub mod cashflows;
+
+pub mod indices;
+
 pub mod money;
 pub use money::{currency, Currency, ExchangeRate, Money};
 
@@ -28,3 +32,7 @@ pub use types::{CompoundFactor, DiscountFactor, MonetaryNumber, Percentage, Stri
 pub mod math;
 
 pub mod pricers;
+
+pub mod models;
+
+pub mod risk;
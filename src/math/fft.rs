@@ -0,0 +1,83 @@
+use std::f64::consts::PI;
+
+use num::complex::Complex64;
+
+/// In-place forward discrete Fourier transform.
+///
+/// Computes
+/// $$
+///     X_k = \sum_{j=0}^{N-1} x_j e^{-2 \pi i jk / N}
+/// $$
+/// with the iterative radix-2 Cooley-Tukey algorithm.
+///
+/// # Panics
+/// if the length of `values` is not a power of two.
+pub fn fft(values: &mut [Complex64]) {
+    let n = values.len();
+    assert!(n.is_power_of_two(), "FFT length must be a power of two.");
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // Butterflies.
+    let mut len = 2;
+    while len <= n {
+        #[allow(clippy::cast_precision_loss)]
+        let angle = -2.0 * PI / len as f64;
+        let w_len = Complex64::new(angle.cos(), angle.sin());
+        for start in (0..n).step_by(len) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = values[start + k];
+                let v = values[start + k + len / 2] * w;
+                values[start + k] = u + v;
+                values[start + k + len / 2] = u - v;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_fft() {
+        let input: Vec<Complex64> = (0..8)
+            .map(|j| Complex64::new(f64::from(j), f64::from(j % 3)))
+            .collect();
+
+        let mut output = input.clone();
+        fft(&mut output);
+
+        // Compare against the naive DFT.
+        for (k, x_k) in output.iter().enumerate() {
+            let expected: Complex64 = input
+                .iter()
+                .enumerate()
+                .map(|(j, x_j)| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let angle = -2.0 * PI * (j * k) as f64 / 8.0;
+                    x_j * Complex64::new(angle.cos(), angle.sin())
+                })
+                .sum();
+            assert_approx_eq!(x_k.re, expected.re, 10e-10);
+            assert_approx_eq!(x_k.im, expected.im, 10e-10);
+        }
+    }
+}
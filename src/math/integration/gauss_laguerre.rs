@@ -0,0 +1,142 @@
+//  ------------------------------------------------------------------------------------------------
+//  Definition.
+//  ------------------------------------------------------------------------------------------------
+
+/// Gauss-Laguerre quadrature.
+///
+/// Approximates integrals over the half-line:
+/// $$
+///     \int_0^\infty f(x) \\, dx \approx \sum_{i=1}^{n} w_i e^{x_i} f(x_i)
+/// $$
+/// where $x_i$ are the roots of the Laguerre polynomial $L_n$ and $w_i$ the associated weights.
+///
+/// The nodes are computed once on construction (Newton iteration on the three-term recurrence, as
+/// in Numerical Recipes' `gaulag`), so an integrator should be reused across integrations.
+#[derive(Debug, Clone)]
+pub struct GaussLaguerreIntegrator {
+    nodes: Vec<f64>,
+
+    /// Weights, already multiplied by $e^{x_i}$.
+    weights: Vec<f64>,
+}
+
+impl GaussLaguerreIntegrator {
+    /// Maximum number of Newton iterations used to find each root.
+    const MAX_ITERATIONS: usize = 100;
+
+    /// Create a new `GaussLaguerreIntegrator` of a given order.
+    ///
+    /// # Panics
+    /// if `order` is zero.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(order: usize) -> Self {
+        assert!(order > 0, "Gauss-Laguerre order must be positive.");
+
+        let n = order as f64;
+        let mut nodes = Vec::with_capacity(order);
+        let mut weights = Vec::with_capacity(order);
+
+        let mut z = 0.0;
+        for i in 0..order {
+            // Initial guesses for the roots.
+            z = match i {
+                0 => 3.0 / 2.4f64.mul_add(n, 1.0),
+                1 => z + 15.0 / 2.5f64.mul_add(n, 1.0),
+                _ => {
+                    let ai = (i - 1) as f64;
+                    z + ((1.0 + 2.55 * ai) / (1.9 * ai)) * (z - nodes[i - 2])
+                }
+            };
+
+            // Refine with Newton's method.
+            let mut p2 = 0.0;
+            let mut pp = 0.0;
+            for _ in 0..Self::MAX_ITERATIONS {
+                let mut p1 = 1.0;
+                p2 = 0.0;
+                for j in 0..order {
+                    let p3 = p2;
+                    p2 = p1;
+                    let j = j as f64;
+                    p1 = ((2.0f64.mul_add(j, 1.0) - z) * p2 - j * p3) / (j + 1.0);
+                }
+                pp = n * (p1 - p2) / z;
+                let z1 = z;
+                z = z1 - p1 / pp;
+                if (z - z1).abs() <= 3.0e-14 * z.abs() {
+                    break;
+                }
+            }
+
+            nodes.push(z);
+            // w_i = -1 / (n L_n'(x_i) L_{n-1}(x_i)), scaled by e^{x_i} in log space to avoid
+            // overflow at the largest nodes.
+            weights.push((z - (pp * n * p2).abs().ln()).exp());
+        }
+
+        Self { nodes, weights }
+    }
+
+    /// The quadrature nodes.
+    #[must_use]
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// The quadrature weights (already scaled by $e^{x_i}$).
+    #[must_use]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Integrate a function over $[0, \infty)$.
+    pub fn integrate<F>(&self, f: F) -> f64
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.nodes
+            .iter()
+            .zip(&self.weights)
+            .map(|(x, w)| w * f(*x))
+            .sum()
+    }
+}
+
+impl Default for GaussLaguerreIntegrator {
+    /// Create a new `GaussLaguerreIntegrator` of order 128.
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_gauss_laguerre_integrator() {
+        let integrator = GaussLaguerreIntegrator::new(32);
+        assert_eq!(integrator.nodes().len(), 32);
+        assert_eq!(integrator.weights().len(), 32);
+
+        // int_0^inf e^{-x} x^2 dx = 2
+        assert_approx_eq!(integrator.integrate(|x| (-x).exp() * x * x), 2.0, 10e-10);
+
+        // int_0^inf e^{-2x} cos(x) dx = 2 / 5
+        assert_approx_eq!(
+            GaussLaguerreIntegrator::default().integrate(|x| (-2.0 * x).exp() * x.cos()),
+            0.4,
+            10e-10
+        );
+
+        // int_0^inf e^{-x^2} dx = sqrt(pi) / 2
+        assert_approx_eq!(
+            GaussLaguerreIntegrator::default().integrate(|x| (-x * x).exp()),
+            std::f64::consts::PI.sqrt() / 2.0,
+            10e-8
+        );
+    }
+}
@@ -0,0 +1,2 @@
+mod gauss_laguerre;
+pub use gauss_laguerre::GaussLaguerreIntegrator;
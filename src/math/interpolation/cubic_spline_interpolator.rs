@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+
+use ordered_float::OrderedFloat;
+
+use crate::math::{
+    interpolation::{InterpolationIndex, Interpolator},
+    FloatScalable,
+};
+
+use super::InterpolationResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition.
+//  ------------------------------------------------------------------------------------------------
+
+/// Natural cubic spline interpolator.
+///
+/// A piecewise cubic which is twice continuously differentiable, with zero second derivative at
+/// the end points. With only two points it reduces to linear interpolation.
+///
+/// For more details on the mathematics of spline interpolation, see [Wikipedia](https://en.wikipedia.org/wiki/Spline_interpolation).
+#[derive(Debug, Clone)]
+pub struct CubicSplineInterpolator<I, V>
+where
+    I: InterpolationIndex,
+    V: FloatScalable,
+{
+    points: BTreeMap<I, V>,
+
+    /// The points as vectors, in increasing order of index.
+    xs: Vec<OrderedFloat<f64>>,
+    ys: Vec<V>,
+
+    /// Second derivatives of the spline at the points, updated whenever the points change.
+    second_derivatives: Vec<V>,
+}
+
+impl<I, V> CubicSplineInterpolator<I, V>
+where
+    I: InterpolationIndex,
+    V: FloatScalable,
+{
+    /// Create a new `CubicSplineInterpolator`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            points: BTreeMap::new(),
+            xs: Vec::new(),
+            ys: Vec::new(),
+            second_derivatives: Vec::new(),
+        }
+    }
+
+    /// Create a new `CubicSplineInterpolator` from a set of points.
+    #[must_use]
+    pub fn new_from_points(points: Vec<(I, V)>) -> Self {
+        let mut ret = Self::new();
+        ret.add_points(points);
+        ret
+    }
+
+    /// Refit the spline after the points have changed.
+    fn fit(&mut self) {
+        self.xs = self.points.keys().map(|x| (*x).into()).collect();
+        self.ys = self.points.values().cloned().collect();
+        self.second_derivatives = Self::second_derivatives(&self.xs, &self.ys);
+    }
+
+    /// Second derivatives of the spline at each point, from the tridiagonal system
+    /// $$
+    ///     h_{i - 1} M_{i - 1} + 2 (h_{i - 1} + h_i) M_i + h_i M_{i + 1}
+    ///         = 6 \left( \frac{y_{i + 1} - y_i}{h_i} - \frac{y_i - y_{i - 1}}{h_{i - 1}} \right)
+    /// $$
+    /// with $M_0 = M_n = 0$, solved with the Thomas algorithm.
+    fn second_derivatives(xs: &[OrderedFloat<f64>], ys: &[V]) -> Vec<V> {
+        let n = xs.len();
+        let mut second_derivatives = vec![V::default(); n];
+        if n < 3 {
+            return second_derivatives;
+        }
+
+        let h: Vec<OrderedFloat<f64>> = xs.windows(2).map(|x| x[1] - x[0]).collect();
+
+        // Forward sweep over the interior points.
+        let mut c_prime = vec![OrderedFloat(0.0); n];
+        let mut d_prime = vec![V::default(); n];
+        for i in 1..n - 1 {
+            let rhs = ((ys[i + 1].clone() - ys[i].clone()) / h[i]
+                - (ys[i].clone() - ys[i - 1].clone()) / h[i - 1])
+                * OrderedFloat(6.0);
+            let pivot = OrderedFloat(2.0) * (h[i - 1] + h[i]) - h[i - 1] * c_prime[i - 1];
+            c_prime[i] = h[i] / pivot;
+            d_prime[i] = (rhs - d_prime[i - 1].clone() * h[i - 1]) / pivot;
+        }
+
+        // Back substitution.
+        for i in (1..n - 1).rev() {
+            second_derivatives[i] =
+                d_prime[i].clone() - second_derivatives[i + 1].clone() * c_prime[i];
+        }
+
+        second_derivatives
+    }
+}
+
+impl<I, V> Default for CubicSplineInterpolator<I, V>
+where
+    I: InterpolationIndex,
+    V: FloatScalable,
+{
+    /// Create a new `CubicSplineInterpolator`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations.
+//  ------------------------------------------------------------------------------------------------
+
+impl<I, V> Interpolator<I, V> for CubicSplineInterpolator<I, V>
+where
+    I: InterpolationIndex,
+    V: FloatScalable,
+{
+    /// Adds a point to the `CubicSplineInterpolator`.
+    ///
+    /// If the interpolator did not have this index present, `None` is returned.
+    /// If the interpolator already had a point at this index, the value is updated
+    /// and the old value is returned.
+    fn add_point(&mut self, point: (I, V)) -> Option<V> {
+        let previous = self.points.insert(point.0, point.1);
+        self.fit();
+        previous
+    }
+
+    /// Adds multiple points to the `CubicSplineInterpolator`.
+    ///
+    /// Each insertion returns a value as described in [`CubicSplineInterpolator::add_point`], and is returned as a `Vec`.
+    fn add_points(&mut self, points: Vec<(I, V)>) -> Vec<Option<V>> {
+        let previous = points
+            .into_iter()
+            .map(|pt| self.points.insert(pt.0, pt.1))
+            .collect();
+        self.fit();
+        previous
+    }
+
+    /// Removes a point from the `CubicSplineInterpolator`.
+    ///
+    /// If the interpolator contained the point, it returns the value at the point.
+    /// If the key was not in the interpolator, `None` is returned.
+    fn remove_point(&mut self, point: I) -> Option<V> {
+        let previous = self.points.remove(&point);
+        self.fit();
+        previous
+    }
+
+    /// Removes multiple points from the `CubicSplineInterpolator`.
+    ///
+    /// Each deletion returns a value as described by [`CubicSplineInterpolator::remove_point`], and is returned as a `Vec`.
+    fn remove_points(&mut self, points: Vec<I>) -> Vec<Option<V>> {
+        let previous = points
+            .into_iter()
+            .map(|pt| self.points.remove(&pt))
+            .collect();
+        self.fit();
+        previous
+    }
+
+    /// Interpolate at a point.
+    ///
+    /// Returns [`InterpolationResult::OutOfRange`] if the point is out of the range of the interpolator.
+    /// Returns [`InterpolationResult::ExistingValue`] if the point was one of the given points (no interpolation necessary).
+    /// Returns [`InterpolationResult::InterpolatedValue`] is the point required interpolation.
+    /// Returns [`InterpolationResult::NoPoints`] if the interpolator does not have any points.
+    fn interpolate(&self, point: I) -> InterpolationResult<V> {
+        // Check if the interpolator is empty.
+        let Some((x_min, x_max)) = self.range() else {
+            return InterpolationResult::NoPoints;
+        };
+
+        // Check if point is in the range of the interpolator.
+        if point < x_min || point > x_max {
+            return InterpolationResult::OutOfRange;
+        }
+
+        // Check if the point is already provided. If so, no need to interpolate.
+        if let Some(y) = self.points.get(&point) {
+            return InterpolationResult::ExistingValue(y.clone());
+        }
+
+        let (xs, ys, second_derivatives) = (&self.xs, &self.ys, &self.second_derivatives);
+        let x: OrderedFloat<f64> = point.into();
+        let i = xs.partition_point(|x_i| *x_i < x) - 1;
+        let h = xs[i + 1] - xs[i];
+        let a = (xs[i + 1] - x) / h;
+        let b = OrderedFloat(1.0) - a;
+
+        let val: V = ys[i].clone() * a
+            + ys[i + 1].clone() * b
+            + (second_derivatives[i].clone() * (a * a * a - a)
+                + second_derivatives[i + 1].clone() * (b * b * b - b))
+                * (h * h)
+                / OrderedFloat(6.0);
+
+        InterpolationResult::InterpolatedValue(val)
+    }
+
+    /// Returns the effective interpolation range of the `CubicSplineInterpolator`.
+    fn range(&self) -> Option<(I, I)> {
+        if self.points.is_empty() {
+            None
+        } else {
+            Some((
+                // Safe to unwrap here, because we checked to make sure there were points in the interpolator.
+                *self.points.first_key_value().unwrap().0,
+                *self.points.last_key_value().unwrap().0,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_cubic_spline_interpolator() {
+        let interpolator: CubicSplineInterpolator<OrderedFloat<f64>, OrderedFloat<f64>> =
+            CubicSplineInterpolator::new();
+        assert_eq!(
+            interpolator.interpolate(OrderedFloat(1.0)),
+            InterpolationResult::NoPoints
+        );
+
+        // A natural spline reproduces linear functions exactly.
+        let interpolator = CubicSplineInterpolator::new_from_points(
+            (0..5)
+                .map(|i| {
+                    (
+                        OrderedFloat(f64::from(i * i)),
+                        OrderedFloat(3.0 * f64::from(i * i) - 1.0),
+                    )
+                })
+                .collect(),
+        );
+        match interpolator.interpolate(OrderedFloat(5.0)) {
+            InterpolationResult::InterpolatedValue(y) => assert_approx_eq!(*y, 14.0),
+            _ => panic!("Expected an interpolated value."),
+        }
+        assert_eq!(
+            interpolator.interpolate(OrderedFloat(17.0)),
+            InterpolationResult::OutOfRange
+        );
+
+        // Natural spline through (0, 0), (1, 1), (2, 0): M_1 = -3, so s(0.5) = 0.6875.
+        let interpolator = CubicSplineInterpolator::new_from_points(vec![
+            (OrderedFloat(0.0), OrderedFloat(0.0)),
+            (OrderedFloat(1.0), OrderedFloat(1.0)),
+            (OrderedFloat(2.0), OrderedFloat(0.0)),
+        ]);
+        match interpolator.interpolate(OrderedFloat(0.5)) {
+            InterpolationResult::InterpolatedValue(y) => assert_approx_eq!(*y, 0.6875),
+            _ => panic!("Expected an interpolated value."),
+        }
+
+        // The spline is refitted when points change: through (0, 0), (1, 0), (2, 0) it is zero.
+        let mut interpolator = interpolator;
+        interpolator.add_point((OrderedFloat(1.0), OrderedFloat(0.0)));
+        match interpolator.interpolate(OrderedFloat(0.5)) {
+            InterpolationResult::InterpolatedValue(y) => assert_approx_eq!(*y, 0.0),
+            _ => panic!("Expected an interpolated value."),
+        }
+        interpolator.remove_point(OrderedFloat(1.0));
+        interpolator.add_point((OrderedFloat(1.0), OrderedFloat(1.0)));
+        match interpolator.interpolate(OrderedFloat(0.5)) {
+            InterpolationResult::InterpolatedValue(y) => assert_approx_eq!(*y, 0.6875),
+            _ => panic!("Expected an interpolated value."),
+        }
+    }
+}
@@ -51,4 +51,10 @@ where
 
 mod linear_interpolator;
 pub use linear_interpolator::LinearInterpolator;
+
+mod cubic_spline_interpolator;
+pub use cubic_spline_interpolator::CubicSplineInterpolator;
+
+mod bicubic_interpolator;
+pub use bicubic_interpolator::BicubicInterpolator;
 use ordered_float::OrderedFloat;
@@ -1,4 +1,13 @@
 mod float_like;
 pub use float_like::FloatScalable;
 
+mod fft;
+pub use fft::fft;
+
 pub mod interpolation;
+
+pub mod integration;
+
+pub mod optimization;
+
+pub mod solvers;
@@ -0,0 +1,19 @@
+mod nelder_mead;
+pub use nelder_mead::NelderMead;
+
+/// Result of a minimisation.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizationResult {
+    /// Location of the minimum.
+    pub x: Vec<f64>,
+
+    /// Value of the objective function at the minimum.
+    pub value: f64,
+
+    /// Number of iterations performed.
+    pub iterations: usize,
+
+    /// Whether the convergence criterion was met before the iteration limit.
+    pub converged: bool,
+}
@@ -0,0 +1,156 @@
+use crate::math::optimization::OptimizationResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition.
+//  ------------------------------------------------------------------------------------------------
+
+/// Nelder-Mead downhill simplex minimiser.
+///
+/// A derivative-free method for unconstrained minimisation of functions of a few variables. The
+/// simplex is reflected, expanded, contracted and shrunk (with the standard coefficients
+/// $1, 2, \frac{1}{2}, \frac{1}{2}$) until the spread of the objective values across its vertices
+/// falls below the tolerance.
+#[derive(Debug, Copy, Clone)]
+pub struct NelderMead {
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl NelderMead {
+    /// Create a new `NelderMead` minimiser.
+    #[must_use]
+    pub const fn new(max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+        }
+    }
+
+    /// Minimise a function, starting from a simplex around `initial` whose edges have length
+    /// `initial_step` along each coordinate axis.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn minimize<F>(&self, f: F, initial: &[f64], initial_step: f64) -> OptimizationResult
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        let n = initial.len();
+
+        let mut simplex: Vec<Vec<f64>> = Vec::with_capacity(n + 1);
+        simplex.push(initial.to_vec());
+        for i in 0..n {
+            let mut vertex = initial.to_vec();
+            vertex[i] += initial_step;
+            simplex.push(vertex);
+        }
+        let mut values: Vec<f64> = simplex.iter().map(|x| f(x)).collect();
+
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < self.max_iterations {
+            // Order the vertices by objective value.
+            let mut order: Vec<usize> = (0..=n).collect();
+            order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+            simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+            values = order.iter().map(|&i| values[i]).collect();
+
+            if (values[n] - values[0]).abs() <= self.tolerance {
+                converged = true;
+                break;
+            }
+            iterations += 1;
+
+            // Centroid of all vertices but the worst.
+            let centroid: Vec<f64> = (0..n)
+                .map(|j| simplex[..n].iter().map(|x| x[j]).sum::<f64>() / n as f64)
+                .collect();
+            let towards = |coefficient: f64| -> Vec<f64> {
+                centroid
+                    .iter()
+                    .zip(&simplex[n])
+                    .map(|(c, w)| coefficient.mul_add(w - c, *c))
+                    .collect()
+            };
+
+            let reflected = towards(-1.0);
+            let reflected_value = f(&reflected);
+            if reflected_value < values[0] {
+                let expanded = towards(-2.0);
+                let expanded_value = f(&expanded);
+                if expanded_value < reflected_value {
+                    simplex[n] = expanded;
+                    values[n] = expanded_value;
+                } else {
+                    simplex[n] = reflected;
+                    values[n] = reflected_value;
+                }
+            } else if reflected_value < values[n - 1] {
+                simplex[n] = reflected;
+                values[n] = reflected_value;
+            } else {
+                let contracted = if reflected_value < values[n] {
+                    towards(-0.5)
+                } else {
+                    towards(0.5)
+                };
+                let contracted_value = f(&contracted);
+                if contracted_value < values[n].min(reflected_value) {
+                    simplex[n] = contracted;
+                    values[n] = contracted_value;
+                } else {
+                    // Shrink towards the best vertex.
+                    for i in 1..=n {
+                        simplex[i] = simplex[0]
+                            .iter()
+                            .zip(&simplex[i])
+                            .map(|(b, x)| 0.5f64.mul_add(x - b, *b))
+                            .collect();
+                        values[i] = f(&simplex[i]);
+                    }
+                }
+            }
+        }
+
+        let best = values
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map_or(0, |(i, _)| i);
+        OptimizationResult {
+            x: simplex[best].clone(),
+            value: values[best],
+            iterations,
+            converged,
+        }
+    }
+}
+
+impl Default for NelderMead {
+    /// Create a new `NelderMead` minimiser, with at most 10,000 iterations and a tolerance of
+    /// $10^{-14}$.
+    fn default() -> Self {
+        Self::new(10_000, 1e-14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_nelder_mead() {
+        // Rosenbrock function, with minimum at (1, 1).
+        let rosenbrock = |x: &[f64]| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+        let result = NelderMead::default().minimize(rosenbrock, &[-1.2, 1.0], 0.5);
+        assert!(result.converged);
+        assert_approx_eq!(result.x[0], 1.0, 10e-5);
+        assert_approx_eq!(result.x[1], 1.0, 10e-5);
+        assert_approx_eq!(result.value, 0.0, 10e-10);
+
+        // Iteration limit.
+        let result = NelderMead::new(5, 1e-14).minimize(rosenbrock, &[-1.2, 1.0], 0.5);
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 5);
+    }
+}
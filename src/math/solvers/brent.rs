@@ -0,0 +1,146 @@
+use crate::math::solvers::SolverError;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition.
+//  ------------------------------------------------------------------------------------------------
+
+/// Brent's root finder.
+///
+/// Combines bisection, the secant method and inverse quadratic interpolation: it converges
+/// superlinearly for smooth functions while never doing worse than bisection on the bracketing
+/// interval.
+#[derive(Debug, Copy, Clone)]
+pub struct Brent {
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl Brent {
+    /// Create a new `Brent` root finder.
+    #[must_use]
+    pub const fn new(max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+        }
+    }
+
+    /// Find a root of `f` in `[lower, upper]`, where `f` must change sign.
+    ///
+    /// # Errors
+    /// Will return `NotBracketed` if `f` has the same sign at both ends of the interval, and
+    /// `NoConvergence` if the root was not located to within the tolerance in the maximum number
+    /// of iterations.
+    pub fn solve<F>(&self, mut f: F, lower: f64, upper: f64) -> Result<f64, SolverError>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let (mut a, mut b) = (lower, upper);
+        let (mut fa, mut fb) = (f(a), f(b));
+
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fb == 0.0 {
+            return Ok(b);
+        }
+        if fa.signum() == fb.signum() {
+            return Err(SolverError::NotBracketed);
+        }
+
+        let (mut c, mut fc) = (a, fa);
+        let mut d = b - a;
+        let mut e = d;
+
+        for _ in 0..self.max_iterations {
+            if fb.signum() == fc.signum() {
+                (c, fc) = (a, fa);
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs() {
+                (a, fa) = (b, fb);
+                (b, fb) = (c, fc);
+                (c, fc) = (a, fa);
+            }
+
+            let tolerance = (2.0 * f64::EPSILON).mul_add(b.abs(), 0.5 * self.tolerance);
+            let m = 0.5 * (c - b);
+            if m.abs() <= tolerance || fb == 0.0 {
+                return Ok(b);
+            }
+
+            if e.abs() >= tolerance && fa.abs() > fb.abs() {
+                // Attempt inverse quadratic interpolation (or the secant method).
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (2.0 * m * s, 1.0 - s)
+                } else {
+                    let q = fa / fc;
+                    let r = fb / fc;
+                    (
+                        s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+                if p > 0.0 {
+                    q = -q;
+                } else {
+                    p = -p;
+                }
+
+                if 2.0 * p < (3.0 * m * q - (tolerance * q).abs()).min((e * q).abs()) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = m;
+                    e = m;
+                }
+            } else {
+                // Bisection.
+                d = m;
+                e = m;
+            }
+
+            (a, fa) = (b, fb);
+            b += if d.abs() > tolerance {
+                d
+            } else {
+                tolerance.copysign(m)
+            };
+            fb = f(b);
+        }
+
+        Err(SolverError::NoConvergence)
+    }
+}
+
+impl Default for Brent {
+    /// Create a new `Brent` root finder, with 100 iterations and a tolerance of $10^{-12}$.
+    fn default() -> Self {
+        Self::new(100, 1e-12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_brent() {
+        let brent = Brent::default();
+
+        let root = brent.solve(|x| x * x - 2.0, 0.0, 2.0).unwrap();
+        assert_approx_eq!(root, 2.0f64.sqrt(), 10e-12);
+
+        let root = brent.solve(|x| x.cos() - x, 0.0, 1.0).unwrap();
+        assert_approx_eq!(root, 0.739_085_133_215_160_6, 10e-12);
+
+        assert_eq!(
+            brent.solve(|x| x * x + 1.0, -1.0, 1.0),
+            Err(SolverError::NotBracketed)
+        );
+    }
+}
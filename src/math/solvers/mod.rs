@@ -0,0 +1,13 @@
+mod brent;
+pub use brent::Brent;
+
+/// Reasons a one-dimensional root finder can fail.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SolverError {
+    /// The function has the same sign at both ends of the interval.
+    NotBracketed,
+
+    /// The tolerance was not met within the maximum number of iterations.
+    NoConvergence,
+}
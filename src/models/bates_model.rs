@@ -0,0 +1,125 @@
+use num::complex::Complex64;
+
+use crate::models::merton_jump_diffusion_model::{normal_jump_cumulants, normal_jump_exponent};
+use crate::models::{CharacteristicFunction, HestonModel};
+
+/// Bates model.
+///
+/// Heston stochastic volatility with Merton-style log-normal jumps of intensity $\lambda$, mean
+/// log-jump $\mu_J$ and log-jump volatility $\sigma_J$. As the jumps are independent of the
+/// diffusion, the characteristic function factorises:
+/// $$
+///     \phi_{\text{Bates}}(u, t) = \phi_{\text{Heston}}(u, t) \\,
+///         \exp \left( t \lambda \left( e^{iu\mu_J - \frac{1}{2} \sigma_J^2 u^2} - 1
+///         - iu (e^{\mu_J + \frac{1}{2} \sigma_J^2} - 1) \right) \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BatesModel {
+    heston: HestonModel,
+
+    /// Jump intensity $\lambda$.
+    lambda: f64,
+
+    /// Mean log-jump size $\mu_J$.
+    jump_mean: f64,
+
+    /// Log-jump size volatility $\sigma_J$.
+    jump_volatility: f64,
+}
+
+impl BatesModel {
+    /// Create a new `BatesModel`.
+    #[must_use]
+    pub const fn new(
+        heston: HestonModel,
+        lambda: f64,
+        jump_mean: f64,
+        jump_volatility: f64,
+    ) -> Self {
+        Self {
+            heston,
+            lambda,
+            jump_mean,
+            jump_volatility,
+        }
+    }
+
+    /// Get the underlying Heston dynamics.
+    #[inline]
+    #[must_use]
+    pub const fn get_heston_model(&self) -> HestonModel {
+        self.heston
+    }
+
+    /// Get the jump intensity.
+    #[inline]
+    #[must_use]
+    pub const fn get_lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Get the mean log-jump size.
+    #[inline]
+    #[must_use]
+    pub const fn get_jump_mean(&self) -> f64 {
+        self.jump_mean
+    }
+
+    /// Get the log-jump size volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_jump_volatility(&self) -> f64 {
+        self.jump_volatility
+    }
+}
+
+impl CharacteristicFunction for BatesModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let jumps = normal_jump_exponent(u, self.lambda, self.jump_mean, self.jump_volatility);
+        self.heston.characteristic_function(u, t) * (t * jumps).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let (h1, h2, h4) = self.heston.cumulants(t);
+        let (j1, j2, j4) = normal_jump_cumulants(self.lambda, self.jump_mean, self.jump_volatility);
+        (t.mul_add(j1, h1), t.mul_add(j2, h2), t.mul_add(j4, h4))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_bates_model() {
+        let model = BatesModel::new(
+            HestonModel::new(0.04, 1.5, 0.04, 0.3, -0.7),
+            0.5,
+            -0.1,
+            0.15,
+        );
+        assert_eq!(model.get_heston_model().get_v0(), 0.04);
+        assert_eq!(model.get_lambda(), 0.5);
+        assert_eq!(model.get_jump_mean(), -0.1);
+        assert_eq!(model.get_jump_volatility(), 0.15);
+
+        // Without jumps the model is the Heston model.
+        let heston = HestonModel::new(0.04, 1.5, 0.04, 0.3, -0.7);
+        let u = Complex64::new(1.3, -0.4);
+        let phi = BatesModel::new(
+            HestonModel::new(0.04, 1.5, 0.04, 0.3, -0.7),
+            0.0,
+            -0.1,
+            0.15,
+        )
+        .characteristic_function(u, 1.0);
+        let heston_phi = heston.characteristic_function(u, 1.0);
+        assert_approx_eq!(phi.re, heston_phi.re, 10e-14);
+        assert_approx_eq!(phi.im, heston_phi.im, 10e-14);
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -0,0 +1,64 @@
+use num::complex::Complex64;
+
+use crate::models::CharacteristicFunction;
+
+/// Black-Scholes model.
+///
+/// The spot follows a geometric Brownian motion with constant volatility $\sigma$:
+/// $$
+///     \phi(u, t) = \exp \left( -\frac{1}{2} \sigma^2 t (iu + u^2) \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BlackScholesModel {
+    /// Volatility $\sigma$.
+    sigma: f64,
+}
+
+impl BlackScholesModel {
+    /// Create a new `BlackScholesModel`.
+    #[must_use]
+    pub const fn new(sigma: f64) -> Self {
+        Self { sigma }
+    }
+
+    /// Get the volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+impl CharacteristicFunction for BlackScholesModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        (-0.5 * self.sigma * self.sigma * t * (i * u + u * u)).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let variance = self.sigma * self.sigma * t;
+        (-0.5 * variance, variance, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_black_scholes_model() {
+        let model = BlackScholesModel::new(0.2);
+        assert_eq!(model.get_sigma(), 0.2);
+
+        // Lognormal with variance sigma^2 t, drifting by -sigma^2 t / 2.
+        let (c1, c2, c4) = model.cumulants(2.0);
+        assert_approx_eq!(c1, -0.04, 10e-15);
+        assert_approx_eq!(c2, 0.08, 10e-15);
+        assert_approx_eq!(c4, 0.0, 10e-15);
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -0,0 +1,124 @@
+use num::complex::Complex64;
+use statrs::function::gamma::gamma;
+
+use crate::models::CharacteristicFunction;
+
+/// CGMY model (Carr, Geman, Madan & Yor).
+///
+/// A pure-jump tempered stable process with Lévy density
+/// $$
+///     k(x) = C \frac{e^{-G|x|}}{|x|^{1+Y}} \mathbb{1}_{x < 0}
+///         + C \frac{e^{-Mx}}{x^{1+Y}} \mathbb{1}_{x > 0}
+/// $$
+/// and characteristic function
+/// $$
+///     \phi(u, t) = \exp \left( t \left( iu\omega + C \Gamma(-Y) \left[ (M - iu)^Y - M^Y
+///         + (G + iu)^Y - G^Y \right] \right) \right)
+/// $$
+/// where $\omega$ is the martingale correction. $Y$ must be below 2 and different from 0 and 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CgmyModel {
+    /// Overall activity $C$.
+    c: f64,
+
+    /// Exponential decay of the left tail $G$.
+    g: f64,
+
+    /// Exponential decay of the right tail $M$.
+    m: f64,
+
+    /// Fine structure $Y$.
+    y: f64,
+}
+
+impl CgmyModel {
+    /// Create a new `CgmyModel`.
+    #[must_use]
+    pub const fn new(c: f64, g: f64, m: f64, y: f64) -> Self {
+        Self { c, g, m, y }
+    }
+
+    /// Get the overall activity.
+    #[inline]
+    #[must_use]
+    pub const fn get_c(&self) -> f64 {
+        self.c
+    }
+
+    /// Get the left-tail decay.
+    #[inline]
+    #[must_use]
+    pub const fn get_g(&self) -> f64 {
+        self.g
+    }
+
+    /// Get the right-tail decay.
+    #[inline]
+    #[must_use]
+    pub const fn get_m(&self) -> f64 {
+        self.m
+    }
+
+    /// Get the fine structure.
+    #[inline]
+    #[must_use]
+    pub const fn get_y(&self) -> f64 {
+        self.y
+    }
+
+    /// Lévy exponent (per unit time), without the martingale correction.
+    fn levy_exponent(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        self.c
+            * gamma(-self.y)
+            * ((self.m - i * u).powf(self.y) - self.m.powf(self.y) + (self.g + i * u).powf(self.y)
+                - self.g.powf(self.y))
+    }
+
+    fn omega(&self) -> f64 {
+        -self.levy_exponent(Complex64::new(0.0, -1.0)).re
+    }
+}
+
+impl CharacteristicFunction for CgmyModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        (t * (i * u * self.omega() + self.levy_exponent(u))).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let (c, g, m, y) = (self.c, self.g, self.m, self.y);
+        (
+            t * (c * gamma(1.0 - y)).mul_add(m.powf(y - 1.0) - g.powf(y - 1.0), self.omega()),
+            t * c * gamma(2.0 - y) * (m.powf(y - 2.0) + g.powf(y - 2.0)),
+            t * c * gamma(4.0 - y) * (m.powf(y - 4.0) + g.powf(y - 4.0)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_cgmy_model() {
+        let model = CgmyModel::new(1.0, 5.0, 5.0, 0.5);
+        assert_eq!(model.get_c(), 1.0);
+        assert_eq!(model.get_g(), 5.0);
+        assert_eq!(model.get_m(), 5.0);
+        assert_eq!(model.get_y(), 0.5);
+
+        // With G = M the variance is 2 C Gamma(2 - Y) M^(Y - 2) t, where Gamma(3 / 2) = sqrt(pi) / 2.
+        let gamma = 0.5 * std::f64::consts::PI.sqrt();
+        assert_approx_eq!(
+            model.cumulants(1.0).1,
+            2.0 * gamma * 5.0f64.powf(-1.5),
+            10e-12
+        );
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -0,0 +1,47 @@
+use num::complex::Complex64;
+
+/// Characteristic function of a model's log-price.
+///
+/// Implementors describe the distribution of the forward-normalised log-price
+/// $$
+///     X_t = \ln \frac{S_t}{F_t}
+/// $$
+/// under the risk-neutral measure, so that the deterministic drift from rates and dividends is
+/// left to the pricer. In particular, the martingale condition $\phi(-i, t) = E[S_t / F_t] = 1$
+/// must hold.
+pub trait CharacteristicFunction {
+    /// The characteristic function $\phi(u, t) = E[e^{iuX_t}]$.
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64;
+
+    /// The first, second and fourth cumulants $(c_1, c_2, c_4)$ of $X_t$.
+    ///
+    /// These are used to choose truncation ranges for Fourier-cosine expansions.
+    fn cumulants(&self, t: f64) -> (f64, f64, f64);
+}
+
+/// Check the properties every characteristic function must have at time $t = 1$: $\phi(0) = 1$,
+/// the martingale condition $\phi(-i) = 1$, and the first two cumulants against finite
+/// differences of the cumulant generating function $\ln \phi(-ix)$.
+#[cfg(test)]
+pub(crate) fn assert_characteristic_function_consistent(model: &impl CharacteristicFunction) {
+    use assert_approx_eq::assert_approx_eq;
+    use num::complex::Complex64;
+
+    let phi_zero = model.characteristic_function(Complex64::new(0.0, 0.0), 1.0);
+    assert_approx_eq!(phi_zero.re, 1.0, 10e-12);
+    assert_approx_eq!(phi_zero.im, 0.0, 10e-12);
+    let phi_minus_i = model.characteristic_function(Complex64::new(0.0, -1.0), 1.0);
+    assert_approx_eq!(phi_minus_i.re, 1.0, 10e-10);
+    assert_approx_eq!(phi_minus_i.im, 0.0, 10e-10);
+
+    let h = 1e-3;
+    let cgf = |x: f64| {
+        model
+            .characteristic_function(Complex64::new(0.0, -x), 1.0)
+            .ln()
+            .re
+    };
+    let (c1, c2, _) = model.cumulants(1.0);
+    assert_approx_eq!(c1, (cgf(h) - cgf(-h)) / (2.0 * h), 10e-7);
+    assert_approx_eq!(c2, (cgf(h) - 2.0 * cgf(0.0) + cgf(-h)) / (h * h), 10e-6);
+}
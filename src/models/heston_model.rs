@@ -0,0 +1,200 @@
+use num::complex::Complex64;
+
+use crate::models::CharacteristicFunction;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Heston stochastic volatility model.
+///
+/// Under the risk-neutral measure, the spot and its instantaneous variance follow:
+/// $$
+///     dS_t = (r - q) S_t \\, dt + \sqrt{v_t} S_t \\, dW^S_t
+/// $$
+/// $$
+///     dv_t = \kappa (\theta - v_t) \\, dt + \sigma \sqrt{v_t} \\, dW^v_t
+/// $$
+/// with $d\langle W^S, W^v \rangle_t = \rho \\, dt$.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HestonModel {
+    /// Initial variance $v_0$.
+    v0: f64,
+
+    /// Mean-reversion speed $\kappa$.
+    kappa: f64,
+
+    /// Long-run variance $\theta$.
+    theta: f64,
+
+    /// Volatility of variance $\sigma$.
+    sigma: f64,
+
+    /// Correlation between the spot and variance processes $\rho$.
+    rho: f64,
+}
+
+impl HestonModel {
+    /// Create a new `HestonModel`.
+    #[must_use]
+    pub const fn new(v0: f64, kappa: f64, theta: f64, sigma: f64, rho: f64) -> Self {
+        Self {
+            v0,
+            kappa,
+            theta,
+            sigma,
+            rho,
+        }
+    }
+
+    /// Get the initial variance.
+    #[inline]
+    #[must_use]
+    pub const fn get_v0(&self) -> f64 {
+        self.v0
+    }
+
+    /// Get the mean-reversion speed.
+    #[inline]
+    #[must_use]
+    pub const fn get_kappa(&self) -> f64 {
+        self.kappa
+    }
+
+    /// Get the long-run variance.
+    #[inline]
+    #[must_use]
+    pub const fn get_theta(&self) -> f64 {
+        self.theta
+    }
+
+    /// Get the volatility of variance.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Get the spot/variance correlation.
+    #[inline]
+    #[must_use]
+    pub const fn get_rho(&self) -> f64 {
+        self.rho
+    }
+
+    /// Whether the Feller condition $2 \kappa \theta > \sigma^2$ holds, i.e. whether the variance
+    /// process stays strictly positive.
+    #[must_use]
+    pub fn satisfies_feller_condition(&self) -> bool {
+        2.0 * self.kappa * self.theta > self.sigma * self.sigma
+    }
+}
+
+impl HestonModel {
+    /// Log of the Heston characteristic function, in Gatheral's formulation.
+    ///
+    /// For $j \in \\{0, 1\\}$ this returns $C_j(u, \tau) \theta + D_j(u, \tau) v_0$, where:
+    /// $$
+    ///     \alpha = -\frac{u^2}{2} - \frac{iu}{2} + iju, \quad
+    ///     \beta = \kappa - \rho \sigma j - \rho \sigma i u, \quad
+    ///     d = \sqrt{\beta^2 - 2 \alpha \sigma^2}
+    /// $$
+    /// $$
+    ///     r_\pm = \frac{\beta \pm d}{\sigma^2}, \quad g = \frac{r_-}{r_+}
+    /// $$
+    /// $$
+    ///     D_j = r_- \frac{1 - e^{-d \tau}}{1 - g e^{-d \tau}}, \quad
+    ///     C_j = \kappa \left( r_- \tau - \frac{2}{\sigma^2}
+    ///         \ln \frac{1 - g e^{-d \tau}}{1 - g} \right)
+    /// $$
+    ///
+    /// Using $e^{-d \tau}$ rather than $e^{d \tau}$ avoids the branch-cut discontinuity of the
+    /// complex logarithm (Albrecher et al.'s "little Heston trap", Lord & Kahl).
+    ///
+    /// $j = 0$ is the characteristic function of $\ln(S_T / F_T)$ under the risk-neutral measure,
+    /// $j = 1$ under the share measure.
+    pub(crate) fn log_characteristic_function(&self, u: Complex64, tau: f64, j: f64) -> Complex64 {
+        let i = Complex64::i();
+        let sigma2 = self.sigma * self.sigma;
+
+        let alpha = -0.5 * u * u - 0.5 * i * u + i * j * u;
+        let beta = self.kappa - self.rho * self.sigma * j - self.rho * self.sigma * i * u;
+        let d = (beta * beta - 2.0 * alpha * sigma2).sqrt();
+
+        let r_plus = (beta + d) / sigma2;
+        let r_minus = (beta - d) / sigma2;
+        let g = r_minus / r_plus;
+
+        let exp_d_tau = (-d * tau).exp();
+        let big_d = r_minus * (1.0 - exp_d_tau) / (1.0 - g * exp_d_tau);
+        let big_c = self.kappa
+            * (r_minus * tau - (2.0 / sigma2) * ((1.0 - g * exp_d_tau) / (1.0 - g)).ln());
+
+        big_c * self.theta + big_d * self.v0
+    }
+}
+
+impl CharacteristicFunction for HestonModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        self.log_characteristic_function(u, t, 0.0).exp()
+    }
+
+    /// Cumulants of the integrated variance decomposition of $X_t$, with $c_4$ neglected as is
+    /// customary (Fang & Oosterlee, 2008).
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let (v0, kappa, theta, sigma, rho) =
+            (self.v0, self.kappa, self.theta, self.sigma, self.rho);
+        let e1 = (-kappa * t).exp();
+        let e2 = (-2.0 * kappa * t).exp();
+
+        let c1 = (1.0 - e1) * (theta - v0) / (2.0 * kappa) - 0.5 * theta * t;
+        let c2 =
+            (sigma * t * kappa * e1 * (v0 - theta) * 8.0f64.mul_add(kappa * rho, -4.0 * sigma)
+                + kappa * rho * sigma * (1.0 - e1) * 16.0f64.mul_add(theta, -8.0 * v0)
+                + 2.0
+                    * theta
+                    * kappa
+                    * t
+                    * (4.0 * kappa)
+                        .mul_add(kappa, (-4.0 * kappa * rho).mul_add(sigma, sigma * sigma))
+                + sigma
+                    * sigma
+                    * (2.0f64.mul_add(
+                        v0,
+                        (theta - 2.0 * v0) * e2 + theta * 4.0f64.mul_add(e1, -5.0),
+                    ))
+                + 8.0 * kappa * kappa * (v0 - theta) * (1.0 - e1))
+                / (8.0 * kappa * kappa * kappa);
+
+        (c1, c2, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_heston_model() {
+        let model = HestonModel::new(0.0175, 1.5768, 0.0398, 0.5751, -0.5711);
+        assert_eq!(model.get_v0(), 0.0175);
+        assert_eq!(model.get_kappa(), 1.5768);
+        assert_eq!(model.get_theta(), 0.0398);
+        assert_eq!(model.get_sigma(), 0.5751);
+        assert_eq!(model.get_rho(), -0.5711);
+        assert!(!model.satisfies_feller_condition());
+        assert!(HestonModel::new(0.04, 2.0, 0.04, 0.3, -0.7).satisfies_feller_condition());
+
+        // phi(0) = 1 under the share measure too.
+        assert_approx_eq!(
+            model
+                .log_characteristic_function(Complex64::new(0.0, 0.0), 1.0, 1.0)
+                .norm(),
+            0.0
+        );
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -0,0 +1,392 @@
+use day_count_conventions::DayCounter;
+use hifitime::Unit;
+
+use crate::money::Currency;
+use crate::term_structures::{TermStructureError, YieldTermStructure};
+use crate::time::{DateTime, Duration};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Hull-White one-factor short rate model.
+///
+/// The short rate mean-reverts at a speed $a$ with a constant volatility $\sigma$:
+/// $$
+///     dr_t = \left( \theta(t) - a r_t \right) dt + \sigma \\, dW_t
+/// $$
+/// where the drift $\theta$ is fitted to the discount factors of a yield curve, see
+/// [`HullWhiteModel::tree`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HullWhiteModel {
+    /// Mean reversion speed $a$.
+    mean_reversion: f64,
+
+    /// Volatility $\sigma$ of the short rate.
+    sigma: f64,
+}
+
+/// Trinomial tree of the Hull-White short rate, fitted to a yield curve (Hull & White, 1994).
+///
+/// The short rate at step $i$ and node $j$ is $r_{i,j} = \alpha_i + j \\, \Delta x_i$, where the
+/// deviations $j \\, \Delta x_i$ follow the mean-reverting process $dx_t = -a x_t \\, dt + \sigma
+/// \\, dW_t$ from $x_0 = 0$, and the shifts $\alpha_i$ are such that the tree reprices the curve's
+/// discount factors at every step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HullWhiteTree {
+    /// Times of the steps, from the curve's reference date.
+    times: Vec<f64>,
+
+    /// Spacing $\Delta x_i$ of the nodes at each step.
+    spacings: Vec<f64>,
+
+    /// Index $j$ of the lowest node at each step.
+    lowest: Vec<i64>,
+
+    /// Branching from each node to the next step.
+    branchings: Vec<Vec<Branching>>,
+
+    /// Shift $\alpha_i$ of the short rate over each step but the last.
+    shifts: Vec<f64>,
+}
+
+/// Branching from a node to the three nodes around `middle` at the next step.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Branching {
+    middle: i64,
+    probabilities: [f64; 3],
+}
+
+impl HullWhiteModel {
+    /// Create a new `HullWhiteModel`.
+    #[must_use]
+    pub const fn new(mean_reversion: f64, sigma: f64) -> Self {
+        Self {
+            mean_reversion,
+            sigma,
+        }
+    }
+
+    /// Get the mean reversion speed.
+    #[inline]
+    #[must_use]
+    pub const fn get_mean_reversion(&self) -> f64 {
+        self.mean_reversion
+    }
+
+    /// Get the volatility of the short rate.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Variance of the short rate over a period of length `dt`.
+    fn variance(&self, dt: f64) -> f64 {
+        let a = self.mean_reversion;
+        if a == 0.0 {
+            self.sigma * self.sigma * dt
+        } else {
+            self.sigma * self.sigma * -(-2.0 * a * dt).exp_m1() / (2.0 * a)
+        }
+    }
+
+    /// Trinomial tree fitted to a curve, from its reference date to the last of `dates`.
+    ///
+    /// Every date after the reference date is a step of the tree, and the periods between them
+    /// are split into whole days so that there are at least `time_steps` steps in all. Dates at
+    /// the same time from the reference date share a step.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to a step.
+    pub fn tree<C, D>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        dates: &[DateTime],
+        time_steps: usize,
+    ) -> Result<HullWhiteTree, TermStructureError>
+    where
+        C: Currency,
+        D: DayCounter,
+    {
+        let reference_date = curve.get_reference_date();
+        let days = |start: DateTime, end: DateTime| (*end - *start).to_unit(Unit::Day);
+        let mut mandatory: Vec<DateTime> = dates
+            .iter()
+            .copied()
+            .filter(|&date| date > reference_date)
+            .collect();
+        mandatory.sort();
+        mandatory.dedup();
+
+        let end_date = mandatory.last().copied().unwrap_or(reference_date);
+        let step_days = (days(reference_date, end_date) / time_steps.max(1) as f64).max(1.0);
+        let mut grid = vec![reference_date];
+        for date in mandatory {
+            let start_date = *grid.last().expect("the grid starts at the reference date");
+            let period = days(start_date, date);
+            let steps = (period / step_days).ceil().max(1.0) as usize;
+            grid.extend((1..steps).map(|step| {
+                start_date + Duration::new_from_days((period * step as f64 / steps as f64).round())
+            }));
+            grid.push(date);
+        }
+
+        // Steps at the same time, such as the 30th and 31st of a month in a 30/360 convention,
+        // are merged.
+        let mut times = vec![0.0];
+        let mut discount_factors = vec![1.0];
+        for date in grid {
+            let time = curve.time_from_reference(date);
+            if time > *times.last().expect("the grid starts at the reference date") {
+                times.push(time);
+                discount_factors.push(*curve.discount_factor(date)?);
+            }
+        }
+
+        let mut spacings = vec![0.0];
+        let mut lowest = vec![0];
+        let mut branchings = Vec::with_capacity(times.len() - 1);
+        let mut shifts = Vec::with_capacity(times.len() - 1);
+        // Arrow-Debreu prices of the nodes at the current step.
+        let mut state_prices = vec![1.0];
+        for (i, period) in times.windows(2).enumerate() {
+            let dt = period[1] - period[0];
+            let std_dev = self.variance(dt).sqrt();
+            let spacing = std_dev * 3.0f64.sqrt();
+            let decay = (-self.mean_reversion * dt).exp();
+
+            let nodes: Vec<Branching> = (0..state_prices.len())
+                .map(|node| {
+                    if std_dev == 0.0 {
+                        return Branching {
+                            middle: 0,
+                            probabilities: [0.0, 1.0, 0.0],
+                        };
+                    }
+                    let expectation = (lowest[i] + node as i64) as f64 * spacings[i] * decay;
+                    let middle = (expectation / spacing).round() as i64;
+                    let error = (expectation - middle as f64 * spacing) / std_dev;
+                    let error2 = error * error;
+                    Branching {
+                        middle,
+                        probabilities: [
+                            (1.0 + error2 - error * 3.0f64.sqrt()) / 6.0,
+                            (2.0 - error2) / 3.0,
+                            (1.0 + error2 + error * 3.0f64.sqrt()) / 6.0,
+                        ],
+                    }
+                })
+                .collect();
+
+            // The shift discounts the Arrow-Debreu prices to the curve's discount factor.
+            let deviations = |node: usize| (lowest[i] + node as i64) as f64 * spacings[i];
+            let discounted: f64 = state_prices
+                .iter()
+                .enumerate()
+                .map(|(node, price)| price * (-deviations(node) * dt).exp())
+                .sum();
+            let shift = (discounted / discount_factors[i + 1]).ln() / dt;
+
+            let next_lowest = nodes
+                .iter()
+                .map(|branching| branching.middle)
+                .min()
+                .unwrap()
+                - 1;
+            let next_highest = nodes
+                .iter()
+                .map(|branching| branching.middle)
+                .max()
+                .unwrap()
+                + 1;
+            let mut next_prices = vec![0.0; (next_highest - next_lowest + 1) as usize];
+            for (node, branching) in nodes.iter().enumerate() {
+                let discount = (-(shift + deviations(node)) * dt).exp();
+                for (offset, probability) in branching.probabilities.iter().enumerate() {
+                    let next = (branching.middle - 1 - next_lowest) as usize + offset;
+                    next_prices[next] += state_prices[node] * probability * discount;
+                }
+            }
+
+            spacings.push(spacing);
+            lowest.push(next_lowest);
+            branchings.push(nodes);
+            shifts.push(shift);
+            state_prices = next_prices;
+        }
+
+        Ok(HullWhiteTree {
+            times,
+            spacings,
+            lowest,
+            branchings,
+            shifts,
+        })
+    }
+}
+
+impl HullWhiteTree {
+    /// Times of the steps from the curve's reference date, starting at zero.
+    #[must_use]
+    pub fn get_times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// The step at a time from the curve's reference date, if there is one.
+    #[must_use]
+    pub fn step_at(&self, time: f64) -> Option<usize> {
+        let step = self.times.partition_point(|&t| t < time);
+        (self.times.get(step) == Some(&time)).then_some(step)
+    }
+
+    /// Number of nodes at a step.
+    #[must_use]
+    pub fn size(&self, step: usize) -> usize {
+        self.branchings.get(step).map_or_else(
+            || {
+                let middles = self.branchings[step - 1]
+                    .iter()
+                    .map(|branching| branching.middle);
+                let highest = middles.max().unwrap() + 1;
+                (highest - self.lowest[step] + 1) as usize
+            },
+            Vec::len,
+        )
+    }
+
+    /// Values at a step of the values at the next step, discounted at the short rate plus a
+    /// continuously compounded spread.
+    ///
+    /// # Panics
+    /// if `step` is the last step, or `values` do not match the nodes of the next step.
+    #[must_use]
+    pub fn rollback(&self, step: usize, values: &[f64], spread: f64) -> Vec<f64> {
+        assert_eq!(values.len(), self.size(step + 1));
+        let dt = self.times[step + 1] - self.times[step];
+        self.branchings[step]
+            .iter()
+            .enumerate()
+            .map(|(node, branching)| {
+                let deviation = (self.lowest[step] + node as i64) as f64 * self.spacings[step];
+                let discount = (-(self.shifts[step] + deviation + spread) * dt).exp();
+                let first = (branching.middle - 1 - self.lowest[step + 1]) as usize;
+                let expectation: f64 = branching
+                    .probabilities
+                    .iter()
+                    .zip(&values[first..first + 3])
+                    .map(|(probability, value)| probability * value)
+                    .sum();
+                discount * expectation
+            })
+            .collect()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+    use statrs::distribution::{ContinuousCDF, Normal};
+
+    use super::*;
+    use crate::{
+        money::currency::EUR,
+        term_structures::{flat_curve, FlatForwardTermStructure, TermStructure},
+        Compounding,
+    };
+
+    /// Values at the first step of a unit paid at a step.
+    fn zero_coupon_values(tree: &HullWhiteTree, step: usize, spread: f64) -> Vec<f64> {
+        (0..step)
+            .rev()
+            .fold(vec![1.0; tree.size(step)], |values, i| {
+                tree.rollback(i, &values, spread)
+            })
+    }
+
+    #[test]
+    fn test_hull_white_tree() {
+        let curve: FlatForwardTermStructure<EUR, Actual365Fixed> = flat_curve(
+            DateTime::new_from_ymd(2024, 1, 2),
+            0.03,
+            Compounding::Continuous,
+        );
+        let model = HullWhiteModel::new(0.1, 0.01);
+        assert_eq!(model.get_mean_reversion(), 0.1);
+        assert_eq!(model.get_sigma(), 0.01);
+        let expiry = DateTime::new_from_ymd(2026, 1, 2);
+        let maturity = DateTime::new_from_ymd(2029, 1, 2);
+        let tree = model.tree(&curve, &[maturity, expiry], 200).unwrap();
+
+        // The requested dates are steps, and the 1827 days of the tree are split into steps of at
+        // most 10 whole days.
+        let times = tree.get_times();
+        assert!(times.len() > 200);
+        assert!(times
+            .windows(2)
+            .all(|period| period[1] - period[0] < 10.5 / 365.0));
+        let expiry_step = tree.step_at(curve.time_from_reference(expiry)).unwrap();
+        let maturity_step = tree.step_at(curve.time_from_reference(maturity)).unwrap();
+        assert_eq!(maturity_step, times.len() - 1);
+        assert_eq!(tree.step_at(1.234), None);
+
+        // The tree reprices the curve, and a spread discounts on top of it.
+        for step in [1, expiry_step, maturity_step] {
+            let discount_factor = (-0.03 * times[step]).exp();
+            assert_approx_eq!(
+                zero_coupon_values(&tree, step, 0.0)[0],
+                discount_factor,
+                10e-12
+            );
+            assert_approx_eq!(
+                zero_coupon_values(&tree, step, 0.01)[0],
+                discount_factor * (-0.01 * times[step]).exp(),
+                10e-12
+            );
+        }
+
+        // A call on a zero-coupon bond matches the closed form (Jamshidian, 1989).
+        let bond_values = (expiry_step..maturity_step)
+            .rev()
+            .fold(vec![1.0; tree.size(maturity_step)], |values, i| {
+                tree.rollback(i, &values, 0.0)
+            });
+        let (t, s) = (times[expiry_step], times[maturity_step]);
+        let (expiry_discount, maturity_discount) = ((-0.03 * t).exp(), (-0.03 * s).exp());
+        let strike = maturity_discount / expiry_discount;
+        let payoff = bond_values
+            .iter()
+            .map(|value| (value - strike).max(0.0))
+            .collect();
+        let price = (0..expiry_step)
+            .rev()
+            .fold(payoff, |values: Vec<f64>, i| tree.rollback(i, &values, 0.0))[0];
+
+        let a = 0.1;
+        let sigma_p =
+            0.01 * ((1.0 - (-2.0 * a * t).exp()) / (2.0 * a)).sqrt() * (1.0 - (-a * (s - t)).exp())
+                / a;
+        let h = (maturity_discount / (strike * expiry_discount)).ln() / sigma_p + 0.5 * sigma_p;
+        let norm = Normal::standard();
+        let expected =
+            maturity_discount * norm.cdf(h) - strike * expiry_discount * norm.cdf(h - sigma_p);
+        assert_approx_eq!(price, expected, 10e-6);
+
+        // Without volatility the tree is a single path of forward rates.
+        let deterministic = HullWhiteModel::new(0.1, 0.0)
+            .tree(&curve, &[maturity], 10)
+            .unwrap();
+        let last = deterministic.get_times().len() - 1;
+        assert!((0..=last).all(|step| deterministic.size(step) <= 3));
+        assert_approx_eq!(
+            zero_coupon_values(&deterministic, last, 0.0)[0],
+            (-0.03 * deterministic.get_times()[last]).exp(),
+            10e-12
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use num::complex::Complex64;
+
+use crate::models::CharacteristicFunction;
+
+/// Kou double-exponential jump-diffusion model.
+///
+/// A geometric Brownian motion with volatility $\sigma$, overlaid with a compound Poisson process
+/// of intensity $\lambda$ whose log-jump sizes have the asymmetric double-exponential density
+/// $$
+///     f_Y(y) = p \eta_1 e^{-\eta_1 y} \mathbb{1}_{y \geq 0}
+///         + (1 - p) \eta_2 e^{\eta_2 y} \mathbb{1}_{y < 0}
+/// $$
+/// with $\eta_1 > 1$ (so that $E[e^Y]$ is finite) and $\eta_2 > 0$. The characteristic function
+/// is
+/// $$
+///     \phi(u, t) = \exp \left( t \left( iu\omega - \frac{1}{2} \sigma^2 u^2
+///         + \lambda \left( \frac{p \eta_1}{\eta_1 - iu} + \frac{(1 - p) \eta_2}{\eta_2 + iu} - 1
+///         \right) \right) \right)
+/// $$
+/// where $\omega = -\frac{1}{2} \sigma^2 - \lambda \zeta$ and $\zeta = E[e^Y] - 1$.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KouModel {
+    /// Diffusion volatility $\sigma$.
+    sigma: f64,
+
+    /// Jump intensity $\lambda$.
+    lambda: f64,
+
+    /// Probability of an upward jump $p$.
+    p: f64,
+
+    /// Rate of upward jumps $\eta_1$.
+    eta1: f64,
+
+    /// Rate of downward jumps $\eta_2$.
+    eta2: f64,
+}
+
+impl KouModel {
+    /// Create a new `KouModel`.
+    #[must_use]
+    pub const fn new(sigma: f64, lambda: f64, p: f64, eta1: f64, eta2: f64) -> Self {
+        Self {
+            sigma,
+            lambda,
+            p,
+            eta1,
+            eta2,
+        }
+    }
+
+    /// Get the diffusion volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Get the jump intensity.
+    #[inline]
+    #[must_use]
+    pub const fn get_lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Get the probability of an upward jump.
+    #[inline]
+    #[must_use]
+    pub const fn get_p(&self) -> f64 {
+        self.p
+    }
+
+    /// Get the rate of upward jumps.
+    #[inline]
+    #[must_use]
+    pub const fn get_eta1(&self) -> f64 {
+        self.eta1
+    }
+
+    /// Get the rate of downward jumps.
+    #[inline]
+    #[must_use]
+    pub const fn get_eta2(&self) -> f64 {
+        self.eta2
+    }
+
+    /// Expected relative jump size $\zeta = E[e^Y] - 1$.
+    #[must_use]
+    pub fn mean_relative_jump(&self) -> f64 {
+        let q = 1.0 - self.p;
+        self.p * self.eta1 / (self.eta1 - 1.0) + q * self.eta2 / (self.eta2 + 1.0) - 1.0
+    }
+}
+
+impl CharacteristicFunction for KouModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let q = 1.0 - self.p;
+        let omega =
+            (-0.5 * self.sigma).mul_add(self.sigma, -self.lambda * self.mean_relative_jump());
+        let jumps = self.lambda
+            * (self.p * self.eta1 / (self.eta1 - i * u) + q * self.eta2 / (self.eta2 + i * u)
+                - 1.0);
+        (t * (i * u * omega - 0.5 * self.sigma * self.sigma * u * u + jumps)).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let q = 1.0 - self.p;
+        let omega =
+            (-0.5 * self.sigma).mul_add(self.sigma, -self.lambda * self.mean_relative_jump());
+        let jump_mean = self.p / self.eta1 - q / self.eta2;
+        let jump_second_moment = 2.0 * (self.p / self.eta1.powi(2) + q / self.eta2.powi(2));
+        let jump_fourth_moment = 24.0 * (self.p / self.eta1.powi(4) + q / self.eta2.powi(4));
+        (
+            t * self.lambda.mul_add(jump_mean, omega),
+            t * self
+                .sigma
+                .mul_add(self.sigma, self.lambda * jump_second_moment),
+            t * self.lambda * jump_fourth_moment,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_kou_model() {
+        let model = KouModel::new(0.16, 1.0, 0.4, 10.0, 5.0);
+        assert_eq!(model.get_sigma(), 0.16);
+        assert_eq!(model.get_lambda(), 1.0);
+        assert_eq!(model.get_p(), 0.4);
+        assert_eq!(model.get_eta1(), 10.0);
+        assert_eq!(model.get_eta2(), 5.0);
+        assert_approx_eq!(
+            model.mean_relative_jump(),
+            0.4 * 10.0 / 9.0 + 0.6 * 5.0 / 6.0 - 1.0
+        );
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
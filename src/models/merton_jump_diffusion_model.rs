@@ -0,0 +1,153 @@
+use num::complex::Complex64;
+
+use crate::models::CharacteristicFunction;
+
+/// Merton jump-diffusion model.
+///
+/// A geometric Brownian motion with volatility $\sigma$, overlaid with a compound Poisson process
+/// of intensity $\lambda$ whose log-jump sizes are normal with mean $\mu_J$ and standard deviation
+/// $\sigma_J$:
+/// $$
+///     \phi(u, t) = \exp \left( t \left( iu\omega - \frac{1}{2} \sigma^2 u^2
+///         + \lambda \left( e^{iu\mu_J - \frac{1}{2} \sigma_J^2 u^2} - 1 \right) \right) \right)
+/// $$
+/// where $\omega = -\frac{1}{2} \sigma^2 - \lambda (e^{\mu_J + \frac{1}{2} \sigma_J^2} - 1)$ is
+/// the martingale correction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MertonJumpDiffusionModel {
+    /// Diffusion volatility $\sigma$.
+    sigma: f64,
+
+    /// Jump intensity $\lambda$.
+    lambda: f64,
+
+    /// Mean log-jump size $\mu_J$.
+    jump_mean: f64,
+
+    /// Log-jump size volatility $\sigma_J$.
+    jump_volatility: f64,
+}
+
+impl MertonJumpDiffusionModel {
+    /// Create a new `MertonJumpDiffusionModel`.
+    #[must_use]
+    pub const fn new(sigma: f64, lambda: f64, jump_mean: f64, jump_volatility: f64) -> Self {
+        Self {
+            sigma,
+            lambda,
+            jump_mean,
+            jump_volatility,
+        }
+    }
+
+    /// Get the diffusion volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Get the jump intensity.
+    #[inline]
+    #[must_use]
+    pub const fn get_lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Get the mean log-jump size.
+    #[inline]
+    #[must_use]
+    pub const fn get_jump_mean(&self) -> f64 {
+        self.jump_mean
+    }
+
+    /// Get the log-jump size volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_jump_volatility(&self) -> f64 {
+        self.jump_volatility
+    }
+
+    /// Expected relative jump size $k = E[e^J] - 1$.
+    #[must_use]
+    pub fn mean_relative_jump(&self) -> f64 {
+        (0.5 * self.jump_volatility)
+            .mul_add(self.jump_volatility, self.jump_mean)
+            .exp_m1()
+    }
+}
+
+/// Log-characteristic function (per unit time) of a compensated compound Poisson process with
+/// normal log-jumps, shared by the Merton and Bates models.
+pub(crate) fn normal_jump_exponent(
+    u: Complex64,
+    lambda: f64,
+    jump_mean: f64,
+    jump_volatility: f64,
+) -> Complex64 {
+    let i = Complex64::i();
+    let mean_relative_jump = (0.5 * jump_volatility)
+        .mul_add(jump_volatility, jump_mean)
+        .exp_m1();
+    lambda
+        * ((i * u * jump_mean - 0.5 * jump_volatility * jump_volatility * u * u).exp()
+            - 1.0
+            - i * u * mean_relative_jump)
+}
+
+/// Cumulants $(c_1, c_2, c_4)$ (per unit time) of a compensated compound Poisson process with
+/// normal log-jumps.
+pub(crate) fn normal_jump_cumulants(
+    lambda: f64,
+    jump_mean: f64,
+    jump_volatility: f64,
+) -> (f64, f64, f64) {
+    let mean_relative_jump = (0.5 * jump_volatility)
+        .mul_add(jump_volatility, jump_mean)
+        .exp_m1();
+    let m2 = jump_mean * jump_mean;
+    let s2 = jump_volatility * jump_volatility;
+    (
+        lambda * (jump_mean - mean_relative_jump),
+        lambda * (m2 + s2),
+        lambda * (m2 * m2 + 6.0 * s2 * m2 + 3.0 * s2 * s2),
+    )
+}
+
+impl CharacteristicFunction for MertonJumpDiffusionModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let diffusion = -0.5 * self.sigma * self.sigma * (i * u + u * u);
+        let jumps = normal_jump_exponent(u, self.lambda, self.jump_mean, self.jump_volatility);
+        (t * (diffusion + jumps)).exp()
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let (j1, j2, j4) = normal_jump_cumulants(self.lambda, self.jump_mean, self.jump_volatility);
+        let variance = self.sigma * self.sigma;
+        (t * (-0.5 * variance + j1), t * (variance + j2), t * j4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_merton_jump_diffusion_model() {
+        let model = MertonJumpDiffusionModel::new(0.15, 0.5, -0.1, 0.15);
+        assert_eq!(model.get_sigma(), 0.15);
+        assert_eq!(model.get_lambda(), 0.5);
+        assert_eq!(model.get_jump_mean(), -0.1);
+        assert_eq!(model.get_jump_volatility(), 0.15);
+        assert_approx_eq!(
+            model.mean_relative_jump(),
+            (-0.1f64 + 0.5 * 0.15 * 0.15).exp() - 1.0
+        );
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -0,0 +1,29 @@
+mod characteristic_function;
+pub use characteristic_function::CharacteristicFunction;
+
+mod black_scholes_model;
+pub use black_scholes_model::BlackScholesModel;
+
+mod heston_model;
+pub use heston_model::HestonModel;
+
+mod bates_model;
+pub use bates_model::BatesModel;
+
+mod merton_jump_diffusion_model;
+pub use merton_jump_diffusion_model::MertonJumpDiffusionModel;
+
+mod kou_model;
+pub use kou_model::KouModel;
+
+mod variance_gamma_model;
+pub use variance_gamma_model::VarianceGammaModel;
+
+mod cgmy_model;
+pub use cgmy_model::CgmyModel;
+
+mod sabr_model;
+pub use sabr_model::{SabrCalibrationError, SabrModel, SabrVolatilityFormula};
+
+mod hull_white_model;
+pub use hull_white_model::{HullWhiteModel, HullWhiteTree};
@@ -0,0 +1,390 @@
+use crate::math::optimization::NelderMead;
+use crate::types::{Strike, Volatility};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// SABR stochastic volatility model (Hagan, Kumar, Lesniewski & Woodward, 2002).
+///
+/// The forward and its volatility follow:
+/// $$
+///     dF_t = \alpha_t F_t^\beta \\, dW_t, \quad d\alpha_t = \nu \alpha_t \\, dZ_t, \quad
+///     d\langle W, Z \rangle_t = \rho \\, dt
+/// $$
+/// with $\alpha_0 = \alpha$. Implied volatilities are given by asymptotic expansions; see
+/// [`SabrModel::lognormal_volatility`], [`SabrModel::normal_volatility`] and
+/// [`SabrModel::obloj_volatility`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SabrModel {
+    /// Initial volatility $\alpha$.
+    alpha: f64,
+
+    /// CEV exponent $\beta$.
+    beta: f64,
+
+    /// Forward/volatility correlation $\rho$.
+    rho: f64,
+
+    /// Volatility of volatility $\nu$.
+    nu: f64,
+}
+
+/// Asymptotic expansion used to turn SABR parameters into implied volatilities.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SabrVolatilityFormula {
+    /// Hagan et al. (2002) lognormal (Black) volatility.
+    HaganLognormal,
+
+    /// Obłój (2008) correction to the leading-order lognormal volatility.
+    Obloj,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SabrCalibrationError {
+    /// At least three quotes are needed to calibrate $\alpha$, $\rho$ and $\nu$.
+    NotEnoughQuotes,
+
+    /// The optimiser did not converge.
+    NoConvergence,
+}
+
+impl SabrModel {
+    /// Create a new `SabrModel`.
+    #[must_use]
+    pub const fn new(alpha: f64, beta: f64, rho: f64, nu: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            rho,
+            nu,
+        }
+    }
+
+    /// Get the initial volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Get the CEV exponent.
+    #[inline]
+    #[must_use]
+    pub const fn get_beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Get the forward/volatility correlation.
+    #[inline]
+    #[must_use]
+    pub const fn get_rho(&self) -> f64 {
+        self.rho
+    }
+
+    /// Get the volatility of volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_nu(&self) -> f64 {
+        self.nu
+    }
+}
+
+impl SabrModel {
+    /// Implied volatility of a strike, using a given expansion.
+    #[must_use]
+    pub fn volatility(
+        &self,
+        formula: SabrVolatilityFormula,
+        forward: f64,
+        strike: f64,
+        expiry_time: f64,
+    ) -> f64 {
+        match formula {
+            SabrVolatilityFormula::HaganLognormal => {
+                self.lognormal_volatility(forward, strike, expiry_time)
+            }
+            SabrVolatilityFormula::Obloj => self.obloj_volatility(forward, strike, expiry_time),
+        }
+    }
+
+    /// Hagan's lognormal (Black) implied volatility.
+    ///
+    /// $$
+    ///     \sigma_B = \frac{\alpha}{(FK)^{\frac{1 - \beta}{2}}
+    ///         \left( 1 + \frac{(1 - \beta)^2}{24} l^2 + \frac{(1 - \beta)^4}{1920} l^4 \right)}
+    ///         \frac{z}{x(z)} \left( 1 + \epsilon \tau \right)
+    /// $$
+    /// where $l = \ln(F / K)$, $z = \frac{\nu}{\alpha} (FK)^{\frac{1 - \beta}{2}} l$,
+    /// $x(z) = \ln \frac{\sqrt{1 - 2 \rho z + z^2} + z - \rho}{1 - \rho}$ and
+    /// $$
+    ///     \epsilon = \frac{(1 - \beta)^2}{24} \frac{\alpha^2}{(FK)^{1 - \beta}}
+    ///         + \frac{\rho \beta \nu \alpha}{4 (FK)^{\frac{1 - \beta}{2}}}
+    ///         + \frac{2 - 3 \rho^2}{24} \nu^2
+    /// $$
+    #[must_use]
+    pub fn lognormal_volatility(&self, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        let one_minus_beta = 1.0 - self.beta;
+        let fk_beta = (forward * strike).powf(0.5 * one_minus_beta);
+        let l = (forward / strike).ln();
+        let l2 = l * l;
+
+        let denominator = fk_beta
+            * (one_minus_beta.powi(4) / 1920.0)
+                .mul_add(l2 * l2, (one_minus_beta.powi(2) / 24.0).mul_add(l2, 1.0));
+        let z = self.nu / self.alpha * fk_beta * l;
+
+        self.alpha / denominator
+            * self.z_over_x(z)
+            * self.time_correction(fk_beta).mul_add(expiry_time, 1.0)
+    }
+
+    /// Hagan's normal (Bachelier) implied volatility.
+    ///
+    /// $$
+    ///     \sigma_N = \alpha (FK)^{\frac{\beta}{2}}
+    ///         \frac{1 + \frac{1}{24} l^2 + \frac{1}{1920} l^4}
+    ///         {1 + \frac{(1 - \beta)^2}{24} l^2 + \frac{(1 - \beta)^4}{1920} l^4}
+    ///         \frac{z}{x(z)} \left( 1 + \epsilon_N \tau \right)
+    /// $$
+    /// with $l$, $z$ and $x(z)$ as in [`SabrModel::lognormal_volatility`] and
+    /// $$
+    ///     \epsilon_N = \frac{-\beta (2 - \beta)}{24} \frac{\alpha^2}{(FK)^{1 - \beta}}
+    ///         + \frac{\rho \beta \nu \alpha}{4 (FK)^{\frac{1 - \beta}{2}}}
+    ///         + \frac{2 - 3 \rho^2}{24} \nu^2
+    /// $$
+    #[must_use]
+    pub fn normal_volatility(&self, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        let one_minus_beta = 1.0 - self.beta;
+        let fk = forward * strike;
+        let fk_beta = fk.powf(0.5 * one_minus_beta);
+        let l = (forward / strike).ln();
+        let l2 = l * l;
+
+        let numerator = (l2 * l2).mul_add(1.0 / 1920.0, l2.mul_add(1.0 / 24.0, 1.0));
+        let denominator = (one_minus_beta.powi(4) / 1920.0)
+            .mul_add(l2 * l2, (one_minus_beta.powi(2) / 24.0).mul_add(l2, 1.0));
+        let z = self.nu / self.alpha * fk_beta * l;
+
+        let correction = (-self.beta * (2.0 - self.beta) / 24.0).mul_add(
+            self.alpha * self.alpha / (fk_beta * fk_beta),
+            0.25 * self.rho * self.beta * self.nu * self.alpha / fk_beta
+                + (2.0 - 3.0 * self.rho * self.rho) / 24.0 * self.nu * self.nu,
+        );
+
+        self.alpha * fk.powf(0.5 * self.beta) * numerator / denominator
+            * self.z_over_x(z)
+            * correction.mul_add(expiry_time, 1.0)
+    }
+
+    /// Obłój's corrected lognormal implied volatility.
+    ///
+    /// Replaces Hagan's leading-order term with
+    /// $$
+    ///     \sigma_B = \frac{\nu \ln(F / K)}{x(z)} \left( 1 + \epsilon \tau \right), \quad
+    ///     z = \frac{\nu}{\alpha} \frac{F^{1 - \beta} - K^{1 - \beta}}{1 - \beta}
+    /// $$
+    /// (with $z = \frac{\nu}{\alpha} \ln(F / K)$ when $\beta = 1$), which is exact in the limits
+    /// $\nu \to 0$ and $\beta \to 1$ and better behaved far from the money.
+    #[must_use]
+    pub fn obloj_volatility(&self, forward: f64, strike: f64, expiry_time: f64) -> f64 {
+        let one_minus_beta = 1.0 - self.beta;
+        let fk_beta = (forward * strike).powf(0.5 * one_minus_beta);
+        let l = (forward / strike).ln();
+
+        let y = if one_minus_beta.abs() < f64::EPSILON {
+            l
+        } else {
+            (forward.powf(one_minus_beta) - strike.powf(one_minus_beta)) / one_minus_beta
+        };
+
+        let leading = if l.abs() < f64::EPSILON {
+            self.alpha / forward.powf(one_minus_beta)
+        } else {
+            // Written as the CEV term times z / x(z), which stays finite as the vol of vol vanishes.
+            self.alpha * l / y * self.z_over_x(self.nu / self.alpha * y)
+        };
+
+        leading * self.time_correction(fk_beta).mul_add(expiry_time, 1.0)
+    }
+
+    /// Lognormal time correction $\epsilon$.
+    fn time_correction(&self, fk_beta: f64) -> f64 {
+        let one_minus_beta = 1.0 - self.beta;
+        (one_minus_beta.powi(2) / 24.0).mul_add(
+            self.alpha * self.alpha / (fk_beta * fk_beta),
+            0.25 * self.rho * self.beta * self.nu * self.alpha / fk_beta
+                + (2.0 - 3.0 * self.rho * self.rho) / 24.0 * self.nu * self.nu,
+        )
+    }
+
+    fn x(&self, z: f64) -> f64 {
+        ((z.mul_add(z, (-2.0 * self.rho).mul_add(z, 1.0))).sqrt() + z - self.rho).ln()
+            - (1.0 - self.rho).ln()
+    }
+
+    /// $z / x(z)$, with its limit of 1 at $z = 0$.
+    fn z_over_x(&self, z: f64) -> f64 {
+        if z.abs() < 1e-12 {
+            1.0
+        } else {
+            z / self.x(z)
+        }
+    }
+}
+
+impl SabrModel {
+    /// Calibrate $\alpha$, $\rho$ and $\nu$ to a strip of implied volatilities at one expiry, with
+    /// $\beta$ held fixed.
+    ///
+    /// The sum of squared volatility errors is minimised with Nelder-Mead, over transformed
+    /// parameters ($\alpha = e^a$, $\rho = \tanh r$, $\nu = e^n$) so that the search is
+    /// unconstrained.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if fewer than three quotes are provided, and `NoConvergence`
+    /// if the optimiser did not converge.
+    pub fn calibrate(
+        forward: f64,
+        expiry_time: f64,
+        quotes: &[(Strike, Volatility)],
+        beta: f64,
+        formula: SabrVolatilityFormula,
+    ) -> Result<Self, SabrCalibrationError> {
+        if quotes.len() < 3 {
+            return Err(SabrCalibrationError::NotEnoughQuotes);
+        }
+
+        // Start from the quote closest to the money.
+        let atm_volatility = quotes
+            .iter()
+            .min_by(|a, b| (*a.0 - forward).abs().total_cmp(&(*b.0 - forward).abs()))
+            .map_or(0.2, |quote| *quote.1);
+        let initial = [
+            (atm_volatility * forward.powf(1.0 - beta)).ln(),
+            0.0,
+            0.5f64.ln(),
+        ];
+
+        let to_model = |x: &[f64]| Self::new(x[0].exp(), beta, x[1].tanh(), x[2].exp());
+        let objective = |x: &[f64]| {
+            let model = to_model(x);
+            quotes
+                .iter()
+                .map(|(strike, volatility)| {
+                    (model.volatility(formula, forward, **strike, expiry_time) - **volatility)
+                        .powi(2)
+                })
+                .sum::<f64>()
+        };
+
+        let result = NelderMead::new(10_000, 1e-16).minimize(objective, &initial, 0.1);
+        if result.converged {
+            Ok(to_model(&result.x))
+        } else {
+            Err(SabrCalibrationError::NoConvergence)
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use ordered_float::OrderedFloat;
+
+    use super::*;
+    use crate::instruments::OptionType;
+    use crate::pricers::{bachelier_formula, black_formula};
+
+    #[test]
+    fn test_sabr_lognormal_limit() {
+        // Without vol of vol and with beta = 1, SABR is Black-Scholes with volatility alpha.
+        let model = SabrModel::new(0.25, 1.0, 0.0, 0.0);
+        for strike in [80.0, 100.0, 120.0] {
+            assert_approx_eq!(model.lognormal_volatility(100.0, strike, 2.0), 0.25, 10e-12);
+            assert_approx_eq!(model.obloj_volatility(100.0, strike, 2.0), 0.25, 10e-12);
+        }
+    }
+
+    #[test]
+    fn test_sabr_obloj_matches_hagan_at_the_money() {
+        let model = SabrModel::new(0.04, 0.5, -0.3, 0.4);
+        let forward = 0.03;
+        assert_approx_eq!(
+            model.obloj_volatility(forward, forward, 5.0),
+            model.lognormal_volatility(forward, forward, 5.0),
+            10e-12
+        );
+        assert_approx_eq!(
+            model.obloj_volatility(forward, forward * 1.01, 5.0),
+            model.lognormal_volatility(forward, forward * 1.01, 5.0),
+            10e-5
+        );
+    }
+
+    #[test]
+    fn test_sabr_normal_and_lognormal_prices_agree() {
+        let model = SabrModel::new(0.035, 0.6, -0.25, 0.35);
+        let (forward, time) = (0.025, 1.0);
+
+        for strike in [0.015, 0.02, 0.025, 0.03, 0.035] {
+            let black = black_formula(
+                OptionType::CALL,
+                strike,
+                forward,
+                model.lognormal_volatility(forward, strike, time) * time.sqrt(),
+                1.0,
+            );
+            let bachelier = bachelier_formula(
+                OptionType::CALL,
+                strike,
+                forward,
+                model.normal_volatility(forward, strike, time) * time.sqrt(),
+                1.0,
+            );
+            assert_approx_eq!(black, bachelier, 10e-7);
+        }
+    }
+
+    #[test]
+    fn test_sabr_calibration() {
+        let model = SabrModel::new(0.3, 0.7, -0.35, 0.5);
+        let (forward, time) = (1.2, 0.75);
+        let quotes: Vec<(Strike, Volatility)> = [0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.6]
+            .iter()
+            .map(|&strike| {
+                (
+                    OrderedFloat(strike),
+                    OrderedFloat(model.lognormal_volatility(forward, strike, time)),
+                )
+            })
+            .collect();
+
+        let calibrated = SabrModel::calibrate(
+            forward,
+            time,
+            &quotes,
+            0.7,
+            SabrVolatilityFormula::HaganLognormal,
+        )
+        .unwrap();
+        assert_approx_eq!(calibrated.get_alpha(), 0.3, 10e-6);
+        assert_approx_eq!(calibrated.get_rho(), -0.35, 10e-6);
+        assert_approx_eq!(calibrated.get_nu(), 0.5, 10e-6);
+
+        assert_eq!(
+            SabrModel::calibrate(
+                forward,
+                time,
+                &quotes[..2],
+                0.7,
+                SabrVolatilityFormula::HaganLognormal
+            ),
+            Err(SabrCalibrationError::NotEnoughQuotes)
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use num::complex::Complex64;
+
+use crate::models::CharacteristicFunction;
+
+/// Variance Gamma model (Madan, Carr & Chang).
+///
+/// The log-price is a Brownian motion with drift $\theta$ and volatility $\sigma$, evaluated at a
+/// gamma-distributed business time with variance rate $\nu$:
+/// $$
+///     \phi(u, t) = e^{iu\omega t}
+///         \left( 1 - iu\theta\nu + \frac{1}{2} \sigma^2 \nu u^2 \right)^{-t / \nu}
+/// $$
+/// where $\omega = \frac{1}{\nu} \ln(1 - \theta\nu - \frac{1}{2} \sigma^2 \nu)$ is the
+/// martingale correction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VarianceGammaModel {
+    /// Volatility of the subordinated Brownian motion $\sigma$.
+    sigma: f64,
+
+    /// Variance rate of the gamma time change $\nu$.
+    nu: f64,
+
+    /// Drift of the subordinated Brownian motion $\theta$.
+    theta: f64,
+}
+
+impl VarianceGammaModel {
+    /// Create a new `VarianceGammaModel`.
+    #[must_use]
+    pub const fn new(sigma: f64, nu: f64, theta: f64) -> Self {
+        Self { sigma, nu, theta }
+    }
+
+    /// Get the volatility.
+    #[inline]
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Get the variance rate.
+    #[inline]
+    #[must_use]
+    pub const fn get_nu(&self) -> f64 {
+        self.nu
+    }
+
+    /// Get the drift.
+    #[inline]
+    #[must_use]
+    pub const fn get_theta(&self) -> f64 {
+        self.theta
+    }
+
+    fn omega(&self) -> f64 {
+        (0.5 * self.sigma * self.sigma)
+            .mul_add(-self.nu, -self.theta * self.nu)
+            .ln_1p()
+            / self.nu
+    }
+}
+
+impl CharacteristicFunction for VarianceGammaModel {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let base =
+            1.0 - i * u * self.theta * self.nu + 0.5 * self.sigma * self.sigma * self.nu * u * u;
+        (i * u * self.omega() * t).exp() * base.powf(-t / self.nu)
+    }
+
+    fn cumulants(&self, t: f64) -> (f64, f64, f64) {
+        let (sigma2, theta2, nu) = (self.sigma * self.sigma, self.theta * self.theta, self.nu);
+        (
+            (self.omega() + self.theta) * t,
+            nu.mul_add(theta2, sigma2) * t,
+            3.0 * (sigma2 * sigma2 * nu
+                + 2.0 * theta2 * theta2 * nu * nu * nu
+                + 4.0 * sigma2 * theta2 * nu * nu)
+                * t,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+    use crate::models::characteristic_function::assert_characteristic_function_consistent;
+
+    #[test]
+    fn test_variance_gamma_model() {
+        let model = VarianceGammaModel::new(0.12, 0.2, -0.14);
+        assert_eq!(model.get_sigma(), 0.12);
+        assert_eq!(model.get_nu(), 0.2);
+        assert_eq!(model.get_theta(), -0.14);
+
+        // The variance is (sigma^2 + nu theta^2) t.
+        assert_approx_eq!(
+            model.cumulants(2.0).1,
+            2.0 * 0.2f64.mul_add(0.14 * 0.14, 0.12 * 0.12),
+            10e-15
+        );
+
+        assert_characteristic_function_consistent(&model);
+    }
+}
@@ -38,32 +38,26 @@ macro_rules! generate_currency {
         #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
         pub struct $identifier();
         impl $crate::money::Currency for $identifier {
-            #[must_use]
             #[inline]
             fn name(&self) -> &'static str {
                 $name
             }
-            #[must_use]
             #[inline]
             fn symbol(&self) -> &'static str {
                 $symbol
             }
-            #[must_use]
             #[inline]
             fn alphabetic_code(&self) -> &'static str {
                 $alphabetic_code
             }
-            #[must_use]
             #[inline]
             fn numeric_code(&self) -> &'static str {
                 $numeric_code
             }
-            #[must_use]
             #[inline]
             fn minor(&self) -> usize {
                 $minor
             }
-            #[must_use]
             #[inline]
             fn fractions(&self) -> u16 {
                 $fractions
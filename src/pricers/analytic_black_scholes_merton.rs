@@ -58,10 +58,7 @@ where
 
         let s = self.underlying_spot;
 
-        let sigma = match self
-            .volatility_curve
-            .black_volatility(self.volatility_curve.get_reference_date(), s.into())
-        {
+        let sigma = match self.volatility_curve.black_volatility(t, k.into()) {
             ExistingValue(v) | InterpolatedValue(v) => v,
             OutOfRange => panic!("Out of range."),
             NoPoints => panic!("No points."),
@@ -89,8 +86,8 @@ where
             }
             OptionType::PUT => {
                 let put_price = d * norm
-                    .cdf(-1.0 * d_minus)
-                    .mul_add(*k.amount(), -(norm.cdf(-1.0 * d_plus) * *f.amount()));
+                    .cdf(-d_minus)
+                    .mul_add(*k.amount(), -(norm.cdf(-d_plus) * *f.amount()));
                 Money::new(put_price)
             }
         }
@@ -100,3 +97,120 @@ where
         options.iter().map(|option| self.price(option)).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+    use num::Bounded;
+    use ordered_float::OrderedFloat;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, VanillaPayoff},
+        money::currency::USD,
+        pricers::black_formula,
+        term_structures::{
+            BlackForwardVolatilityResult, FlatForwardTermStructure,
+            FlatForwardTermStructureBuilder, TermStructure, TermStructureStrikeValidity,
+            VolatilityTermStructure,
+        },
+        time::DateTime,
+        types::Strike,
+        Compounding, InterestRate,
+    };
+
+    /// Volatility of 50% at the reference date, and a skew in strike after it.
+    struct SkewedVolatility {
+        reference_date: DateTime,
+    }
+
+    impl TermStructure<Actual365Fixed> for SkewedVolatility {
+        fn get_reference_date(&self) -> DateTime {
+            self.reference_date
+        }
+
+        fn get_max_datetime(&self) -> DateTime {
+            DateTime::new_from_ymd(9999, 12, 31)
+        }
+
+        fn get_day_counter(&self) -> Actual365Fixed {
+            Actual365Fixed
+        }
+
+        fn is_datetime_valid(&self, dt: DateTime) -> bool {
+            dt >= self.reference_date
+        }
+    }
+
+    impl VolatilityTermStructure<Actual365Fixed> for SkewedVolatility {
+        fn get_min_max_strike(&self) -> (Strike, Strike) {
+            (
+                <Strike as Bounded>::min_value(),
+                <Strike as Bounded>::max_value(),
+            )
+        }
+
+        fn validate_strike(&self, _strike: Strike) -> TermStructureStrikeValidity {
+            TermStructureStrikeValidity::Valid
+        }
+    }
+
+    impl BlackVolatilityTermStructure<Actual365Fixed> for SkewedVolatility {
+        fn black_volatility(
+            &self,
+            maturity: DateTime,
+            strike: Strike,
+        ) -> BlackVolatilityTermStructureResult {
+            if maturity <= self.reference_date {
+                return BlackVolatilityTermStructureResult::ExistingValue(OrderedFloat(0.5));
+            }
+            BlackVolatilityTermStructureResult::InterpolatedValue(OrderedFloat(
+                0.001f64.mul_add(100.0 - *strike, 0.2),
+            ))
+        }
+
+        fn black_forward_volatility(
+            &self,
+            _start_date: DateTime,
+            _end_date: DateTime,
+            _strike: Strike,
+        ) -> BlackForwardVolatilityResult {
+            BlackForwardVolatilityResult::NoPoints
+        }
+    }
+
+    #[test]
+    fn test_volatility_at_expiry_and_strike() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let yield_curve: FlatForwardTermStructure<USD, Actual365Fixed> =
+            FlatForwardTermStructureBuilder::new()
+                .reference_date(reference_date)
+                .rate(InterestRate::new(
+                    0.05,
+                    Actual365Fixed,
+                    Compounding::Continuous,
+                ))
+                .build()
+                .unwrap();
+        let volatility = SkewedVolatility { reference_date };
+        let pricer = AnalyticBlackScholesMerton::new(Money::new(100.0), &volatility, &yield_curve);
+
+        // Each option is priced at the volatility of its own expiry and strike, not at the spot
+        // volatility of the reference date.
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let discount = (-0.05f64).exp();
+        for (strike, option_type) in [(90.0, OptionType::PUT), (110.0, OptionType::CALL)] {
+            let option = EuropeanOption::new(
+                VanillaPayoff::new(Money::new(strike), option_type),
+                EuropeanExercise::new(expiry),
+            );
+            let sigma = 0.001f64.mul_add(100.0 - strike, 0.2);
+            assert_approx_eq!(
+                *pricer.price(&option).amount(),
+                black_formula(option_type, strike, 100.0 / discount, sigma, discount),
+                10e-10
+            );
+        }
+    }
+}
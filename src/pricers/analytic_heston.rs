@@ -0,0 +1,185 @@
+use std::f64::consts::PI;
+
+use day_count_conventions::DayCounter;
+use num::complex::Complex64;
+
+use crate::{
+    instruments::{EuropeanOption, Exercise, Option, OptionType},
+    math::integration::GaussLaguerreIntegrator,
+    models::HestonModel,
+    money::{Currency, Money},
+    pricers::{time_discount_and_forward, Pricer},
+    term_structures::YieldTermStructure,
+};
+
+/// Semi-analytic Heston pricer for European options.
+///
+/// Prices are computed in Gatheral's formulation:
+/// $$
+///     C = D(T) \left( F P_1 - K P_0 \right)
+/// $$
+/// $$
+///     P_j = \frac{1}{2} + \frac{1}{\pi} \int_0^\infty
+///         \Re \left[ \frac{e^{C_j(u, T) \theta + D_j(u, T) v_0 + iux}}{iu} \right] du
+/// $$
+/// where $x = \ln(F / K)$ (see [`HestonModel`]). The integrals are evaluated with Gauss-Laguerre
+/// quadrature.
+pub struct AnalyticHeston<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    model: &'a HestonModel,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    integrator: GaussLaguerreIntegrator,
+}
+
+impl<'a, C, D> AnalyticHeston<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `AnalyticHeston` pricer, using a 128 point Gauss-Laguerre quadrature.
+    pub fn new(
+        underlying_spot: Money<C>,
+        model: &'a HestonModel,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            model,
+            yield_curve,
+            dividend_curve,
+            integrator: GaussLaguerreIntegrator::default(),
+        }
+    }
+
+    /// Set the order of the Gauss-Laguerre quadrature.
+    #[must_use]
+    pub fn with_integration_order(mut self, order: usize) -> Self {
+        self.integrator = GaussLaguerreIntegrator::new(order);
+        self
+    }
+
+    /// In-the-money probability $P_j$.
+    fn probability(&self, x: f64, tau: f64, j: f64) -> f64 {
+        let i = Complex64::i();
+        let integral = self.integrator.integrate(|u| {
+            let u = Complex64::new(u, 0.0);
+            ((self.model.log_characteristic_function(u, tau, j) + i * u * x).exp() / (i * u)).re
+        });
+        0.5 + integral / PI
+    }
+}
+
+impl<'a, C, D> Pricer<C> for AnalyticHeston<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn price(&self, option: &EuropeanOption<C>) -> Money<C> {
+        let t = option.get_exercise().get_last_date();
+
+        let k = *option.get_strike().amount();
+        let (tau, d, _, f) = time_discount_and_forward(
+            self.underlying_spot,
+            self.yield_curve,
+            self.dividend_curve,
+            t,
+        );
+
+        let x = (f / k).ln();
+        let p1 = self.probability(x, tau, 1.0);
+        let p0 = self.probability(x, tau, 0.0);
+
+        match option.get_option_type() {
+            OptionType::CALL => Money::new(d * f.mul_add(p1, -k * p0)),
+            OptionType::PUT => Money::new(d * k.mul_add(1.0 - p0, -f * (1.0 - p1))),
+        }
+    }
+
+    fn price_vec(&self, options: &[EuropeanOption<C>]) -> Vec<Money<C>> {
+        options.iter().map(|option| self.price(option)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, VanillaPayoff},
+        money::currency::USD,
+        pricers::AnalyticBlackScholesMerton,
+        term_structures::{flat_curve, ConstantVolTermStructureBuilder},
+        time::DateTime,
+        Compounding,
+    };
+
+    #[test]
+    fn test_analytic_heston() {
+        let ref_date = DateTime::new_from_ymd(2023, 1, 1);
+        let expiry = EuropeanExercise::new(DateTime::new_from_ymd(2024, 1, 1));
+
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(ref_date, 0.0, Compounding::Continuous);
+
+        // Fang & Oosterlee (2008), Table 4.
+        let model = HestonModel::new(0.0175, 1.5768, 0.0398, 0.5751, -0.5711);
+        let pricer = AnalyticHeston::new(Money::new(100.0), &model, &yield_curve, &yield_curve);
+
+        let call = EuropeanOption::new(
+            VanillaPayoff::new(Money::new(100.0), OptionType::CALL),
+            expiry,
+        );
+        let put = EuropeanOption::new(
+            VanillaPayoff::new(Money::new(100.0), OptionType::PUT),
+            expiry,
+        );
+        assert_approx_eq!(*pricer.price(&call).amount(), 5.785_155_450, 10e-7);
+        // Put-call parity at zero rates: C - P = S - K.
+        assert_approx_eq!(*pricer.price(&put).amount(), 5.785_155_450, 10e-7);
+    }
+
+    #[test]
+    fn test_analytic_heston_black_scholes_limit() {
+        let ref_date = DateTime::new_from_ymd(2023, 1, 1);
+        let expiry = EuropeanExercise::new(DateTime::new_from_ymd(2024, 1, 1));
+
+        let yield_curve =
+            flat_curve::<USD, Actual365Fixed>(ref_date, 0.05, Compounding::Continuous);
+        let dividend_curve = flat_curve(ref_date, 0.0, Compounding::Continuous);
+        let vol_curve = ConstantVolTermStructureBuilder::new()
+            .reference_date(ref_date)
+            .day_count_convention(Actual365Fixed)
+            .volatility(0.2)
+            .build()
+            .unwrap();
+
+        // With v0 = theta and a vanishing vol of variance, Heston collapses to Black-Scholes.
+        let model = HestonModel::new(0.04, 1.0, 0.04, 1e-4, 0.0);
+        let heston = AnalyticHeston::new(Money::new(100.0), &model, &yield_curve, &dividend_curve);
+        let bsm = AnalyticBlackScholesMerton::new(Money::new(100.0), &vol_curve, &yield_curve);
+
+        let options: Vec<EuropeanOption<USD>> = [80.0, 100.0, 120.0]
+            .into_iter()
+            .flat_map(|k| {
+                [OptionType::CALL, OptionType::PUT].map(|option_type| {
+                    EuropeanOption::new(VanillaPayoff::new(Money::new(k), option_type), expiry)
+                })
+            })
+            .collect();
+
+        for (heston_price, bsm_price) in heston
+            .price_vec(&options)
+            .into_iter()
+            .zip(bsm.price_vec(&options))
+        {
+            assert_approx_eq!(*heston_price.amount(), *bsm_price.amount(), 10e-5);
+        }
+    }
+}
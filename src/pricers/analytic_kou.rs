@@ -0,0 +1,362 @@
+use std::f64::consts::PI;
+
+use day_count_conventions::DayCounter;
+use statrs::distribution::{ContinuousCDF, DiscreteCDF, Normal, Poisson};
+
+use crate::{
+    instruments::{EuropeanOption, Exercise, Option, OptionType},
+    models::KouModel,
+    money::{Currency, Money},
+    pricers::{time_discount_and_forward, Pricer},
+    term_structures::YieldTermStructure,
+};
+
+/// Analytic Kou double-exponential jump-diffusion pricer for European options (Kou, 2002).
+///
+/// With $a = \ln(K / F)$ and $\zeta$ the mean relative jump, the call price is
+/// $$
+///     C = D \left( F \\, \Upsilon(\tfrac{1}{2} \sigma^2 - \lambda \zeta, \sigma, \tilde{\lambda},
+///         \tilde{p}, \tilde{\eta}_1, \tilde{\eta}_2; a, \tau)
+///     - K \\, \Upsilon(-\tfrac{1}{2} \sigma^2 - \lambda \zeta, \sigma, \lambda, p, \eta_1,
+///         \eta_2; a, \tau) \right)
+/// $$
+/// where $\Upsilon(\mu, \dots; a, \tau) = P(Z_\tau \geq a)$ for the jump-diffusion
+/// $Z_t = \mu t + \sigma W_t + \sum_{i=1}^{N_t} Y_i$, evaluated in closed form through the
+/// $Hh_n$ functions, and
+/// $$
+///     \tilde{p} = \frac{p}{1 + \zeta} \frac{\eta_1}{\eta_1 - 1}, \quad
+///     \tilde{\eta}_1 = \eta_1 - 1, \quad \tilde{\eta}_2 = \eta_2 + 1, \quad
+///     \tilde{\lambda} = \lambda (1 + \zeta)
+/// $$
+/// is the model under the share measure. Puts follow from put-call parity.
+pub struct AnalyticKou<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    model: &'a KouModel,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    tolerance: f64,
+}
+
+impl<'a, C, D> AnalyticKou<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `AnalyticKou` pricer, truncating the jump-count series once the probability
+    /// of more jumps is below $10^{-12}$.
+    pub fn new(
+        underlying_spot: Money<C>,
+        model: &'a KouModel,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            model,
+            yield_curve,
+            dividend_curve,
+            tolerance: 1e-12,
+        }
+    }
+
+    /// Set the Poisson tail probability below which the jump-count series is truncated.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Parameters of a double-exponential jump-diffusion $Z_t$.
+struct JumpDiffusion {
+    mu: f64,
+    sigma: f64,
+    lambda: f64,
+    p: f64,
+    eta1: f64,
+    eta2: f64,
+}
+
+/// The $Hh_n$ functions $Hh_{-1}(x), \dots, Hh_{n}(x)$, where
+/// $Hh_n(x) = \frac{1}{n!} \int_x^\infty (t - x)^n e^{-t^2/2} dt$.
+#[allow(clippy::cast_precision_loss)]
+fn hh(n: usize, x: f64) -> Vec<f64> {
+    let mut values = Vec::with_capacity(n + 2);
+    values.push((-0.5 * x * x).exp());
+    values.push((2.0 * PI).sqrt() * Normal::standard().cdf(-x));
+    for i in 1..=n {
+        values.push(x.mul_add(-values[i], values[i - 1]) / i as f64);
+    }
+    values
+}
+
+/// $I_n(c; \alpha, \beta, \delta) = \int_c^\infty e^{\alpha x} Hh_n(\beta x - \delta) dx$.
+///
+/// Valid for $\beta > 0, \alpha \neq 0$ and for $\beta < 0, \alpha < 0$.
+#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+fn i_n(n: usize, c: f64, alpha: f64, beta: f64, delta: f64) -> f64 {
+    let hh = hh(n, beta.mul_add(c, -delta));
+    let ratio = beta / alpha;
+    let sum: f64 = (0..=n)
+        .map(|i| ratio.powi((n - i) as i32) * hh[i + 1])
+        .sum();
+    let tail = ratio.powi(n as i32 + 1) * (2.0 * PI).sqrt() / beta
+        * (alpha * delta / beta + alpha * alpha / (2.0 * beta * beta)).exp();
+    let norm = Normal::standard();
+    if beta > 0.0 {
+        -(alpha * c).exp() / alpha * sum + tail * norm.cdf(-beta * c + delta + alpha / beta)
+    } else {
+        -(alpha * c).exp() / alpha * sum - tail * norm.cdf(beta * c - delta - alpha / beta)
+    }
+}
+
+/// Number of jumps $n$ after which the Poisson probability $P(N_\tau > n)$ of more jumps, with
+/// mean $\lambda \tau$, is below the tolerance.
+#[allow(clippy::cast_possible_truncation)]
+fn jumps_for_tolerance(lambda_tau: f64, tolerance: f64) -> usize {
+    if lambda_tau <= 0.0 {
+        return 0;
+    }
+    let poisson = Poisson::new(lambda_tau).expect("the jump intensity is positive");
+    let mut n = 0;
+    while poisson.sf(n) > tolerance {
+        n += 1;
+    }
+    n as usize
+}
+
+/// Binomial coefficient.
+#[allow(clippy::cast_precision_loss)]
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+impl JumpDiffusion {
+    /// $\Upsilon(\mu, \sigma, \lambda, p, \eta_1, \eta_2; a, \tau) = P(Z_\tau \geq a)$.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_wrap,
+        clippy::cast_possible_truncation
+    )]
+    fn upsilon(&self, a: f64, tau: f64, tolerance: f64) -> f64 {
+        let max_jumps = jumps_for_tolerance(self.lambda * tau, tolerance);
+        let (sigma, eta1, eta2, p) = (self.sigma, self.eta1, self.eta2, self.p);
+        let q = 1.0 - p;
+        let std_dev = sigma * tau.sqrt();
+        let c = self.mu.mul_add(-tau, a);
+
+        let up_share = eta1 / (eta1 + eta2);
+        let down_share = eta2 / (eta1 + eta2);
+
+        // I_{k-1} terms do not depend on n.
+        let up_integrals: Vec<f64> = (1..=max_jumps)
+            .map(|k| i_n(k - 1, c, -eta1, -1.0 / std_dev, -sigma * eta1 * tau.sqrt()))
+            .collect();
+        let down_integrals: Vec<f64> = (1..=max_jumps)
+            .map(|k| i_n(k - 1, c, eta2, 1.0 / std_dev, -sigma * eta2 * tau.sqrt()))
+            .collect();
+
+        let mut up_sum = 0.0;
+        let mut down_sum = 0.0;
+        let mut weight = (-self.lambda * tau).exp();
+        let no_jump_weight = weight;
+        for n in 1..=max_jumps {
+            weight *= self.lambda * tau / n as f64;
+            for k in 1..=n {
+                let (p_nk, q_nk) = if k == n {
+                    (p.powi(n as i32), q.powi(n as i32))
+                } else {
+                    (k..n).fold((0.0, 0.0), |(p_nk, q_nk), i| {
+                        let coefficient = binomial(n - k - 1, i - k) * binomial(n, i);
+                        (
+                            coefficient.mul_add(
+                                up_share.powi((i - k) as i32)
+                                    * down_share.powi((n - i) as i32)
+                                    * p.powi(i as i32)
+                                    * q.powi((n - i) as i32),
+                                p_nk,
+                            ),
+                            coefficient.mul_add(
+                                up_share.powi((n - i) as i32)
+                                    * down_share.powi((i - k) as i32)
+                                    * p.powi((n - i) as i32)
+                                    * q.powi(i as i32),
+                                q_nk,
+                            ),
+                        )
+                    })
+                };
+                up_sum += weight * p_nk * (std_dev * eta1).powi(k as i32) * up_integrals[k - 1];
+                down_sum += weight * q_nk * (std_dev * eta2).powi(k as i32) * down_integrals[k - 1];
+            }
+        }
+
+        let scale = 1.0 / (std_dev * (2.0 * PI).sqrt());
+        (0.5 * (sigma * eta1).powi(2) * tau).exp() * scale * up_sum
+            + (0.5 * (sigma * eta2).powi(2) * tau).exp() * scale * down_sum
+            + no_jump_weight * Normal::standard().cdf(-c / std_dev)
+    }
+}
+
+impl<'a, C, D> Pricer<C> for AnalyticKou<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn price(&self, option: &EuropeanOption<C>) -> Money<C> {
+        let t = option.get_exercise().get_last_date();
+
+        let k = *option.get_strike().amount();
+        let (tau, d, _, f) = time_discount_and_forward(
+            self.underlying_spot,
+            self.yield_curve,
+            self.dividend_curve,
+            t,
+        );
+
+        let model = self.model;
+        let sigma = model.get_sigma();
+        let zeta = model.mean_relative_jump();
+
+        let share_measure = JumpDiffusion {
+            mu: (0.5 * sigma).mul_add(sigma, -model.get_lambda() * zeta),
+            sigma,
+            lambda: model.get_lambda() * (1.0 + zeta),
+            p: model.get_p() / (1.0 + zeta) * model.get_eta1() / (model.get_eta1() - 1.0),
+            eta1: model.get_eta1() - 1.0,
+            eta2: model.get_eta2() + 1.0,
+        };
+        let risk_neutral_measure = JumpDiffusion {
+            mu: (-0.5 * sigma).mul_add(sigma, -model.get_lambda() * zeta),
+            sigma,
+            lambda: model.get_lambda(),
+            p: model.get_p(),
+            eta1: model.get_eta1(),
+            eta2: model.get_eta2(),
+        };
+
+        let a = (k / f).ln();
+        let call_price = d * f.mul_add(
+            share_measure.upsilon(a, tau, self.tolerance),
+            -k * risk_neutral_measure.upsilon(a, tau, self.tolerance),
+        );
+
+        match option.get_option_type() {
+            OptionType::CALL => Money::new(call_price),
+            OptionType::PUT => Money::new(d.mul_add(k - f, call_price)),
+        }
+    }
+
+    fn price_vec(&self, options: &[EuropeanOption<C>]) -> Vec<Money<C>> {
+        options.iter().map(|option| self.price(option)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, VanillaPayoff},
+        money::currency::USD,
+        pricers::CosFangOosterlee,
+        term_structures::flat_curve,
+        time::DateTime,
+        Compounding,
+    };
+
+    #[test]
+    fn test_hh() {
+        // Hh_1(x) = e^{-x^2/2} - x sqrt(2 pi) N(-x).
+        let x = 0.3;
+        let values = hh(1, x);
+        assert_approx_eq!(values[0], (-0.5f64 * x * x).exp());
+        assert_approx_eq!(values[2], values[0] - x * values[1]);
+    }
+
+    #[test]
+    fn test_analytic_kou() {
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.02,
+            Compounding::Continuous,
+        );
+        let spot = Money::new(100.0);
+
+        let model = KouModel::new(0.16, 1.0, 0.4, 10.0, 5.0);
+        let kou = AnalyticKou::new(spot, &model, &yield_curve, &dividend_curve);
+        let cos = CosFangOosterlee::new(spot, &model, &yield_curve, &dividend_curve)
+            .with_number_of_terms(1024)
+            .with_truncation_width(16.0);
+
+        for expiry in [
+            DateTime::new_from_ymd(2023, 2, 1),
+            DateTime::new_from_ymd(2023, 7, 2),
+            DateTime::new_from_ymd(2024, 1, 1),
+        ] {
+            for strike in [80.0, 98.0, 100.0, 120.0] {
+                for option_type in [OptionType::CALL, OptionType::PUT] {
+                    let option = EuropeanOption::new(
+                        VanillaPayoff::new(Money::new(strike), option_type),
+                        EuropeanExercise::new(expiry),
+                    );
+                    assert_approx_eq!(
+                        *kou.price(&option).amount(),
+                        *cos.price(&option).amount(),
+                        10e-8
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jumps_for_tolerance() {
+        assert_eq!(jumps_for_tolerance(0.0, 1e-12), 0);
+        // With a mean of 1, more than 2 jumps have a probability of 1 - 5 / (2e) = 0.0803.
+        assert_eq!(jumps_for_tolerance(1.0, 0.1), 2);
+        assert_eq!(jumps_for_tolerance(1.0, 0.08), 3);
+        // The series grows with the expected number of jumps, well beyond a fixed cut-off.
+        assert!(jumps_for_tolerance(30.0, 1e-12) > 60);
+    }
+
+    #[test]
+    fn test_analytic_kou_high_intensity() {
+        let curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let spot = Money::new(100.0);
+
+        // About 30 jumps a year, where a series cut at 20 jumps misses most of the jump paths.
+        let model = KouModel::new(0.1, 30.0, 0.4, 30.0, 25.0);
+        let kou = AnalyticKou::new(spot, &model, &curve, &curve);
+        let cos = CosFangOosterlee::new(spot, &model, &curve, &curve)
+            .with_number_of_terms(1024)
+            .with_truncation_width(16.0);
+
+        for strike in [80.0, 100.0, 120.0] {
+            let option = EuropeanOption::new(
+                VanillaPayoff::new(Money::new(strike), OptionType::CALL),
+                EuropeanExercise::new(DateTime::new_from_ymd(2024, 1, 1)),
+            );
+            assert_approx_eq!(
+                *kou.price(&option).amount(),
+                *cos.price(&option).amount(),
+                10e-7
+            );
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use day_count_conventions::DayCounter;
+
+use crate::{
+    instruments::{EuropeanOption, Exercise, Option},
+    models::MertonJumpDiffusionModel,
+    money::{Currency, Money},
+    pricers::{black_formula, time_discount_and_forward, Pricer},
+    term_structures::YieldTermStructure,
+};
+
+/// Analytic Merton jump-diffusion pricer for European options.
+///
+/// Conditioning on the number of jumps $n$ before expiry, the log-price is normal, so the price is
+/// a Poisson-weighted series of Black prices:
+/// $$
+///     V = \sum_{n=0}^{\infty} e^{-\lambda \tau} \frac{(\lambda \tau)^n}{n!}
+///         \text{Black}(F_n, K, \sigma_n \sqrt{\tau}, D)
+/// $$
+/// with
+/// $$
+///     F_n = F e^{-\lambda k \tau} (1 + k)^n, \quad
+///     \sigma_n^2 \tau = \sigma^2 \tau + n \sigma_J^2
+/// $$
+/// where $k = e^{\mu_J + \frac{1}{2} \sigma_J^2} - 1$ is the mean relative jump.
+pub struct AnalyticMertonJumpDiffusion<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    model: &'a MertonJumpDiffusionModel,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    max_terms: usize,
+}
+
+impl<'a, C, D> AnalyticMertonJumpDiffusion<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Probability mass below which the series is truncated.
+    const TOLERANCE: f64 = 1e-16;
+
+    /// Create a new `AnalyticMertonJumpDiffusion` pricer, summing at most 100 terms.
+    pub fn new(
+        underlying_spot: Money<C>,
+        model: &'a MertonJumpDiffusionModel,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            model,
+            yield_curve,
+            dividend_curve,
+            max_terms: 100,
+        }
+    }
+
+    /// Set the maximum number of terms in the series.
+    #[must_use]
+    pub const fn with_max_terms(mut self, max_terms: usize) -> Self {
+        self.max_terms = max_terms;
+        self
+    }
+}
+
+impl<'a, C, D> Pricer<C> for AnalyticMertonJumpDiffusion<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn price(&self, option: &EuropeanOption<C>) -> Money<C> {
+        let t = option.get_exercise().get_last_date();
+
+        let k = *option.get_strike().amount();
+        let (tau, d, _, f) = time_discount_and_forward(
+            self.underlying_spot,
+            self.yield_curve,
+            self.dividend_curve,
+            t,
+        );
+
+        let lambda_tau = self.model.get_lambda() * tau;
+        let mean_relative_jump = self.model.mean_relative_jump();
+        let diffusion_variance = self.model.get_sigma().powi(2) * tau;
+        let jump_variance = self.model.get_jump_volatility().powi(2);
+
+        let mut weight = (-lambda_tau).exp();
+        let mut cumulative_weight = 0.0;
+        let mut forward = f * (-lambda_tau * mean_relative_jump).exp();
+        let mut price = 0.0;
+        for n in 0..self.max_terms {
+            let variance = (n as f64).mul_add(jump_variance, diffusion_variance);
+            price +=
+                weight * black_formula(option.get_option_type(), k, forward, variance.sqrt(), d);
+
+            cumulative_weight += weight;
+            if 1.0 - cumulative_weight < Self::TOLERANCE {
+                break;
+            }
+            weight *= lambda_tau / (n + 1) as f64;
+            forward *= 1.0 + mean_relative_jump;
+        }
+
+        Money::new(price)
+    }
+
+    fn price_vec(&self, options: &[EuropeanOption<C>]) -> Vec<Money<C>> {
+        options.iter().map(|option| self.price(option)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, OptionType, VanillaPayoff},
+        money::currency::USD,
+        pricers::{AnalyticBlackScholesMerton, CosFangOosterlee},
+        term_structures::{flat_curve, ConstantVolTermStructureBuilder},
+        time::DateTime,
+        Compounding,
+    };
+
+    fn options(expiry: DateTime) -> Vec<EuropeanOption<USD>> {
+        [80.0, 100.0, 120.0]
+            .into_iter()
+            .flat_map(|k| {
+                [OptionType::CALL, OptionType::PUT].map(|option_type| {
+                    EuropeanOption::new(
+                        VanillaPayoff::new(Money::new(k), option_type),
+                        EuropeanExercise::new(expiry),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_analytic_merton_jump_diffusion() {
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.02,
+            Compounding::Continuous,
+        );
+        let spot = Money::new(100.0);
+
+        let model = MertonJumpDiffusionModel::new(0.15, 0.5, -0.1, 0.15);
+        let merton = AnalyticMertonJumpDiffusion::new(spot, &model, &yield_curve, &dividend_curve);
+        let cos = CosFangOosterlee::new(spot, &model, &yield_curve, &dividend_curve);
+
+        for expiry in [
+            DateTime::new_from_ymd(2023, 2, 1),
+            DateTime::new_from_ymd(2024, 1, 1),
+        ] {
+            for (merton_price, cos_price) in merton
+                .price_vec(&options(expiry))
+                .into_iter()
+                .zip(cos.price_vec(&options(expiry)))
+            {
+                assert_approx_eq!(*merton_price.amount(), *cos_price.amount(), 10e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_analytic_merton_jump_diffusion_without_jumps() {
+        let yield_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let vol_curve = ConstantVolTermStructureBuilder::new()
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .day_count_convention(Actual365Fixed)
+            .volatility(0.2)
+            .build()
+            .unwrap();
+        let spot = Money::new(100.0);
+
+        let model = MertonJumpDiffusionModel::new(0.2, 0.0, -0.1, 0.15);
+        let no_dividends = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+        let merton = AnalyticMertonJumpDiffusion::new(spot, &model, &yield_curve, &no_dividends);
+        let bsm = AnalyticBlackScholesMerton::new(spot, &vol_curve, &yield_curve);
+
+        let options = options(DateTime::new_from_ymd(2024, 1, 1));
+        for (merton_price, bsm_price) in merton
+            .price_vec(&options)
+            .into_iter()
+            .zip(bsm.price_vec(&options))
+        {
+            assert_approx_eq!(*merton_price.amount(), *bsm_price.amount(), 10e-10);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+use crate::instruments::OptionType;
+use crate::math::solvers::{Brent, SolverError};
+
+/// Black (1976) formula for a European option on a forward.
+///
+/// $$
+///     C = D \left( F N(d_+) - K N(d_-) \right), \quad
+///     P = D \left( K N(-d_-) - F N(-d_+) \right)
+/// $$
+/// with
+/// $$
+///     d_\pm = \frac{\ln(F / K)}{\sigma \sqrt{\tau}} \pm \frac{\sigma \sqrt{\tau}}{2}
+/// $$
+/// where `std_dev` is the total standard deviation $\sigma \sqrt{\tau}$ and `discount` the
+/// discount factor $D$ to the payment date.
+#[must_use]
+pub fn black_formula(
+    option_type: OptionType,
+    strike: f64,
+    forward: f64,
+    std_dev: f64,
+    discount: f64,
+) -> f64 {
+    if std_dev <= 0.0 {
+        // No optionality left: the option is worth its discounted intrinsic value.
+        let intrinsic = match option_type {
+            OptionType::CALL => (forward - strike).max(0.0),
+            OptionType::PUT => (strike - forward).max(0.0),
+        };
+        return discount * intrinsic;
+    }
+
+    let d_plus = (0.5 * std_dev).mul_add(std_dev, (forward / strike).ln()) / std_dev;
+    let d_minus = d_plus - std_dev;
+
+    let norm = Normal::standard();
+    match option_type {
+        OptionType::CALL => {
+            discount * forward.mul_add(norm.cdf(d_plus), -strike * norm.cdf(d_minus))
+        }
+        OptionType::PUT => {
+            discount * strike.mul_add(norm.cdf(-d_minus), -forward * norm.cdf(-d_plus))
+        }
+    }
+}
+
+/// Total standard deviation $\sigma \sqrt{\tau}$ implied by a price in the Black formula.
+///
+/// Solved with Brent's method on $[0, 20]$, which covers every price strictly between the
+/// discounted intrinsic value and the no-arbitrage upper bound ($D F$ for calls, $D K$ for puts).
+///
+/// # Errors
+/// Will return `NotBracketed` if the price violates these bounds, and `NoConvergence` if the
+/// solver did not converge.
+pub fn black_implied_std_dev(
+    option_type: OptionType,
+    strike: f64,
+    forward: f64,
+    price: f64,
+    discount: f64,
+) -> Result<f64, SolverError> {
+    Brent::default().solve(
+        |std_dev| black_formula(option_type, strike, forward, std_dev, discount) - price,
+        0.0,
+        20.0,
+    )
+}
+
+/// Bachelier (normal) formula for a European option on a forward.
+///
+/// $$
+///     C = D \left( (F - K) N(d) + \sigma_N \sqrt{\tau} n(d) \right), \quad
+///     P = D \left( (K - F) N(-d) + \sigma_N \sqrt{\tau} n(d) \right)
+/// $$
+/// with $d = \frac{F - K}{\sigma_N \sqrt{\tau}}$, where `std_dev` is the total normal standard
+/// deviation $\sigma_N \sqrt{\tau}$ and `discount` the discount factor $D$ to the payment date.
+#[must_use]
+pub fn bachelier_formula(
+    option_type: OptionType,
+    strike: f64,
+    forward: f64,
+    std_dev: f64,
+    discount: f64,
+) -> f64 {
+    let sign = match option_type {
+        OptionType::CALL => 1.0,
+        OptionType::PUT => -1.0,
+    };
+    if std_dev <= 0.0 {
+        return discount * (sign * (forward - strike)).max(0.0);
+    }
+
+    let d = sign * (forward - strike) / std_dev;
+    let norm = Normal::standard();
+    discount * (sign * (forward - strike)).mul_add(norm.cdf(d), std_dev * norm.pdf(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_black_formula() {
+        // Hull, Example 18.6: F = 20, K = 20, r = 9%, sigma = 25%, T = 4 months.
+        let discount = (-0.09f64 / 3.0).exp();
+        let std_dev = 0.25 * (1.0f64 / 3.0).sqrt();
+        assert_approx_eq!(
+            black_formula(OptionType::PUT, 20.0, 20.0, std_dev, discount),
+            1.116_6,
+            10e-5
+        );
+
+        // Put-call parity.
+        let call = black_formula(OptionType::CALL, 95.0, 100.0, 0.2, 0.95);
+        let put = black_formula(OptionType::PUT, 95.0, 100.0, 0.2, 0.95);
+        assert_approx_eq!(call - put, 0.95 * (100.0 - 95.0), 10e-12);
+
+        // Zero volatility collapses to intrinsic value.
+        assert_approx_eq!(
+            black_formula(OptionType::CALL, 95.0, 100.0, 0.0, 0.95),
+            0.95 * 5.0
+        );
+        assert_approx_eq!(black_formula(OptionType::PUT, 95.0, 100.0, 0.0, 0.95), 0.0);
+    }
+
+    #[test]
+    fn test_bachelier_formula() {
+        // At the money: C = P = D sigma sqrt(tau) / sqrt(2 pi).
+        let atm = bachelier_formula(OptionType::CALL, 100.0, 100.0, 20.0, 0.95);
+        assert_approx_eq!(
+            atm,
+            0.95 * 20.0 / (2.0 * std::f64::consts::PI).sqrt(),
+            10e-12
+        );
+
+        // Put-call parity.
+        let call = bachelier_formula(OptionType::CALL, 95.0, 100.0, 20.0, 0.95);
+        let put = bachelier_formula(OptionType::PUT, 95.0, 100.0, 20.0, 0.95);
+        assert_approx_eq!(call - put, 0.95 * (100.0 - 95.0), 10e-12);
+
+        // Zero volatility collapses to intrinsic value.
+        assert_approx_eq!(
+            bachelier_formula(OptionType::PUT, 105.0, 100.0, 0.0, 0.95),
+            0.95 * 5.0
+        );
+    }
+
+    #[test]
+    fn test_black_implied_std_dev() {
+        for option_type in [OptionType::CALL, OptionType::PUT] {
+            for strike in [60.0, 100.0, 140.0] {
+                let price = black_formula(option_type, strike, 100.0, 0.3, 0.95);
+                let std_dev =
+                    black_implied_std_dev(option_type, strike, 100.0, price, 0.95).unwrap();
+                assert_approx_eq!(std_dev, 0.3, 10e-10);
+            }
+        }
+
+        // Below intrinsic value.
+        assert!(black_implied_std_dev(OptionType::CALL, 80.0, 100.0, 10.0, 1.0).is_err());
+    }
+}
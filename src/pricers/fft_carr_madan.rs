@@ -0,0 +1,363 @@
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+use day_count_conventions::DayCounter;
+use num::complex::Complex64;
+use ordered_float::OrderedFloat;
+
+use crate::{
+    instruments::{EuropeanOption, Exercise, Option, OptionType},
+    math::{
+        fft,
+        interpolation::{InterpolationResult, Interpolator, LinearInterpolator},
+    },
+    models::CharacteristicFunction,
+    money::{Currency, Money},
+    pricers::{time_discount_and_forward, Pricer},
+    term_structures::YieldTermStructure,
+    time::DateTime,
+};
+
+/// Carr-Madan FFT pricer for European options.
+///
+/// The damped call price $e^{\alpha k} c(k)$, as a function of the log-moneyness
+/// $k = \ln(K / F)$, is square-integrable and has Fourier transform
+/// $$
+///     \psi(v) = \frac{\phi(v - (\alpha + 1)i)}{\alpha^2 + \alpha - v^2 + i(2\alpha + 1)v}
+/// $$
+/// so that a single FFT of size $N$ (with Simpson weights) prices calls on a whole strip of
+/// log-strikes $k_u = -\frac{N\lambda}{2} + \lambda u$, where $\lambda \eta = \frac{2\pi}{N}$.
+/// Prices for arbitrary strikes are linearly interpolated on that strip, and puts follow from
+/// put-call parity.
+pub struct FftCarrMadan<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    model: &'a dyn CharacteristicFunction,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    number_of_points: usize,
+    grid_spacing: f64,
+    damping: f64,
+}
+
+impl<'a, C, D> FftCarrMadan<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `FftCarrMadan` pricer, using $N = 4096$ points, a grid spacing
+    /// $\eta = 0.25$ and a damping factor $\alpha = 1.5$.
+    pub fn new(
+        underlying_spot: Money<C>,
+        model: &'a impl CharacteristicFunction,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            model,
+            yield_curve,
+            dividend_curve,
+            number_of_points: 4096,
+            grid_spacing: 0.25,
+            damping: 1.5,
+        }
+    }
+
+    /// Set the number of FFT points $N$, rounded up to the next power of two (and at least two)
+    /// for the radix-2 FFT.
+    #[must_use]
+    pub const fn with_number_of_points(mut self, number_of_points: usize) -> Self {
+        self.number_of_points = if number_of_points < 2 {
+            2
+        } else {
+            number_of_points.next_power_of_two()
+        };
+        self
+    }
+
+    /// Set the spacing $\eta$ of the integration grid.
+    #[must_use]
+    pub const fn with_grid_spacing(mut self, grid_spacing: f64) -> Self {
+        self.grid_spacing = grid_spacing;
+        self
+    }
+
+    /// Set the damping factor $\alpha$.
+    #[must_use]
+    pub const fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Call prices for the whole strip of strikes at a maturity.
+    ///
+    /// Returns `(strike, call price)` pairs, in increasing order of strike.
+    ///
+    /// # Panics
+    /// if the maturity is not valid for the yield or dividend curve.
+    pub fn call_price_strip(&self, maturity: DateTime) -> Vec<(Money<C>, Money<C>)> {
+        let (d, f, tau) = self.discount_forward_and_time(maturity);
+        self.normalised_call_strip(tau)
+            .into_iter()
+            .map(|(k, c)| (Money::new(f * k.exp()), Money::new(d * f * c)))
+            .collect()
+    }
+
+    /// Discount factor, forward and year fraction to maturity.
+    fn discount_forward_and_time(&self, t: DateTime) -> (f64, f64, f64) {
+        let (tau, d, _, f) = time_discount_and_forward(
+            self.underlying_spot,
+            self.yield_curve,
+            self.dividend_curve,
+            t,
+        );
+        (d, f, tau)
+    }
+
+    /// Undiscounted call prices, in units of the forward, against log-moneyness.
+    #[allow(clippy::cast_precision_loss)]
+    fn normalised_call_strip(&self, tau: f64) -> Vec<(f64, f64)> {
+        let n = self.number_of_points;
+        let eta = self.grid_spacing;
+        let alpha = self.damping;
+        let lambda = 2.0 * PI / (n as f64 * eta);
+        let b = n as f64 * lambda / 2.0;
+
+        let i = Complex64::i();
+        let mut values: Vec<Complex64> = (0..n)
+            .map(|j| {
+                let v = j as f64 * eta;
+                let psi = self
+                    .model
+                    .characteristic_function(Complex64::new(v, -(alpha + 1.0)), tau)
+                    / Complex64::new(
+                        alpha.mul_add(alpha, alpha) - v * v,
+                        2.0f64.mul_add(alpha, 1.0) * v,
+                    );
+                // Simpson's rule weights.
+                let simpson = match j {
+                    0 => 1.0 / 3.0,
+                    _ if j % 2 == 1 => 4.0 / 3.0,
+                    _ => 2.0 / 3.0,
+                };
+                (i * b * v).exp() * psi * eta * simpson
+            })
+            .collect();
+        fft(&mut values);
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(u, value)| {
+                let k = lambda.mul_add(u as f64, -b);
+                (k, (-alpha * k).exp() / PI * value.re)
+            })
+            .collect()
+    }
+
+    /// Price an option from a precomputed strip at its maturity.
+    fn price_from_strip(
+        &self,
+        option: &EuropeanOption<C>,
+        strip: &LinearInterpolator<OrderedFloat<f64>, OrderedFloat<f64>>,
+        d: f64,
+        f: f64,
+    ) -> Money<C> {
+        let k = *option.get_strike().amount();
+        let call_price = match strip.interpolate(OrderedFloat((k / f).ln())) {
+            InterpolationResult::ExistingValue(c) | InterpolationResult::InterpolatedValue(c) => {
+                d * f * *c
+            }
+            InterpolationResult::OutOfRange => panic!("Strike out of range of the FFT grid."),
+            InterpolationResult::NoPoints => panic!("No points."),
+        };
+        match option.get_option_type() {
+            OptionType::CALL => Money::new(call_price),
+            OptionType::PUT => Money::new(d.mul_add(k - f, call_price)),
+        }
+    }
+
+    fn strip_interpolator(
+        &self,
+        tau: f64,
+    ) -> LinearInterpolator<OrderedFloat<f64>, OrderedFloat<f64>> {
+        LinearInterpolator::new_from_points(
+            self.normalised_call_strip(tau)
+                .into_iter()
+                .map(|(k, c)| (OrderedFloat(k), OrderedFloat(c)))
+                .collect(),
+        )
+    }
+}
+
+impl<'a, C, D> Pricer<C> for FftCarrMadan<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn price(&self, option: &EuropeanOption<C>) -> Money<C> {
+        let (d, f, tau) = self.discount_forward_and_time(option.get_exercise().get_last_date());
+        self.price_from_strip(option, &self.strip_interpolator(tau), d, f)
+    }
+
+    /// Price several options, running a single FFT per distinct maturity.
+    fn price_vec(&self, options: &[EuropeanOption<C>]) -> Vec<Money<C>> {
+        let mut strips = BTreeMap::new();
+        options
+            .iter()
+            .map(|option| {
+                let t = option.get_exercise().get_last_date();
+                let (d, f, strip) = strips.entry(t).or_insert_with(|| {
+                    let (d, f, tau) = self.discount_forward_and_time(t);
+                    (d, f, self.strip_interpolator(tau))
+                });
+                self.price_from_strip(option, strip, *d, *f)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, VanillaPayoff},
+        models::{BatesModel, BlackScholesModel, HestonModel, MertonJumpDiffusionModel},
+        money::currency::USD,
+        pricers::{AnalyticBlackScholesMerton, CosFangOosterlee},
+        term_structures::{flat_curve, ConstantVolTermStructureBuilder},
+        Compounding,
+    };
+
+    fn options() -> Vec<EuropeanOption<USD>> {
+        [80.0, 95.0, 100.0, 105.0, 120.0]
+            .into_iter()
+            .flat_map(|k| {
+                [OptionType::CALL, OptionType::PUT].map(|option_type| {
+                    EuropeanOption::new(
+                        VanillaPayoff::new(Money::new(k), option_type),
+                        EuropeanExercise::new(DateTime::new_from_ymd(2024, 1, 1)),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fft_black_scholes() {
+        let yield_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.02,
+            Compounding::Continuous,
+        );
+        let vol_curve = ConstantVolTermStructureBuilder::new()
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .day_count_convention(Actual365Fixed)
+            .volatility(0.25)
+            .build()
+            .unwrap();
+
+        let model = BlackScholesModel::new(0.25);
+        let fft = FftCarrMadan::new(Money::new(100.0), &model, &yield_curve, &dividend_curve);
+        let cos = CosFangOosterlee::new(Money::new(100.0), &model, &yield_curve, &dividend_curve);
+        let bsm = AnalyticBlackScholesMerton::new(Money::new(100.0), &vol_curve, &yield_curve);
+
+        let options = options();
+        for ((fft_price, cos_price), option) in fft
+            .price_vec(&options)
+            .into_iter()
+            .zip(cos.price_vec(&options))
+            .zip(&options)
+        {
+            assert_approx_eq!(*fft_price.amount(), *cos_price.amount(), 10e-4);
+            assert_approx_eq!(*fft_price.amount(), *fft.price(option).amount(), 10e-12);
+        }
+        // Without dividends, the engines agree with the closed form.
+        let no_dividends = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+        let fft = FftCarrMadan::new(Money::new(100.0), &model, &yield_curve, &no_dividends);
+        for (fft_price, bsm_price) in fft
+            .price_vec(&options)
+            .into_iter()
+            .zip(bsm.price_vec(&options))
+        {
+            assert_approx_eq!(*fft_price.amount(), *bsm_price.amount(), 10e-4);
+        }
+    }
+
+    #[test]
+    fn test_fft_heston_and_bates() {
+        let curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+        // Fang & Oosterlee (2008), Table 4.
+        let heston = HestonModel::new(0.0175, 1.5768, 0.0398, 0.5751, -0.5711);
+        let fft = FftCarrMadan::new(Money::new(100.0), &heston, &curve, &curve);
+        assert_approx_eq!(*fft.price(&options()[4]).amount(), 5.785_155_450, 10e-4);
+
+        // Bates without jumps is Heston.
+        let bates = BatesModel::new(heston, 0.0, -0.1, 0.1);
+        let fft_bates = FftCarrMadan::new(Money::new(100.0), &bates, &curve, &curve);
+        for (bates_price, heston_price) in fft_bates
+            .price_vec(&options())
+            .into_iter()
+            .zip(fft.price_vec(&options()))
+        {
+            assert_approx_eq!(*bates_price.amount(), *heston_price.amount(), 10e-10);
+        }
+    }
+
+    #[test]
+    fn test_fft_merton_jump_diffusion() {
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+        let model = MertonJumpDiffusionModel::new(0.15, 0.5, -0.1, 0.15);
+
+        let fft = FftCarrMadan::new(Money::new(100.0), &model, &yield_curve, &dividend_curve);
+        let cos = CosFangOosterlee::new(Money::new(100.0), &model, &yield_curve, &dividend_curve);
+        for (fft_price, cos_price) in fft
+            .price_vec(&options())
+            .into_iter()
+            .zip(cos.price_vec(&options()))
+        {
+            assert_approx_eq!(*fft_price.amount(), *cos_price.amount(), 10e-4);
+        }
+
+        let strip = fft.call_price_strip(DateTime::new_from_ymd(2024, 1, 1));
+        assert_eq!(strip.len(), 4096);
+        assert!(strip.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        // Sizes the radix-2 FFT cannot take are rounded up to the next power of two.
+        let rounded = FftCarrMadan::new(Money::new(100.0), &model, &yield_curve, &dividend_curve)
+            .with_number_of_points(3000);
+        assert_eq!(
+            rounded.call_price_strip(DateTime::new_from_ymd(2024, 1, 1)),
+            strip
+        );
+    }
+}
@@ -1,9 +1,31 @@
+mod black_formula;
+pub use black_formula::{bachelier_formula, black_formula, black_implied_std_dev};
+
 mod analytic_black_scholes_merton;
 pub use analytic_black_scholes_merton::AnalyticBlackScholesMerton;
 
+mod analytic_heston;
+pub use analytic_heston::AnalyticHeston;
+
+mod analytic_merton_jump_diffusion;
+pub use analytic_merton_jump_diffusion::AnalyticMertonJumpDiffusion;
+
+mod analytic_kou;
+pub use analytic_kou::AnalyticKou;
+
+mod fft_carr_madan;
+pub use fft_carr_madan::FftCarrMadan;
+
+mod cos_fang_oosterlee;
+pub use cos_fang_oosterlee::CosFangOosterlee;
+
+use day_count_conventions::DayCounter;
+
 use crate::{
     instruments::EuropeanOption,
     money::{Currency, Money},
+    term_structures::YieldTermStructure,
+    time::DateTime,
 };
 
 pub trait Pricer<C>
@@ -14,3 +36,27 @@ where
 
     fn price_vec(&self, options: &[EuropeanOption<C>]) -> Vec<Money<C>>;
 }
+
+/// Year fraction to maturity $\tau$ on the yield curve, discount factors $D_r$ and $D_q$ of the
+/// yield and dividend curves, and forward price of the underlying $F = S D_q / D_r$ at a
+/// maturity date.
+fn time_discount_and_forward<C, D>(
+    underlying_spot: Money<C>,
+    yield_curve: &dyn YieldTermStructure<C, D>,
+    dividend_curve: &dyn YieldTermStructure<C, D>,
+    maturity_date: DateTime,
+) -> (f64, f64, f64, f64)
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let df_r = *yield_curve.discount_factor(maturity_date).unwrap();
+    let df_q = *dividend_curve.discount_factor(maturity_date).unwrap();
+    let forward = *underlying_spot.amount() * df_q / df_r;
+
+    let tau = yield_curve
+        .get_day_counter()
+        .day_count_fraction(&yield_curve.get_reference_date(), &maturity_date)
+        .get_fraction();
+    (tau, df_r, df_q, forward)
+}
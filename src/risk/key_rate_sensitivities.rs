@@ -0,0 +1,474 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::math::interpolation::Interpolator;
+use crate::money::Currency;
+use crate::term_structures::{
+    PiecewiseYieldCurveBuilder, PiecewiseYieldCurveBuilderError, TermStructure, TermStructureError,
+    YieldTermStructure, ZeroSpreadedTermStructure,
+};
+use crate::time::DateTime;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// One basis point.
+const BASIS_POINT: f64 = 1e-4;
+
+/// Sensitivity of a value to the rates of one bucket of the curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRateSensitivity {
+    /// Tenor of the bucket from the reference date of the curve, e.g. `"3M"` or `"10Y"`.
+    tenor: String,
+
+    /// Date of the bucket.
+    date: DateTime,
+
+    /// Change in value for a 1bp decrease of the rates of the bucket.
+    dv01: f64,
+
+    /// Key-rate duration, $-\frac{1}{V} \frac{\partial V}{\partial r_i}$, or `None` if the
+    /// value is zero, as for an at-market swap.
+    duration: Option<f64>,
+}
+
+/// Bucketed sensitivities of a value to a curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveSensitivities {
+    /// Value on the unbumped curve.
+    value: f64,
+
+    /// Change in value for a 1bp parallel decrease of the rates: the DV01 for zero-rate bumps,
+    /// often called the PV01 for par-rate bumps.
+    parallel_dv01: f64,
+
+    /// Sensitivities to each bucket, sorted by date.
+    key_rates: Vec<KeyRateSensitivity>,
+}
+
+impl KeyRateSensitivity {
+    #[must_use]
+    pub fn get_tenor(&self) -> &str {
+        &self.tenor
+    }
+
+    #[must_use]
+    pub const fn get_date(&self) -> DateTime {
+        self.date
+    }
+
+    #[must_use]
+    pub const fn get_dv01(&self) -> f64 {
+        self.dv01
+    }
+
+    #[must_use]
+    pub const fn get_duration(&self) -> Option<f64> {
+        self.duration
+    }
+}
+
+impl CurveSensitivities {
+    #[must_use]
+    pub const fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    #[must_use]
+    pub const fn get_parallel_dv01(&self) -> f64 {
+        self.parallel_dv01
+    }
+
+    #[must_use]
+    pub fn get_key_rates(&self) -> &[KeyRateSensitivity] {
+        &self.key_rates
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CurveRiskError {
+    /// The bump size is not positive.
+    NonPositiveBumpSize,
+
+    /// No key dates, or key dates not after the reference date of the curve.
+    InvalidKeyDates,
+
+    /// The value could not be computed on a curve.
+    Pricing(TermStructureError),
+
+    /// A bumped curve could not be bootstrapped.
+    Bootstrap(PiecewiseYieldCurveBuilderError),
+}
+
+/// Key-rate sensitivities by bump-and-reprice with central differences.
+///
+/// Values are given by a pricing function of the curve, so any instrument that can be priced
+/// against a [`YieldTermStructure`] can be risked. Two kinds of bumps are supported:
+/// - zero-rate bumps of any curve, with a triangular bump of the continuously-compounded zero
+///   rate at each key date, falling to zero at the neighbouring key dates (and flat beyond the
+///   first and last key dates), so that the buckets add up to a parallel shift;
+/// - par-rate bumps of a bootstrapped curve, bumping the quote of each rate helper in turn and
+///   bootstrapping the curve again.
+#[derive(Debug, Copy, Clone)]
+pub struct KeyRateCalculator {
+    bump_size: f64,
+}
+
+impl KeyRateCalculator {
+    /// Create a new `KeyRateCalculator`, bumping rates up and down by `bump_size`.
+    ///
+    /// # Errors
+    /// Will return `NonPositiveBumpSize` if `bump_size` is not positive.
+    pub fn new(bump_size: f64) -> Result<Self, CurveRiskError> {
+        if bump_size.is_nan() || bump_size <= 0.0 {
+            return Err(CurveRiskError::NonPositiveBumpSize);
+        }
+        Ok(Self { bump_size })
+    }
+
+    #[must_use]
+    pub const fn get_bump_size(&self) -> f64 {
+        self.bump_size
+    }
+
+    /// Sensitivities from the value on the curve and its values with rates bumped up and down:
+    /// the DV01 and the duration, which is undefined for a zero value.
+    fn sensitivities(&self, value: f64, up: f64, down: f64) -> (f64, Option<f64>) {
+        let derivative = (up - down) / (2.0 * self.bump_size);
+        let duration = if value == 0.0 {
+            None
+        } else {
+            Some(-derivative / value)
+        };
+        (-derivative * BASIS_POINT, duration)
+    }
+
+    /// Sensitivities to zero-rate bumps of a curve at key dates.
+    ///
+    /// # Errors
+    /// Will return `InvalidKeyDates` if there are no key dates or one is not after the reference
+    /// date, and `Pricing` if the pricing function fails.
+    pub fn zero_rate_sensitivities<C, D, F>(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+        key_dates: &[DateTime],
+        pricer: F,
+    ) -> Result<CurveSensitivities, CurveRiskError>
+    where
+        C: Currency,
+        D: DayCounter,
+        F: Fn(&dyn YieldTermStructure<C, D>) -> Result<f64, TermStructureError>,
+    {
+        let reference_date = curve.get_reference_date();
+        let mut key_dates = key_dates.to_vec();
+        key_dates.sort();
+        key_dates.dedup();
+        if key_dates.is_empty() || key_dates[0] <= reference_date {
+            return Err(CurveRiskError::InvalidKeyDates);
+        }
+
+        let price =
+            |bumped: &dyn YieldTermStructure<C, D>| pricer(bumped).map_err(CurveRiskError::Pricing);
+        let value = price(curve)?;
+
+        let parallel = |bump: f64| {
+            price(&ZeroSpreadedTermStructure::new(
+                curve,
+                bump,
+                Compounding::Continuous,
+            ))
+        };
+        let (parallel_dv01, _) =
+            self.sensitivities(value, parallel(self.bump_size)?, parallel(-self.bump_size)?);
+
+        let key_rates = key_dates
+            .iter()
+            .enumerate()
+            .map(|(i, date)| {
+                let bumped = |bump: f64| {
+                    let mut spreads = vec![(*date, bump)];
+                    if i > 0 {
+                        spreads.push((key_dates[i - 1], 0.0));
+                    }
+                    if i + 1 < key_dates.len() {
+                        spreads.push((key_dates[i + 1], 0.0));
+                    }
+                    price(&ZeroSpreadedTermStructure::new_with_spreads(
+                        curve,
+                        &spreads,
+                        Compounding::Continuous,
+                    ))
+                };
+                let (dv01, duration) =
+                    self.sensitivities(value, bumped(self.bump_size)?, bumped(-self.bump_size)?);
+                Ok(KeyRateSensitivity {
+                    tenor: tenor_label(reference_date, *date),
+                    date: *date,
+                    dv01,
+                    duration,
+                })
+            })
+            .collect::<Result<_, CurveRiskError>>()?;
+
+        Ok(CurveSensitivities {
+            value,
+            parallel_dv01,
+            key_rates,
+        })
+    }
+
+    /// Sensitivities to par-rate bumps of the quotes of the helpers of a bootstrapped curve,
+    /// bucketed at the pillar dates.
+    ///
+    /// # Errors
+    /// Will return `Bootstrap` if the curve or a bumped curve cannot be bootstrapped, and
+    /// `Pricing` if the pricing function fails.
+    pub fn par_rate_sensitivities<C, D, I, F>(
+        &self,
+        builder: &PiecewiseYieldCurveBuilder<C, D, I>,
+        pricer: F,
+    ) -> Result<CurveSensitivities, CurveRiskError>
+    where
+        C: Currency,
+        D: DayCounter,
+        I: Interpolator<DateTime, OrderedFloat<f64>> + Clone,
+        F: Fn(&dyn YieldTermStructure<C, D>) -> Result<f64, TermStructureError>,
+    {
+        let price = |bumps: &[f64]| {
+            let curve = builder
+                .build_with_bumps(bumps)
+                .map_err(CurveRiskError::Bootstrap)?;
+            pricer(&curve).map_err(CurveRiskError::Pricing)
+        };
+
+        let curve = builder.build().map_err(CurveRiskError::Bootstrap)?;
+        let reference_date = curve.get_reference_date();
+        let value = pricer(&curve).map_err(CurveRiskError::Pricing)?;
+
+        let pillars = builder.pillar_dates();
+        let (parallel_dv01, _) = self.sensitivities(
+            value,
+            price(&vec![self.bump_size; pillars.len()])?,
+            price(&vec![-self.bump_size; pillars.len()])?,
+        );
+
+        let key_rates = pillars
+            .iter()
+            .enumerate()
+            .map(|(i, date)| {
+                let bumped = |bump: f64| {
+                    let mut bumps = vec![0.0; pillars.len()];
+                    bumps[i] = bump;
+                    price(&bumps)
+                };
+                let (dv01, duration) =
+                    self.sensitivities(value, bumped(self.bump_size)?, bumped(-self.bump_size)?);
+                Ok(KeyRateSensitivity {
+                    tenor: tenor_label(reference_date, *date),
+                    date: *date,
+                    dv01,
+                    duration,
+                })
+            })
+            .collect::<Result<_, CurveRiskError>>()?;
+
+        Ok(CurveSensitivities {
+            value,
+            parallel_dv01,
+            key_rates,
+        })
+    }
+}
+
+impl Default for KeyRateCalculator {
+    /// Create a new `KeyRateCalculator` with a bump of 1bp.
+    fn default() -> Self {
+        Self {
+            bump_size: BASIS_POINT,
+        }
+    }
+}
+
+/// Tenor between two dates, in days under a month, then in whole months or years.
+#[allow(clippy::cast_possible_truncation)]
+fn tenor_label(reference_date: DateTime, date: DateTime) -> String {
+    let seconds = |dt: DateTime| -> f64 { *Into::<OrderedFloat<f64>>::into(dt) };
+    let days = ((seconds(date) - seconds(reference_date)) / 86_400.0).round();
+    let months = (days / (365.25 / 12.0)).round() as i64;
+    if days < 28.0 {
+        format!("{}D", days as i64)
+    } else if months % 12 == 0 {
+        format!("{}Y", months / 12)
+    } else {
+        format!("{months}M")
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed};
+
+    use super::*;
+    use crate::{
+        math::interpolation::LinearInterpolator,
+        money::currency::USD,
+        term_structures::{
+            DepositRateHelper, InterpolatedZeroCurve, InterpolatedZeroCurveBuilder, SwapRateHelper,
+        },
+        time::Frequency,
+    };
+
+    fn reference_date() -> DateTime {
+        DateTime::new_from_ymd(2024, 1, 2)
+    }
+
+    /// Value of a 5 year annual bond paying a 4% coupon on a notional of 100.
+    fn bond_value(
+        curve: &dyn YieldTermStructure<USD, Actual365Fixed>,
+    ) -> Result<f64, TermStructureError> {
+        (1..=5).try_fold(0.0, |value, year| {
+            let cashflow = if year == 5 { 104.0 } else { 4.0 };
+            Ok(cashflow * *curve.discount_factor(reference_date().add_months(12 * year))? + value)
+        })
+    }
+
+    #[test]
+    fn test_zero_rate_sensitivities() {
+        let reference_date = reference_date();
+        let curve: InterpolatedZeroCurve<USD, Actual365Fixed, _> =
+            InterpolatedZeroCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[
+                    (reference_date.add_months(12), 0.03),
+                    (reference_date.add_months(60), 0.035),
+                ])
+                .reference_date(reference_date)
+                .build()
+                .unwrap();
+
+        let key_dates: Vec<DateTime> = [3, 12, 24, 60, 120]
+            .iter()
+            .map(|months| reference_date.add_months(*months))
+            .collect();
+        let sensitivities = KeyRateCalculator::default()
+            .zero_rate_sensitivities(&curve, &key_dates, bond_value)
+            .unwrap();
+
+        let tenors: Vec<&str> = sensitivities
+            .get_key_rates()
+            .iter()
+            .map(|key_rate| key_rate.get_tenor())
+            .collect();
+        assert_eq!(tenors, ["3M", "1Y", "2Y", "5Y", "10Y"]);
+
+        // The parallel DV01 is the sum of the cash flow durations.
+        let dv01: f64 = (1..=5)
+            .map(|year: i32| {
+                let date = reference_date.add_months(12 * year);
+                let time = Actual365Fixed
+                    .day_count_fraction(&reference_date, &date)
+                    .get_fraction();
+                let cashflow = if year == 5 { 104.0 } else { 4.0 };
+                cashflow * time * *curve.discount_factor(date).unwrap() * BASIS_POINT
+            })
+            .sum();
+        assert_approx_eq!(sensitivities.get_parallel_dv01(), dv01, 10e-9);
+
+        // Buckets add up to the parallel shift, and only the 5Y bucket sees the final payment.
+        let total: f64 = sensitivities
+            .get_key_rates()
+            .iter()
+            .map(|key_rate| key_rate.get_dv01())
+            .sum();
+        assert_approx_eq!(total, sensitivities.get_parallel_dv01(), 10e-9);
+        let key_rates = sensitivities.get_key_rates();
+        assert_approx_eq!(key_rates[4].get_dv01(), 0.0, 10e-12);
+        assert!(key_rates[3].get_dv01() > key_rates[2].get_dv01());
+        assert_approx_eq!(
+            key_rates[3].get_duration().unwrap(),
+            key_rates[3].get_dv01() / BASIS_POINT / sensitivities.get_value(),
+            10e-12
+        );
+
+        // The bond bought for its value is worth nothing, with the same DV01s but no durations.
+        let purchase = |curve: &dyn YieldTermStructure<USD, Actual365Fixed>| {
+            Ok(bond_value(curve)? - sensitivities.get_value())
+        };
+        let purchase_sensitivities = KeyRateCalculator::default()
+            .zero_rate_sensitivities(&curve, &key_dates, purchase)
+            .unwrap();
+        assert_eq!(purchase_sensitivities.get_value(), 0.0);
+        for (key_rate, bond_key_rate) in purchase_sensitivities
+            .get_key_rates()
+            .iter()
+            .zip(sensitivities.get_key_rates())
+        {
+            assert_approx_eq!(key_rate.get_dv01(), bond_key_rate.get_dv01(), 10e-12);
+            assert_eq!(key_rate.get_duration(), None);
+        }
+
+        assert_eq!(
+            KeyRateCalculator::default().zero_rate_sensitivities(&curve, &[], bond_value),
+            Err(CurveRiskError::InvalidKeyDates)
+        );
+        assert_eq!(
+            KeyRateCalculator::new(0.0).unwrap_err(),
+            CurveRiskError::NonPositiveBumpSize
+        );
+    }
+
+    #[test]
+    fn test_par_rate_sensitivities() {
+        let spot = reference_date();
+        let deposit = DepositRateHelper::new(0.05, spot, spot.add_months(6), Actual360);
+        let swaps: Vec<SwapRateHelper<USD, Actual365Fixed, _>> = [(24, 0.045), (60, 0.04)]
+            .iter()
+            .map(|(months, rate)| {
+                SwapRateHelper::new(
+                    *rate,
+                    spot,
+                    spot.add_months(*months),
+                    Frequency::Annual,
+                    Actual360,
+                )
+            })
+            .collect();
+        let mut builder = PiecewiseYieldCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .reference_date(spot)
+            .day_count_convention(Actual365Fixed)
+            .helper(&deposit);
+        for swap in &swaps {
+            builder.helper(swap);
+        }
+
+        let sensitivities = KeyRateCalculator::new(10e-4)
+            .unwrap()
+            .par_rate_sensitivities(&builder, bond_value)
+            .unwrap();
+        let tenors: Vec<&str> = sensitivities
+            .get_key_rates()
+            .iter()
+            .map(|key_rate| key_rate.get_tenor())
+            .collect();
+        assert_eq!(tenors, ["6M", "2Y", "5Y"]);
+
+        // A bond maturing on the last pillar is mostly exposed to the 5Y swap rate, and the
+        // buckets add up to the parallel shift to first order.
+        let key_rates = sensitivities.get_key_rates();
+        assert!(key_rates[2].get_dv01() > 0.0);
+        assert!(key_rates[2].get_dv01() > 10.0 * key_rates[1].get_dv01().abs());
+        let total: f64 = sensitivities
+            .get_key_rates()
+            .iter()
+            .map(|key_rate| key_rate.get_dv01())
+            .sum();
+        assert_approx_eq!(total, sensitivities.get_parallel_dv01(), 10e-6);
+    }
+}
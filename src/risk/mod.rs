@@ -0,0 +1,4 @@
+mod key_rate_sensitivities;
+pub use key_rate_sensitivities::{
+    CurveRiskError, CurveSensitivities, KeyRateCalculator, KeyRateSensitivity,
+};
@@ -1,14 +1,36 @@
 mod volatility_structure;
+#[cfg(test)]
+pub(crate) use volatility_structure::VolatilityTermStructure;
 pub use volatility_structure::{
-    BlackVolatilityCurve, BlackVolatilityCurveBuilder, BlackVolatilityTermStructure,
-    BlackVolatilityTermStructureResult, ConstantVolTermStructure, ConstantVolTermStructureBuilder,
-    ConstantVolTermStructureBuilderError,
+    BlackForwardVolatilityResult, BlackVarianceSurface, BlackVarianceSurfaceBuilder,
+    BlackVarianceSurfaceBuilderError, BlackVarianceSurfaceInterpolation, BlackVolatilityCurve,
+    BlackVolatilityCurveBuilder, BlackVolatilityTermStructure, BlackVolatilityTermStructureResult,
+    ConstantVolTermStructure, ConstantVolTermStructureBuilder,
+    ConstantVolTermStructureBuilderError, DupireLocalVolTermStructure, FxAtmType, FxButterflyType,
+    FxDeltaType, FxSmileInterpolation, FxSmileQuote, FxVolatilitySurface,
+    FxVolatilitySurfaceBuilder, FxVolatilitySurfaceBuilderError, JumpWingsSvi,
+    LocalVolTermStructure, LocalVolTermStructureResult, NaturalSvi, OptionChainSlice,
+    OptionChainSurfaceBuilder, OptionChainSurfaceBuilderError, OptionQuote, RawSvi,
+    SabrSmileSection, SabrSmileSectionBuilder, SabrSmileSectionBuilderError, SmileDynamics,
+    SmileDynamicsSurface, SsviSurface, SsviSurfaceBuilder, SsviSurfaceBuilderError,
+    SviCalibrationError, SviSurface, SviSurfaceBuilder, SviSurfaceBuilderError,
+    VolatilityExtrapolation,
 };
 
 mod yield_structure;
+#[cfg(test)]
+pub(crate) use yield_structure::flat_curve;
 pub use yield_structure::{
-    FlatForwardTermStructure, FlatForwardTermStructureBuilder,
-    FlatForwardTermStructureBuilderError, YieldTermStructure,
+    CompositeTermStructure, CurveSet, DepositRateHelper, Discount, FlatForwardTermStructure,
+    FlatForwardTermStructureBuilder, FlatForwardTermStructureBuilderError, ForwardRate,
+    ForwardSpreadedTermStructure, FraRateHelper, FuturesRateHelper, ImpliedTermStructure,
+    InterpolatedCurve, InterpolatedCurveBuilder, InterpolatedCurveBuilderError,
+    InterpolatedDiscountCurve, InterpolatedDiscountCurveBuilder, InterpolatedForwardCurve,
+    InterpolatedForwardCurveBuilder, InterpolatedQuantity, InterpolatedZeroCurve,
+    InterpolatedZeroCurveBuilder, NelsonSiegel, ParametricYieldCurve, ParametricYieldModel,
+    PiecewiseYieldCurve, PiecewiseYieldCurveBuilder, PiecewiseYieldCurveBuilderError, RateHelper,
+    SmithWilson, Svensson, SwapRateHelper, YieldCurveFitError, YieldTermStructure,
+    ZeroSpreadedTermStructure, ZeroYield,
 };
 
 use day_count_conventions::DayCounter;
@@ -20,9 +42,9 @@ pub trait TermStructure<D>
 where
     D: DayCounter,
 {
-    /// The calendar used for the term structure.
-    ///
-    /// TODO: This will be added when `ItoLib` gets support for international calendars.
+    // The calendar used for the term structure.
+    //
+    // TODO: This will be added when `ItoLib` gets support for international calendars.
     // fn get_calendar(&self) -> Calendar;
 
     /// The datetime at which the discount factor is equal to 1.0.
@@ -38,6 +60,14 @@ where
     /// The day counting convention used by the term structure.
     fn get_day_counter(&self) -> D;
 
+    /// Year fraction from the reference date to a datetime, in the term structure's day counter.
+    #[must_use]
+    fn time_from_reference(&self, dt: DateTime) -> f64 {
+        self.get_day_counter()
+            .day_count_fraction(&self.get_reference_date(), &dt)
+            .get_fraction()
+    }
+
     /// Ensure that the term structure is applicable to the date.
     ///
     /// This is a utility method that makes sure that the term structure can make predictions about
@@ -0,0 +1,583 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::math::interpolation::{
+    BicubicInterpolator, CubicSplineInterpolator, InterpolationResult, Interpolator,
+    LinearInterpolator,
+};
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, VolatilityTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureStrikeValidity};
+use crate::time::DateTime;
+use crate::types::{Strike, Volatility};
+
+use super::black_volatility_term_structure::BlackVolatilityTermStructureResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Black volatility surface built from a grid of `(expiry, strike) -> volatility` quotes.
+///
+/// Quotes are converted to total variance $w = \sigma^2 \tau$. For a given strike, total variance
+/// is interpolated linearly in time between the expiries (starting from $w = 0$ at the reference
+/// date), after interpolating each expiry across strikes with the chosen
+/// [`BlackVarianceSurfaceInterpolation`]. The bicubic scheme instead interpolates total variance
+/// jointly in time and strike.
+pub struct BlackVarianceSurface<D>
+where
+    D: DayCounter,
+{
+    expiries: Vec<DateTime>,
+
+    /// Times to expiry from the reference date.
+    times: Vec<f64>,
+
+    strikes: Vec<f64>,
+
+    interpolation: SurfaceInterpolation,
+
+    strike_extrapolation: VolatilityExtrapolation,
+
+    time_extrapolation: VolatilityExtrapolation,
+
+    reference_date: DateTime,
+
+    day_count_convention: D,
+}
+
+/// Interpolation scheme across strikes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum BlackVarianceSurfaceInterpolation {
+    /// Linear in total variance across strikes.
+    #[default]
+    Linear,
+
+    /// Natural cubic spline in total variance across strikes.
+    CubicSpline,
+
+    /// Bicubic in total variance across both time and strikes.
+    Bicubic,
+}
+
+/// Behaviour of a volatility surface outside its quoted range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum VolatilityExtrapolation {
+    /// No extrapolation: lookups outside the quoted range are out of range.
+    #[default]
+    None,
+
+    /// The volatility at the nearest quoted point is used.
+    Flat,
+}
+
+enum SurfaceInterpolation {
+    /// Total variance interpolators across strikes, one per expiry.
+    OneDimensional(Vec<Box<dyn Interpolator<Strike, OrderedFloat<f64>>>>),
+
+    /// Total variance interpolator across times (including the reference date) and strikes.
+    Bicubic(BicubicInterpolator),
+}
+
+#[derive(Debug)]
+pub struct BlackVarianceSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    points: BTreeMap<(DateTime, Strike), Volatility>,
+    interpolation: BlackVarianceSurfaceInterpolation,
+    strike_extrapolation: VolatilityExtrapolation,
+    time_extrapolation: VolatilityExtrapolation,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlackVarianceSurfaceBuilderError {
+    NoPointsProvided,
+    /// Every expiry must be quoted at every strike.
+    IncompleteGrid,
+    /// At least two strikes must be quoted to interpolate across strikes.
+    TooFewStrikes,
+    NonPositiveStrike,
+    NegativeVolatility,
+    ExpiryNotAfterReferenceDate,
+}
+
+impl<D> BlackVarianceSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            points: BTreeMap::new(),
+            interpolation: BlackVarianceSurfaceInterpolation::Linear,
+            strike_extrapolation: VolatilityExtrapolation::None,
+            time_extrapolation: VolatilityExtrapolation::None,
+            reference_date: None,
+            day_count_convention: None,
+        }
+    }
+
+    /// Add a quote to the surface.
+    pub fn add_point(
+        &mut self,
+        expiry: DateTime,
+        strike: impl Into<Strike>,
+        volatility: impl Into<Volatility>,
+    ) -> &mut Self {
+        let _ = self
+            .points
+            .insert((expiry, strike.into()), volatility.into());
+        self
+    }
+
+    /// Add quotes to the surface.
+    pub fn add_points(&mut self, points: &[(DateTime, Strike, Volatility)]) -> &mut Self {
+        for (expiry, strike, volatility) in points {
+            self.add_point(*expiry, *strike, *volatility);
+        }
+        self
+    }
+
+    pub fn interpolation(&mut self, interpolation: BlackVarianceSurfaceInterpolation) -> &mut Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn strike_extrapolation(&mut self, extrapolation: VolatilityExtrapolation) -> &mut Self {
+        self.strike_extrapolation = extrapolation;
+        self
+    }
+
+    pub fn time_extrapolation(&mut self, extrapolation: VolatilityExtrapolation) -> &mut Self {
+        self.time_extrapolation = extrapolation;
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    pub fn build(&self) -> Result<BlackVarianceSurface<D>, BlackVarianceSurfaceBuilderError> {
+        if self.points.is_empty() {
+            return Err(BlackVarianceSurfaceBuilderError::NoPointsProvided);
+        }
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+        let day_count_convention = self.day_count_convention.unwrap_or_default();
+
+        let expiries: Vec<DateTime> = self
+            .points
+            .keys()
+            .map(|(expiry, _)| *expiry)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let strikes: Vec<Strike> = self
+            .points
+            .keys()
+            .map(|(_, strike)| *strike)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        if strikes.len() < 2 {
+            return Err(BlackVarianceSurfaceBuilderError::TooFewStrikes);
+        }
+        if self.points.len() != expiries.len() * strikes.len() {
+            return Err(BlackVarianceSurfaceBuilderError::IncompleteGrid);
+        }
+        if *strikes[0] <= 0.0 {
+            return Err(BlackVarianceSurfaceBuilderError::NonPositiveStrike);
+        }
+        if expiries[0] <= reference_date {
+            return Err(BlackVarianceSurfaceBuilderError::ExpiryNotAfterReferenceDate);
+        }
+        if self.points.values().any(|volatility| **volatility < 0.0) {
+            return Err(BlackVarianceSurfaceBuilderError::NegativeVolatility);
+        }
+
+        let times: Vec<f64> = expiries
+            .iter()
+            .map(|expiry| {
+                day_count_convention
+                    .day_count_fraction(&reference_date, expiry)
+                    .get_fraction()
+            })
+            .collect();
+        let variances: Vec<Vec<f64>> = expiries
+            .iter()
+            .zip(&times)
+            .map(|(expiry, time)| {
+                strikes
+                    .iter()
+                    .map(|strike| {
+                        let volatility = *self.points[&(*expiry, *strike)];
+                        volatility * volatility * time
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let interpolation = match self.interpolation {
+            BlackVarianceSurfaceInterpolation::Linear
+            | BlackVarianceSurfaceInterpolation::CubicSpline => {
+                SurfaceInterpolation::OneDimensional(
+                    variances
+                        .iter()
+                        .map(|row| {
+                            let points: Vec<(Strike, OrderedFloat<f64>)> = strikes
+                                .iter()
+                                .zip(row)
+                                .map(|(strike, variance)| (*strike, OrderedFloat(*variance)))
+                                .collect();
+                            let interpolator: Box<dyn Interpolator<Strike, OrderedFloat<f64>>> =
+                                if self.interpolation == BlackVarianceSurfaceInterpolation::Linear {
+                                    Box::new(LinearInterpolator::new_from_points(points))
+                                } else {
+                                    Box::new(CubicSplineInterpolator::new_from_points(points))
+                                };
+                            interpolator
+                        })
+                        .collect(),
+                )
+            }
+            BlackVarianceSurfaceInterpolation::Bicubic => {
+                let mut grid_times = vec![0.0];
+                grid_times.extend(&times);
+                let mut grid_variances = vec![vec![0.0; strikes.len()]];
+                grid_variances.extend(variances);
+
+                SurfaceInterpolation::Bicubic(
+                    BicubicInterpolator::new(
+                        grid_times,
+                        strikes.iter().map(|strike| **strike).collect(),
+                        grid_variances,
+                    )
+                    .ok_or(BlackVarianceSurfaceBuilderError::IncompleteGrid)?,
+                )
+            }
+        };
+
+        Ok(BlackVarianceSurface {
+            expiries,
+            times,
+            strikes: strikes.iter().map(|strike| **strike).collect(),
+            interpolation,
+            strike_extrapolation: self.strike_extrapolation,
+            time_extrapolation: self.time_extrapolation,
+            reference_date,
+            day_count_convention,
+        })
+    }
+}
+
+impl<D> Default for BlackVarianceSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> BlackVarianceSurface<D>
+where
+    D: DayCounter,
+{
+    /// Total variance at a quoted expiry and a strike within the quoted range.
+    fn expiry_variance(&self, index: usize, strike: f64) -> f64 {
+        match &self.interpolation {
+            SurfaceInterpolation::OneDimensional(interpolators) => {
+                match interpolators[index].interpolate(OrderedFloat(strike)) {
+                    InterpolationResult::ExistingValue(w)
+                    | InterpolationResult::InterpolatedValue(w) => *w,
+                    _ => unreachable!("Strike is within the quoted range."),
+                }
+            }
+            SurfaceInterpolation::Bicubic(interpolator) => {
+                interpolation_value(interpolator.interpolate(self.times[index], strike))
+            }
+        }
+    }
+
+    /// Total variance at a time to maturity and a strike within the quoted range, for times up to
+    /// the last expiry.
+    fn total_variance(&self, t: f64, strike: f64) -> f64 {
+        if let SurfaceInterpolation::Bicubic(interpolator) = &self.interpolation {
+            return interpolation_value(interpolator.interpolate(t, strike));
+        }
+
+        let index = self.times.partition_point(|time| *time < t);
+        let (t1, w1) = if index == 0 {
+            (0.0, 0.0)
+        } else {
+            (
+                self.times[index - 1],
+                self.expiry_variance(index - 1, strike),
+            )
+        };
+        let (t2, w2) = (self.times[index], self.expiry_variance(index, strike));
+
+        (w2 - w1).mul_add((t - t1) / (t2 - t1), w1)
+    }
+}
+
+fn interpolation_value(result: InterpolationResult<OrderedFloat<f64>>) -> f64 {
+    match result {
+        InterpolationResult::ExistingValue(w) | InterpolationResult::InterpolatedValue(w) => *w,
+        _ => unreachable!("Point is within the quoted range."),
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<D> TermStructure<D> for BlackVarianceSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        match self.time_extrapolation {
+            VolatilityExtrapolation::None => self.expiries[self.expiries.len() - 1],
+            VolatilityExtrapolation::Flat => DateTime::new_from_ymd(9999, 12, 31),
+        }
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && dt <= self.get_max_datetime()
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<D> VolatilityTermStructure<D> for BlackVarianceSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        match self.strike_extrapolation {
+            VolatilityExtrapolation::None => (
+                OrderedFloat(self.strikes[0]),
+                OrderedFloat(self.strikes[self.strikes.len() - 1]),
+            ),
+            VolatilityExtrapolation::Flat => {
+                (OrderedFloat(f64::MIN_POSITIVE), OrderedFloat(f64::MAX))
+            }
+        }
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        let (min_strike, max_strike) = self.get_min_max_strike();
+        if strike >= min_strike && strike <= max_strike {
+            TermStructureStrikeValidity::Valid
+        } else {
+            TermStructureStrikeValidity::Invalid
+        }
+    }
+}
+
+impl<D> BlackVolatilityTermStructure<D> for BlackVarianceSurface<D>
+where
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        if !self.is_datetime_valid(maturity)
+            || self.validate_strike(strike) == TermStructureStrikeValidity::Invalid
+        {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        }
+
+        let clamped_strike = strike
+            .into_inner()
+            .clamp(self.strikes[0], self.strikes[self.strikes.len() - 1]);
+        let last_time = self.times[self.times.len() - 1];
+
+        // At the reference date use the limit of the first expiry.
+        let t = self.time_from_reference(maturity).max(self.times[0] * 1e-6);
+        let variance = if t > last_time {
+            self.total_variance(last_time, clamped_strike) * t / last_time
+        } else {
+            self.total_variance(t, clamped_strike)
+        };
+        let volatility = OrderedFloat((variance / t).sqrt());
+
+        if self.expiries.contains(&maturity) && self.strikes.contains(&strike.into_inner()) {
+            BlackVolatilityTermStructureResult::ExistingValue(volatility)
+        } else {
+            BlackVolatilityTermStructureResult::InterpolatedValue(volatility)
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+
+    fn quoted_surface(
+        interpolation: BlackVarianceSurfaceInterpolation,
+        extrapolation: VolatilityExtrapolation,
+    ) -> BlackVarianceSurface<Actual365Fixed> {
+        let expiries = [
+            DateTime::new_from_ymd(2024, 1, 1),
+            DateTime::new_from_ymd(2025, 1, 1),
+        ];
+        let strikes = [80.0, 90.0, 100.0, 110.0, 120.0];
+        let volatilities = [
+            [0.30, 0.26, 0.23, 0.21, 0.20],
+            [0.27, 0.24, 0.22, 0.21, 0.205],
+        ];
+
+        let mut builder = BlackVarianceSurfaceBuilder::new();
+        for (expiry, row) in expiries.iter().zip(volatilities) {
+            for (strike, volatility) in strikes.iter().zip(row) {
+                builder.add_point(*expiry, *strike, volatility);
+            }
+        }
+        builder
+            .interpolation(interpolation)
+            .strike_extrapolation(extrapolation)
+            .time_extrapolation(extrapolation)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build()
+            .unwrap()
+    }
+
+    fn volatility(
+        surface: &BlackVarianceSurface<Actual365Fixed>,
+        maturity: DateTime,
+        strike: f64,
+    ) -> f64 {
+        match surface.black_volatility(maturity, OrderedFloat(strike)) {
+            BlackVolatilityTermStructureResult::ExistingValue(v)
+            | BlackVolatilityTermStructureResult::InterpolatedValue(v) => *v,
+            _ => panic!("Expected a volatility."),
+        }
+    }
+
+    #[test]
+    fn test_black_variance_surface_interpolation() {
+        use BlackVarianceSurfaceInterpolation::{Bicubic, CubicSpline, Linear};
+
+        for interpolation in [Linear, CubicSpline, Bicubic] {
+            let surface = quoted_surface(interpolation, VolatilityExtrapolation::None);
+
+            // Quoted points are recovered.
+            assert!(matches!(
+                surface.black_volatility(DateTime::new_from_ymd(2025, 1, 1), OrderedFloat(90.0)),
+                BlackVolatilityTermStructureResult::ExistingValue(_)
+            ));
+            assert_approx_eq!(
+                volatility(&surface, DateTime::new_from_ymd(2025, 1, 1), 90.0),
+                0.24,
+                10e-12
+            );
+            assert_approx_eq!(
+                volatility(&surface, DateTime::new_from_ymd(2024, 1, 1), 110.0),
+                0.21,
+                10e-12
+            );
+        }
+
+        // Linear in total variance across strikes and across time.
+        let surface = quoted_surface(Linear, VolatilityExtrapolation::None);
+        let t: f64 = 548.0 / 365.0;
+        let w1 = 0.5 * (0.26 * 0.26 + 0.23 * 0.23) * 365.0 / 365.0;
+        let w2 = 0.5 * (0.24 * 0.24 + 0.22 * 0.22) * 731.0 / 365.0;
+        assert_approx_eq!(
+            volatility(&surface, DateTime::new_from_ymd(2024, 7, 2), 95.0),
+            (0.5 * (w1 + w2) / t).sqrt(),
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_black_variance_surface_extrapolation() {
+        let surface = quoted_surface(
+            BlackVarianceSurfaceInterpolation::CubicSpline,
+            VolatilityExtrapolation::None,
+        );
+        assert_eq!(
+            surface.validate_strike(OrderedFloat(70.0)),
+            TermStructureStrikeValidity::Invalid
+        );
+        assert!(matches!(
+            surface.black_volatility(DateTime::new_from_ymd(2024, 1, 1), OrderedFloat(70.0)),
+            BlackVolatilityTermStructureResult::OutOfRange
+        ));
+        assert!(matches!(
+            surface.black_volatility(DateTime::new_from_ymd(2026, 1, 1), OrderedFloat(100.0)),
+            BlackVolatilityTermStructureResult::OutOfRange
+        ));
+
+        let surface = quoted_surface(
+            BlackVarianceSurfaceInterpolation::CubicSpline,
+            VolatilityExtrapolation::Flat,
+        );
+        assert_eq!(
+            surface.validate_strike(OrderedFloat(70.0)),
+            TermStructureStrikeValidity::Valid
+        );
+        assert_approx_eq!(
+            volatility(&surface, DateTime::new_from_ymd(2024, 1, 1), 70.0),
+            0.30,
+            10e-12
+        );
+        assert_approx_eq!(
+            volatility(&surface, DateTime::new_from_ymd(2026, 1, 1), 150.0),
+            0.205,
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_black_variance_surface_incomplete_grid() {
+        let result = BlackVarianceSurfaceBuilder::<Actual365Fixed>::new()
+            .add_point(DateTime::new_from_ymd(2024, 1, 1), 100.0, 0.2)
+            .add_point(DateTime::new_from_ymd(2025, 1, 1), 110.0, 0.2)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build();
+        assert!(matches!(
+            result,
+            Err(BlackVarianceSurfaceBuilderError::IncompleteGrid)
+        ));
+
+        let result = BlackVarianceSurfaceBuilder::<Actual365Fixed>::new()
+            .add_point(DateTime::new_from_ymd(2024, 1, 1), 100.0, 0.2)
+            .add_point(DateTime::new_from_ymd(2025, 1, 1), 100.0, 0.2)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build();
+        assert!(matches!(
+            result,
+            Err(BlackVarianceSurfaceBuilderError::TooFewStrikes)
+        ));
+    }
+}
@@ -47,6 +47,16 @@ where
     I: Interpolator<DateTime, Volatility>,
     D: DayCounter,
 {
+    /// Create a new builder, interpolating volatility in time with the given interpolator.
+    #[must_use]
+    pub const fn new(interpolator: I) -> Self {
+        Self {
+            interpolator,
+            reference_date: None,
+            day_count_convention: None,
+        }
+    }
+
     /// Add a point to the volatility curve.
     pub fn add_point(&mut self, point: (DateTime, Volatility)) -> &mut Self {
         let _ = self.interpolator.add_point(point);
@@ -72,7 +82,7 @@ where
 
     /// Build the volatility curve.
     pub fn build(self) -> BlackVolatilityCurve<I, D> {
-        let reference_date = self.reference_date.map_or_else(DateTime::now, |d| d);
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
         let day_counter = self.day_count_convention.unwrap_or_default();
         BlackVolatilityCurve {
             interpolator: self.interpolator,
@@ -144,13 +154,98 @@ where
             InterpolationResult::NoPoints => NoPoints,
         }
     }
+}
 
-    fn black_forward_volatility(
-        &self,
-        _start_date: DateTime,
-        _end_date: DateTime,
-        _strike: Strike,
-    ) -> BlackVolatilityTermStructureResult {
-        todo!()
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+    use ordered_float::OrderedFloat;
+
+    use super::*;
+    use crate::math::interpolation::LinearInterpolator;
+    use crate::term_structures::volatility_structure::BlackForwardVolatilityResult;
+
+    fn curve(
+        volatilities: [f64; 3],
+    ) -> BlackVolatilityCurve<LinearInterpolator<DateTime, Volatility>, Actual365Fixed> {
+        let mut builder = BlackVolatilityCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .add_points(&[
+                (
+                    DateTime::new_from_ymd(2023, 1, 1),
+                    OrderedFloat(volatilities[0]),
+                ),
+                (
+                    DateTime::new_from_ymd(2024, 1, 1),
+                    OrderedFloat(volatilities[1]),
+                ),
+                (
+                    DateTime::new_from_ymd(2025, 1, 1),
+                    OrderedFloat(volatilities[2]),
+                ),
+            ])
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .day_count_convention(Actual365Fixed);
+        builder.build()
+    }
+
+    #[test]
+    fn test_black_forward_volatility() {
+        let curve = curve([0.2, 0.2, 0.3]);
+        let strike = OrderedFloat(100.0);
+
+        // From the reference date the forward volatility is the spot volatility.
+        match curve.black_forward_volatility(
+            DateTime::new_from_ymd(2023, 1, 1),
+            DateTime::new_from_ymd(2024, 1, 1),
+            strike,
+        ) {
+            BlackForwardVolatilityResult::Value(v) => {
+                assert_approx_eq!(*v, 0.2, 10e-12);
+            }
+            _ => panic!("Expected a value."),
+        }
+
+        let (t1, t2): (f64, f64) = (365.0 / 365.0, 731.0 / 365.0);
+        let expected = ((0.09 * t2 - 0.04 * t1) / (t2 - t1)).sqrt();
+        match curve.black_forward_volatility(
+            DateTime::new_from_ymd(2024, 1, 1),
+            DateTime::new_from_ymd(2025, 1, 1),
+            strike,
+        ) {
+            BlackForwardVolatilityResult::Value(v) => {
+                assert_approx_eq!(*v, expected, 10e-12);
+            }
+            _ => panic!("Expected a value."),
+        }
+
+        assert!(matches!(
+            curve.black_forward_volatility(
+                DateTime::new_from_ymd(2025, 1, 1),
+                DateTime::new_from_ymd(2024, 1, 1),
+                strike,
+            ),
+            BlackForwardVolatilityResult::OutOfRange
+        ));
+    }
+
+    #[test]
+    fn test_black_forward_volatility_calendar_arbitrage() {
+        // Total variance falls from 0.25 to 0.2 over the second year.
+        let curve = curve([0.5, 0.5, 0.31]);
+
+        assert!(matches!(
+            curve.black_forward_volatility(
+                DateTime::new_from_ymd(2024, 1, 1),
+                DateTime::new_from_ymd(2025, 1, 1),
+                OrderedFloat(100.0),
+            ),
+            BlackForwardVolatilityResult::NegativeForwardVariance
+        ));
     }
 }
@@ -17,12 +17,55 @@ where
         strike: Strike,
     ) -> BlackVolatilityTermStructureResult;
 
+    /// Forward volatility between two dates, from the total variance at each date.
+    /// $$
+    ///     \sigma_{1,2} = \sqrt{\frac{\sigma_2^2 \tau_2 - \sigma_1^2 \tau_1}{\tau_2 - \tau_1}}
+    /// $$
+    /// where $\tau_i$ are measured from the reference date with the term structure's day counter.
+    ///
+    /// Returns [`BlackForwardVolatilityResult::NegativeForwardVariance`] if total variance
+    /// decreases between the dates (calendar arbitrage), and
+    /// [`BlackForwardVolatilityResult::OutOfRange`] if the end date is not after the start date.
     fn black_forward_volatility(
         &self,
         start_date: DateTime,
         end_date: DateTime,
         strike: Strike,
-    ) -> BlackVolatilityTermStructureResult;
+    ) -> BlackForwardVolatilityResult {
+        use BlackVolatilityTermStructureResult::{
+            ExistingValue, InterpolatedValue, NoPoints, OutOfRange,
+        };
+
+        if end_date <= start_date {
+            return BlackForwardVolatilityResult::OutOfRange;
+        }
+
+        let reference_date = self.get_reference_date();
+        let t1 = self.time_from_reference(start_date);
+        let t2 = self.time_from_reference(end_date);
+
+        // No variance has accrued by the reference date.
+        let v1 = if start_date == reference_date {
+            0.0
+        } else {
+            match self.black_volatility(start_date, strike) {
+                ExistingValue(v) | InterpolatedValue(v) => *v * *v * t1,
+                OutOfRange => return BlackForwardVolatilityResult::OutOfRange,
+                NoPoints => return BlackForwardVolatilityResult::NoPoints,
+            }
+        };
+        let v2 = match self.black_volatility(end_date, strike) {
+            ExistingValue(v) | InterpolatedValue(v) => *v * *v * t2,
+            OutOfRange => return BlackForwardVolatilityResult::OutOfRange,
+            NoPoints => return BlackForwardVolatilityResult::NoPoints,
+        };
+
+        if v2 < v1 {
+            BlackForwardVolatilityResult::NegativeForwardVariance
+        } else {
+            BlackForwardVolatilityResult::Value(Volatility::from(((v2 - v1) / (t2 - t1)).sqrt()))
+        }
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -33,3 +76,13 @@ pub enum BlackVolatilityTermStructureResult {
     OutOfRange,
     NoPoints,
 }
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone)]
+pub enum BlackForwardVolatilityResult {
+    Value(Volatility),
+    OutOfRange,
+    NoPoints,
+    /// Total variance decreases between two dates, so the forward variance is negative.
+    NegativeForwardVariance,
+}
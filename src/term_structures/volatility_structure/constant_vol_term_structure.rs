@@ -3,7 +3,8 @@ use num::Bounded;
 use ordered_float::OrderedFloat;
 
 use crate::term_structures::volatility_structure::{
-    BlackVolatilityTermStructure, VolatilityTermStructure,
+    BlackForwardVolatilityResult, BlackVolatilityTermStructure, LocalVolTermStructure,
+    LocalVolTermStructureResult, VolatilityTermStructure,
 };
 use crate::term_structures::TermStructure;
 use crate::term_structures::TermStructureStrikeValidity;
@@ -155,7 +156,20 @@ where
         _start_date: DateTime,
         _end_date: DateTime,
         _strike: Strike,
-    ) -> BlackVolatilityTermStructureResult {
-        BlackVolatilityTermStructureResult::ExistingValue(self.volatility)
+    ) -> BlackForwardVolatilityResult {
+        BlackForwardVolatilityResult::Value(self.volatility)
+    }
+}
+
+impl<D> LocalVolTermStructure<D> for ConstantVolTermStructure<D>
+where
+    D: DayCounter,
+{
+    fn local_volatility(
+        &self,
+        _datetime: DateTime,
+        _underlying_level: f64,
+    ) -> LocalVolTermStructureResult {
+        LocalVolTermStructureResult::Value(self.volatility)
     }
 }
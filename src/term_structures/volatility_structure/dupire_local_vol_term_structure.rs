@@ -0,0 +1,346 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::money::{Currency, Money};
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, BlackVolatilityTermStructureResult, LocalVolTermStructure,
+    LocalVolTermStructureResult, VolatilityTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureStrikeValidity, YieldTermStructure};
+use crate::time::{DateTime, Duration};
+use crate::types::Strike;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Dupire local volatility implied by a Black volatility surface.
+///
+/// In terms of total implied variance $w(T, y) = \sigma_{BS}^2 T$ at log-moneyness
+/// $y = \ln(K / F_T)$, the local variance at $(T, K)$ is (Gatheral, 2006)
+/// $$
+///     \sigma_{loc}^2 = \frac{\partial_T w}{1 - \frac{y}{w} \partial_y w
+///         + \frac{1}{4} \left( -\frac{1}{4} - \frac{1}{w} + \frac{y^2}{w^2} \right)
+///             (\partial_y w)^2
+///         + \frac{1}{2} \partial_y^2 w}
+/// $$
+/// where the time derivative is taken at constant log-moneyness. Derivatives are computed by
+/// finite differences of the Black surface (one-sided at the ends of its time range). Working in
+/// forward log-moneyness keeps the rates and dividends out of the formula, and a non-positive
+/// numerator or denominator (calendar or butterfly arbitrage in the Black surface) is reported as
+/// [`LocalVolTermStructureResult::NegativeLocalVariance`] rather than producing a `NaN`.
+pub struct DupireLocalVolTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    black_volatility_surface: &'a dyn BlackVolatilityTermStructure<D>,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    time_step: f64,
+    log_moneyness_step: f64,
+}
+
+impl<'a, C, D> DupireLocalVolTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    pub fn new(
+        underlying_spot: Money<C>,
+        black_volatility_surface: &'a impl BlackVolatilityTermStructure<D>,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            black_volatility_surface,
+            yield_curve,
+            dividend_curve,
+            time_step: 1.0,
+            log_moneyness_step: 1e-3,
+        }
+    }
+
+    /// Set the step, in days, of the finite differences in time. Defaults to one day.
+    #[must_use]
+    pub const fn with_time_step(mut self, days: f64) -> Self {
+        self.time_step = days;
+        self
+    }
+
+    /// Set the step of the finite differences in log-moneyness. Defaults to $10^{-3}$.
+    #[must_use]
+    pub const fn with_log_moneyness_step(mut self, step: f64) -> Self {
+        self.log_moneyness_step = step;
+        self
+    }
+
+    /// Forward of the underlying at a datetime.
+    fn forward(&self, datetime: DateTime) -> Option<f64> {
+        let d = *self.yield_curve.discount_factor(datetime).ok()?;
+        let q = *self.dividend_curve.discount_factor(datetime).ok()?;
+        Some(*self.underlying_spot.amount() * q / d)
+    }
+
+    /// Total implied variance at a datetime and log-moneyness.
+    fn total_variance(&self, datetime: DateTime, log_moneyness: f64) -> Option<f64> {
+        let strike = self.forward(datetime)? * log_moneyness.exp();
+        match self
+            .black_volatility_surface
+            .black_volatility(datetime, OrderedFloat(strike))
+        {
+            BlackVolatilityTermStructureResult::ExistingValue(v)
+            | BlackVolatilityTermStructureResult::InterpolatedValue(v) => {
+                Some(*v * *v * self.time_from_reference(datetime))
+            }
+            _ => None,
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<'a, C, D> TermStructure<D> for DupireLocalVolTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.black_volatility_surface.get_reference_date()
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.black_volatility_surface.get_max_datetime()
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        self.black_volatility_surface.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.black_volatility_surface.get_day_counter()
+    }
+}
+
+impl<'a, C, D> VolatilityTermStructure<D> for DupireLocalVolTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        self.black_volatility_surface.get_min_max_strike()
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        self.black_volatility_surface.validate_strike(strike)
+    }
+}
+
+impl<'a, C, D> LocalVolTermStructure<D> for DupireLocalVolTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn local_volatility(
+        &self,
+        datetime: DateTime,
+        underlying_level: f64,
+    ) -> LocalVolTermStructureResult {
+        use LocalVolTermStructureResult::{NegativeLocalVariance, OutOfRange, Value};
+
+        if !self.is_datetime_valid(datetime)
+            || self.validate_strike(OrderedFloat(underlying_level))
+                == TermStructureStrikeValidity::Invalid
+        {
+            return OutOfRange;
+        }
+
+        // Time derivative at constant log-moneyness, one-sided at the ends of the surface.
+        let step = Duration::new_from_days(self.time_step);
+        let earlier = datetime + Duration::new_from_days(-self.time_step);
+        let before = if earlier > self.get_reference_date() {
+            earlier
+        } else {
+            datetime
+        };
+        let after = if datetime + step <= self.get_max_datetime() {
+            datetime + step
+        } else {
+            datetime
+        };
+        if before == after {
+            return OutOfRange;
+        }
+
+        let Some(forward) = self.forward(datetime) else {
+            return OutOfRange;
+        };
+        let y = (underlying_level / forward).ln();
+        let h = self.log_moneyness_step;
+
+        let (Some(w_before), Some(w_after), Some(w), Some(w_down), Some(w_up)) = (
+            self.total_variance(before, y),
+            self.total_variance(after, y),
+            self.total_variance(datetime, y),
+            self.total_variance(datetime, y - h),
+            self.total_variance(datetime, y + h),
+        ) else {
+            return OutOfRange;
+        };
+
+        let dw_dt = (w_after - w_before)
+            / (self.time_from_reference(after) - self.time_from_reference(before));
+        let dw_dy = (w_up - w_down) / (2.0 * h);
+        let d2w_dy2 = (w_up - 2.0 * w + w_down) / (h * h);
+
+        let denominator = if w > 0.0 {
+            0.25f64.mul_add(
+                (y * y / (w * w) - 1.0 / w - 0.25) * dw_dy * dw_dy,
+                0.5f64.mul_add(d2w_dy2, 1.0 - y / w * dw_dy),
+            )
+        } else {
+            1.0
+        };
+
+        if dw_dt < 0.0 || denominator <= 0.0 {
+            return NegativeLocalVariance;
+        }
+
+        Value(OrderedFloat((dw_dt / denominator).sqrt()))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        math::interpolation::LinearInterpolator,
+        money::currency::USD,
+        term_structures::{
+            flat_curve, BlackVolatilityCurveBuilder, ConstantVolTermStructureBuilder, RawSvi,
+            SviSurfaceBuilder,
+        },
+        Compounding,
+    };
+
+    fn value(result: LocalVolTermStructureResult) -> f64 {
+        match result {
+            LocalVolTermStructureResult::Value(v) => *v,
+            _ => panic!("Expected a local volatility."),
+        }
+    }
+
+    #[test]
+    fn test_dupire_flat_volatility() {
+        let black = ConstantVolTermStructureBuilder::<Actual365Fixed>::new()
+            .volatility(0.25)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build()
+            .unwrap();
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.02,
+            Compounding::Continuous,
+        );
+
+        let local = DupireLocalVolTermStructure::new(
+            Money::new(100.0),
+            &black,
+            &yield_curve,
+            &dividend_curve,
+        );
+        for level in [60.0, 100.0, 150.0] {
+            assert_approx_eq!(
+                value(local.local_volatility(DateTime::new_from_ymd(2024, 6, 1), level)),
+                0.25,
+                10e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_dupire_term_structure_of_volatility() {
+        // Without skew, local variance is the instantaneous forward variance d(sigma^2 T)/dT.
+        let mut builder = BlackVolatilityCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .add_points(&[
+                (DateTime::new_from_ymd(2023, 1, 1), OrderedFloat(0.2)),
+                (DateTime::new_from_ymd(2025, 1, 1), OrderedFloat(0.3)),
+            ])
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .day_count_convention(Actual365Fixed);
+        let black = builder.build();
+        let rates = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+
+        let local = DupireLocalVolTermStructure::new(Money::new(100.0), &black, &rates, &rates);
+
+        let t = 365.0 / 365.0;
+        let slope: f64 = 0.1 / (731.0 / 365.0);
+        let sigma = slope.mul_add(t, 0.2);
+        let expected = (2.0 * sigma * slope).mul_add(t, sigma * sigma).sqrt();
+        assert_approx_eq!(
+            value(local.local_volatility(DateTime::new_from_ymd(2024, 1, 1), 120.0)),
+            expected,
+            10e-6
+        );
+    }
+
+    #[test]
+    fn test_dupire_svi_smile() {
+        let slice = RawSvi::new(0.02, 0.15, -0.5, 0.05, 0.15);
+        let black = SviSurfaceBuilder::<Actual365Fixed>::new()
+            .spot(100.0)
+            .slice(DateTime::new_from_ymd(2025, 1, 1), 100.0, slice)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build()
+            .unwrap();
+        let rates = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+
+        let local = DupireLocalVolTermStructure::new(Money::new(100.0), &black, &rates, &rates);
+
+        // Before the first slice total variance is proportional to time, so the local variance is
+        // the slice's total variance per year over Gatheral's density function.
+        let scale = 365.0 / 731.0;
+        let scaled = RawSvi::new(
+            slice.get_a() * scale,
+            slice.get_b() * scale,
+            slice.get_rho(),
+            slice.get_m(),
+            slice.get_sigma(),
+        );
+        for level in [80.0, 100.0, 125.0] {
+            let k: f64 = (level / 100.0f64).ln();
+            let expected =
+                (slice.total_variance(k) / (731.0 / 365.0) / scaled.density_function(k)).sqrt();
+            assert_approx_eq!(
+                value(local.local_volatility(DateTime::new_from_ymd(2024, 1, 1), level)),
+                expected,
+                10e-6
+            );
+        }
+    }
+}
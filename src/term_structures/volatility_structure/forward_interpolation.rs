@@ -0,0 +1,58 @@
+/// Forward at time `t` from spot and `(time, forward)` points sorted by time, interpolating
+/// $\ln F$ linearly in time and extrapolating the last carry rate beyond the last point.
+///
+/// The times must be positive and strictly increasing; the surface builders reject pillars
+/// that would break this.
+pub(super) fn interpolate_forward(spot: f64, forwards: &[(f64, f64)], t: f64) -> f64 {
+    let mut previous = (0.0, spot);
+    for &(time, forward) in forwards {
+        if t <= time {
+            let weight = (t - previous.0) / (time - previous.0);
+            return previous.1 * (forward / previous.1).powf(weight);
+        }
+        previous = (time, forward);
+    }
+    let (time, forward) = previous;
+    if time > 0.0 {
+        forward * (forward / spot).powf((t - time) / time)
+    } else {
+        spot
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_interpolate_forward() {
+        let forwards = [(0.5, 102.0), (1.0, 103.0)];
+
+        assert_approx_eq!(interpolate_forward(100.0, &forwards, 0.0), 100.0, 10e-12);
+        assert_approx_eq!(interpolate_forward(100.0, &forwards, 0.5), 102.0, 10e-12);
+        assert_approx_eq!(
+            interpolate_forward(100.0, &forwards, 0.25),
+            (100.0_f64 * 102.0).sqrt(),
+            10e-12
+        );
+        assert_approx_eq!(
+            interpolate_forward(100.0, &forwards, 0.75),
+            (102.0_f64 * 103.0).sqrt(),
+            10e-12
+        );
+
+        // Beyond the last point the average carry to it is extrapolated.
+        assert_approx_eq!(
+            interpolate_forward(100.0, &forwards, 2.0),
+            103.0 * 1.03,
+            10e-12
+        );
+        assert_approx_eq!(interpolate_forward(100.0, &[], 2.0), 100.0, 10e-12);
+    }
+}
@@ -0,0 +1,1035 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+use crate::instruments::OptionType;
+use crate::math::solvers::Brent;
+use crate::money::{Currency, ExchangeRate};
+use crate::pricers::black_formula;
+use crate::term_structures::volatility_structure::forward_interpolation::interpolate_forward;
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, BlackVolatilityTermStructureResult, VolatilityTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureStrikeValidity, YieldTermStructure};
+use crate::time::DateTime;
+use crate::types::{Strike, Volatility};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Delta convention of FX option quotes.
+///
+/// With $\omega = 1$ for calls and $-1$ for puts, and $D_f$ the foreign discount factor, the
+/// deltas are
+/// - spot: $\omega D_f N(\omega d_+)$,
+/// - forward: $\omega N(\omega d_+)$,
+/// - premium-adjusted spot: $\omega D_f \frac{K}{F} N(\omega d_-)$,
+/// - premium-adjusted forward: $\omega \frac{K}{F} N(\omega d_-)$.
+///
+/// Premium-adjusted deltas are used when the premium is paid in the base (foreign) currency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FxDeltaType {
+    #[default]
+    Spot,
+    Forward,
+    PremiumAdjustedSpot,
+    PremiumAdjustedForward,
+}
+
+/// Convention of the at-the-money strike of FX option quotes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FxAtmType {
+    /// Delta-neutral straddle: the strike at which the call and put deltas sum to zero.
+    #[default]
+    DeltaNeutral,
+
+    /// At-the-money forward: the strike is the forward.
+    Forward,
+}
+
+/// Convention of the butterfly quotes of an FX smile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FxButterflyType {
+    /// Smile strangle: the butterfly is the average wing volatility of the smile over the
+    /// at-the-money volatility.
+    #[default]
+    Smile,
+
+    /// Broker (market) strangle: a strangle struck at the quoted delta with the single volatility
+    /// $\sigma_{ATM} + BF$ is worth the same as under the smile. The smile butterflies are solved
+    /// so that the interpolated smile reprices the strangles.
+    Broker,
+}
+
+/// Interpolation of an FX smile between its pillars.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FxSmileInterpolation {
+    /// Vanna-Volga interpolation through the 25-delta put, at-the-money and 25-delta call pillars
+    /// (second-order approximation of Castagna & Mercurio, 2007). It has no 10-delta pillars.
+    #[default]
+    VannaVolga,
+
+    /// Polynomial in the Black forward call delta through all the pillars (Malz, 1997). With only
+    /// 25-delta quotes this is Malz's quadratic smile.
+    Malz,
+}
+
+/// Broker quotes of an FX smile at one tenor, as volatilities.
+///
+/// The pillar volatilities are $\sigma_{ATM} + BF \pm RR / 2$ for the call and the put, where
+/// $BF$ is the quoted butterfly or, for broker strangles, the smile butterfly implied from it (see
+/// [`FxButterflyType`]). The 10-delta quotes are only used by [`FxSmileInterpolation::Malz`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FxSmileQuote {
+    pub atm: f64,
+    pub risk_reversal_25: f64,
+    pub butterfly_25: f64,
+    pub risk_reversal_10: Option<f64>,
+    pub butterfly_10: Option<f64>,
+}
+
+impl FxSmileQuote {
+    /// Create a new `FxSmileQuote` from the at-the-money volatility and the 25-delta risk reversal
+    /// and butterfly.
+    #[must_use]
+    pub const fn new(atm: f64, risk_reversal_25: f64, butterfly_25: f64) -> Self {
+        Self {
+            atm,
+            risk_reversal_25,
+            butterfly_25,
+            risk_reversal_10: None,
+            butterfly_10: None,
+        }
+    }
+
+    /// Add the 10-delta risk reversal and butterfly.
+    #[must_use]
+    pub const fn with_10_delta(mut self, risk_reversal_10: f64, butterfly_10: f64) -> Self {
+        self.risk_reversal_10 = Some(risk_reversal_10);
+        self.butterfly_10 = Some(butterfly_10);
+        self
+    }
+}
+
+/// Smile at one tenor.
+#[derive(Debug, Clone)]
+struct FxSmileSlice {
+    expiry: DateTime,
+    time: f64,
+    forward: f64,
+
+    /// Pillar strikes, increasing, and their volatilities.
+    strikes: Vec<f64>,
+    volatilities: Vec<f64>,
+}
+
+/// Implied volatility surface of an exchange rate, built from at-the-money, risk reversal and
+/// butterfly quotes.
+///
+/// At each tenor the quotes are converted into pillar strikes under the chosen delta and
+/// at-the-money conventions, and the smile is interpolated in strike with Vanna-Volga or Malz
+/// interpolation. Between tenors total variance is interpolated linearly in time at constant
+/// log-moneyness, and scaled proportionally to time outside the quoted tenors.
+///
+/// Strikes are in units of the quote currency per unit of the base currency, so the surface
+/// prices options on an [`ExchangeRate<B, Q>`] paying in the quote currency.
+pub struct FxVolatilitySurface<D>
+where
+    D: DayCounter,
+{
+    spot: f64,
+    slices: Vec<FxSmileSlice>,
+    interpolation: FxSmileInterpolation,
+    reference_date: DateTime,
+    day_count_convention: D,
+}
+
+pub struct FxVolatilitySurfaceBuilder<'a, B, Q, D>
+where
+    B: Currency,
+    Q: Currency,
+    D: DayCounter,
+{
+    spot: f64,
+    domestic_curve: &'a dyn YieldTermStructure<Q, D>,
+    foreign_curve: &'a dyn YieldTermStructure<B, D>,
+    quotes: Vec<(DateTime, FxSmileQuote)>,
+    delta_type: FxDeltaType,
+    atm_type: FxAtmType,
+    butterfly_type: FxButterflyType,
+    interpolation: FxSmileInterpolation,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FxVolatilitySurfaceBuilderError {
+    NoQuotesProvided,
+    NonPositiveSpot,
+
+    /// An expiry is not after the reference date, or its time to expiry is not positive.
+    ExpiryNotAfterReferenceDate,
+
+    /// Two expiries have the same time to expiry.
+    DuplicateExpiry,
+
+    NonPositiveVolatility,
+
+    /// A discount factor to an expiry could not be computed from the curves.
+    InvalidCurves,
+
+    /// No strike has the quoted delta, which can happen for premium-adjusted call deltas.
+    DeltaStrikeNotFound,
+
+    /// The pillar strikes are not increasing from the 10-delta put to the 10-delta call.
+    UnorderedStrikes,
+
+    /// Vanna-Volga interpolation only uses the 25-delta pillars, so 10-delta quotes are rejected.
+    UnsupportedPillars,
+
+    /// No smile butterfly reprices a quoted broker strangle.
+    StrangleNotMatched,
+}
+
+impl<'a, B, Q, D> FxVolatilitySurfaceBuilder<'a, B, Q, D>
+where
+    B: Currency,
+    Q: Currency,
+    D: DayCounter,
+{
+    /// Create a new `FxVolatilitySurfaceBuilder` from the spot rate and the domestic (quote
+    /// currency) and foreign (base currency) curves.
+    pub fn new(
+        spot: &ExchangeRate<B, Q>,
+        domestic_curve: &'a impl YieldTermStructure<Q, D>,
+        foreign_curve: &'a impl YieldTermStructure<B, D>,
+    ) -> Self {
+        Self {
+            spot: *spot.rate,
+            domestic_curve,
+            foreign_curve,
+            quotes: Vec::new(),
+            delta_type: FxDeltaType::default(),
+            atm_type: FxAtmType::default(),
+            butterfly_type: FxButterflyType::default(),
+            interpolation: FxSmileInterpolation::default(),
+        }
+    }
+
+    /// Add the smile quotes of a tenor.
+    pub fn quote(&mut self, expiry: DateTime, quote: FxSmileQuote) -> &mut Self {
+        self.quotes.push((expiry, quote));
+        self
+    }
+
+    pub fn delta_type(&mut self, delta_type: FxDeltaType) -> &mut Self {
+        self.delta_type = delta_type;
+        self
+    }
+
+    pub fn atm_type(&mut self, atm_type: FxAtmType) -> &mut Self {
+        self.atm_type = atm_type;
+        self
+    }
+
+    pub fn butterfly_type(&mut self, butterfly_type: FxButterflyType) -> &mut Self {
+        self.butterfly_type = butterfly_type;
+        self
+    }
+
+    pub fn interpolation(&mut self, interpolation: FxSmileInterpolation) -> &mut Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn build(&self) -> Result<FxVolatilitySurface<D>, FxVolatilitySurfaceBuilderError> {
+        use FxVolatilitySurfaceBuilderError::{
+            DeltaStrikeNotFound, DuplicateExpiry, ExpiryNotAfterReferenceDate, InvalidCurves,
+            NoQuotesProvided, NonPositiveSpot, NonPositiveVolatility, StrangleNotMatched,
+            UnorderedStrikes, UnsupportedPillars,
+        };
+
+        if self.quotes.is_empty() {
+            return Err(NoQuotesProvided);
+        }
+        if self.spot <= 0.0 {
+            return Err(NonPositiveSpot);
+        }
+        let reference_date = self.domestic_curve.get_reference_date();
+        let day_count_convention = self.domestic_curve.get_day_counter();
+
+        let mut quotes = self.quotes.clone();
+        quotes.sort_by_key(|quote| quote.0);
+
+        let mut slices: Vec<FxSmileSlice> = Vec::with_capacity(quotes.len());
+        for (expiry, quote) in quotes {
+            let time = day_count_convention
+                .day_count_fraction(&reference_date, &expiry)
+                .get_fraction();
+            if expiry <= reference_date || time <= 0.0 {
+                return Err(ExpiryNotAfterReferenceDate);
+            }
+            if slices.last().is_some_and(|last| last.time >= time) {
+                return Err(DuplicateExpiry);
+            }
+
+            let domestic = *self
+                .domestic_curve
+                .discount_factor(expiry)
+                .map_err(|_| InvalidCurves)?;
+            let foreign = *self
+                .foreign_curve
+                .discount_factor(expiry)
+                .map_err(|_| InvalidCurves)?;
+            let forward = self.spot * foreign / domestic;
+
+            if quote.atm <= 0.0 {
+                return Err(NonPositiveVolatility);
+            }
+            let std_dev_factor = time.sqrt();
+            let variance = quote.atm * quote.atm * time;
+            let atm_strike = match (self.atm_type, self.delta_type) {
+                (FxAtmType::Forward, _) => forward,
+                (FxAtmType::DeltaNeutral, FxDeltaType::Spot | FxDeltaType::Forward) => {
+                    forward * (0.5 * variance).exp()
+                }
+                (
+                    FxAtmType::DeltaNeutral,
+                    FxDeltaType::PremiumAdjustedSpot | FxDeltaType::PremiumAdjustedForward,
+                ) => forward * (-0.5 * variance).exp(),
+            };
+
+            // Wings as (delta, risk reversal, butterfly), from the widest inwards.
+            let mut wings = vec![(0.25, quote.risk_reversal_25, quote.butterfly_25)];
+            if let (Some(risk_reversal), Some(butterfly)) =
+                (quote.risk_reversal_10, quote.butterfly_10)
+            {
+                if self.interpolation == FxSmileInterpolation::VannaVolga {
+                    return Err(UnsupportedPillars);
+                }
+                wings.insert(0, (0.10, risk_reversal, butterfly));
+            }
+
+            // The smile through the pillars for the given smile butterflies of the wings.
+            let smile = |butterflies: &[f64]| {
+                let mut pillars: Vec<(f64, f64)> = Vec::with_capacity(2 * wings.len() + 1);
+                for ((delta, risk_reversal, _), butterfly) in wings.iter().zip(butterflies) {
+                    for (option_type, volatility) in [
+                        (OptionType::PUT, quote.atm + butterfly - 0.5 * risk_reversal),
+                        (
+                            OptionType::CALL,
+                            quote.atm + butterfly + 0.5 * risk_reversal,
+                        ),
+                    ] {
+                        if volatility <= 0.0 {
+                            return Err(NonPositiveVolatility);
+                        }
+                        let strike = delta_strike(
+                            self.delta_type,
+                            option_type,
+                            *delta,
+                            forward,
+                            volatility * std_dev_factor,
+                            foreign,
+                        )
+                        .ok_or(DeltaStrikeNotFound)?;
+                        pillars.push((strike, volatility));
+                    }
+                }
+                pillars.push((atm_strike, quote.atm));
+
+                pillars.sort_by(|a, b| a.0.total_cmp(&b.0));
+                if pillars.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                    return Err(UnorderedStrikes);
+                }
+
+                Ok(FxSmileSlice {
+                    expiry,
+                    time,
+                    forward,
+                    strikes: pillars.iter().map(|pillar| pillar.0).collect(),
+                    volatilities: pillars.iter().map(|pillar| pillar.1).collect(),
+                })
+            };
+
+            let quoted_butterflies: Vec<f64> = wings.iter().map(|wing| wing.2).collect();
+            let slice = match self.butterfly_type {
+                FxButterflyType::Smile => smile(&quoted_butterflies)?,
+                FxButterflyType::Broker => {
+                    let strangle = |put: f64, call: f64, put_vol: f64, call_vol: f64| {
+                        black_formula(OptionType::PUT, put, forward, put_vol * std_dev_factor, 1.0)
+                            + black_formula(
+                                OptionType::CALL,
+                                call,
+                                forward,
+                                call_vol * std_dev_factor,
+                                1.0,
+                            )
+                    };
+
+                    // Broker strangles as (put strike, call strike, value), struck at the quoted
+                    // delta with the single volatility atm + butterfly.
+                    let mut broker_strangles = Vec::with_capacity(wings.len());
+                    for (delta, _, butterfly) in &wings {
+                        let volatility = quote.atm + butterfly;
+                        if volatility <= 0.0 {
+                            return Err(NonPositiveVolatility);
+                        }
+                        let [put, call] = [OptionType::PUT, OptionType::CALL].map(|option_type| {
+                            delta_strike(
+                                self.delta_type,
+                                option_type,
+                                *delta,
+                                forward,
+                                volatility * std_dev_factor,
+                                foreign,
+                            )
+                        });
+                        let (put, call) = put.zip(call).ok_or(DeltaStrikeNotFound)?;
+                        broker_strangles.push((
+                            put,
+                            call,
+                            strangle(put, call, volatility, volatility),
+                        ));
+                    }
+
+                    // Solve for each smile butterfly in turn until the smile reprices every
+                    // broker strangle; a single wing needs one pass.
+                    let mut butterflies = quoted_butterflies.clone();
+                    let mut converged = false;
+                    for _ in 0..50 {
+                        let previous = butterflies.clone();
+                        for (i, (put, call, value)) in broker_strangles.iter().enumerate() {
+                            let objective = |butterfly: f64| {
+                                let mut trial = butterflies.clone();
+                                trial[i] = butterfly;
+                                let Ok(slice) = smile(&trial) else {
+                                    return f64::NAN;
+                                };
+                                slice
+                                    .volatility(self.interpolation, *put)
+                                    .zip(slice.volatility(self.interpolation, *call))
+                                    .map_or(f64::NAN, |(put_vol, call_vol)| {
+                                        strangle(*put, *call, put_vol, call_vol) - value
+                                    })
+                            };
+
+                            // Keep the pillar volatilities above half the at-the-money
+                            // volatility, and bracket the root nearest the quoted butterfly on a
+                            // grid, since the smile cannot be built for every butterfly.
+                            let (_, risk_reversal, butterfly) = wings[i];
+                            let lower = (butterfly - 0.5 * quote.atm)
+                                .max(0.5 * (risk_reversal.abs() - quote.atm));
+                            let upper = 0.5f64.mul_add(quote.atm, butterfly);
+                            let grid: Vec<(f64, f64)> = (0..=40)
+                                .map(|k| {
+                                    let x = (upper - lower).mul_add(f64::from(k) / 40.0, lower);
+                                    (x, objective(x))
+                                })
+                                .collect();
+                            let (a, b) = grid
+                                .windows(2)
+                                .filter(|pair| pair[0].1 * pair[1].1 <= 0.0)
+                                .map(|pair| (pair[0].0, pair[1].0))
+                                .min_by(|a, b| {
+                                    let distance =
+                                        |x: &(f64, f64)| (x.0 + x.1 - 2.0 * butterfly).abs();
+                                    distance(a).total_cmp(&distance(b))
+                                })
+                                .ok_or(StrangleNotMatched)?;
+                            butterflies[i] = Brent::default()
+                                .solve(objective, a, b)
+                                .map_err(|_| StrangleNotMatched)?;
+                        }
+                        if wings.len() == 1
+                            || butterflies
+                                .iter()
+                                .zip(&previous)
+                                .all(|(butterfly, previous)| (butterfly - previous).abs() < 1e-12)
+                        {
+                            converged = true;
+                            break;
+                        }
+                    }
+                    if !converged {
+                        return Err(StrangleNotMatched);
+                    }
+                    smile(&butterflies)?
+                }
+            };
+
+            slices.push(slice);
+        }
+
+        Ok(FxVolatilitySurface {
+            spot: self.spot,
+            slices,
+            interpolation: self.interpolation,
+            reference_date,
+            day_count_convention,
+        })
+    }
+}
+
+/// Strike at which an option has the given absolute delta, for a total standard deviation
+/// `std_dev` and foreign discount factor `foreign`.
+fn delta_strike(
+    delta_type: FxDeltaType,
+    option_type: OptionType,
+    delta: f64,
+    forward: f64,
+    std_dev: f64,
+    foreign: f64,
+) -> Option<f64> {
+    let norm = Normal::standard();
+    let omega = match option_type {
+        OptionType::CALL => 1.0,
+        OptionType::PUT => -1.0,
+    };
+    let target = match delta_type {
+        FxDeltaType::Spot | FxDeltaType::PremiumAdjustedSpot => delta / foreign,
+        FxDeltaType::Forward | FxDeltaType::PremiumAdjustedForward => delta,
+    };
+    if target <= 0.0 || target >= 1.0 {
+        return None;
+    }
+
+    match delta_type {
+        FxDeltaType::Spot | FxDeltaType::Forward => {
+            let d_plus = omega * norm.inverse_cdf(target);
+            Some(forward * std_dev.mul_add(-d_plus, 0.5 * std_dev * std_dev).exp())
+        }
+        FxDeltaType::PremiumAdjustedSpot | FxDeltaType::PremiumAdjustedForward => {
+            // In log-moneyness y = ln(K / F), the premium-adjusted delta is e^y N(omega d_-).
+            let d_minus = |y: f64| -y / std_dev - 0.5 * std_dev;
+            let objective = |y: f64| y.exp() * norm.cdf(omega * d_minus(y)) - target;
+
+            let upper = 10.0f64.mul_add(std_dev, 1.0);
+            let lower = match option_type {
+                OptionType::PUT => -upper,
+                // The call delta peaks where N(d_-) = n(d_-) / std_dev, and only the strikes above
+                // the peak are used.
+                OptionType::CALL => {
+                    let peak = Brent::default()
+                        .solve(|x| std_dev * norm.cdf(x) - norm.pdf(x), -10.0, 10.0)
+                        .ok()?;
+                    std_dev.mul_add(-peak, -0.5 * std_dev * std_dev)
+                }
+            };
+            let y = Brent::default().solve(objective, lower, upper).ok()?;
+            Some(forward * y.exp())
+        }
+    }
+}
+
+impl<D> FxVolatilitySurface<D>
+where
+    D: DayCounter,
+{
+    /// The pillars of the surface, as `(expiry, [(strike, volatility)])` sorted by expiry and
+    /// strike.
+    #[must_use]
+    pub fn get_pillars(&self) -> Vec<(DateTime, Vec<(Strike, Volatility)>)> {
+        self.slices
+            .iter()
+            .map(|slice| {
+                let pillars = slice
+                    .strikes
+                    .iter()
+                    .zip(&slice.volatilities)
+                    .map(|(strike, volatility)| (OrderedFloat(*strike), OrderedFloat(*volatility)))
+                    .collect();
+                (slice.expiry, pillars)
+            })
+            .collect()
+    }
+
+    /// Forward at a time to maturity.
+    fn forward(&self, t: f64) -> f64 {
+        let forwards: Vec<(f64, f64)> = self
+            .slices
+            .iter()
+            .map(|slice| (slice.time, slice.forward))
+            .collect();
+        interpolate_forward(self.spot, &forwards, t)
+    }
+
+    /// Total variance at a time to maturity and log-moneyness.
+    fn total_variance(&self, t: f64, k: f64) -> Option<f64> {
+        let index = self.slices.partition_point(|slice| slice.time < t);
+
+        let slice_variance = |slice: &FxSmileSlice| {
+            slice
+                .volatility(self.interpolation, slice.forward * k.exp())
+                .map(|volatility| volatility * volatility * slice.time)
+        };
+
+        if index == 0 {
+            let slice = &self.slices[0];
+            return slice_variance(slice).map(|w| w * t / slice.time);
+        }
+        if index == self.slices.len() {
+            let slice = &self.slices[index - 1];
+            return slice_variance(slice).map(|w| w * t / slice.time);
+        }
+
+        let (slice1, slice2) = (&self.slices[index - 1], &self.slices[index]);
+        let w1 = slice_variance(slice1)?;
+        let w2 = slice_variance(slice2)?;
+        Some((w2 - w1).mul_add((t - slice1.time) / (slice2.time - slice1.time), w1))
+    }
+}
+
+impl FxSmileSlice {
+    /// Volatility of the smile at a strike.
+    fn volatility(&self, interpolation: FxSmileInterpolation, strike: f64) -> Option<f64> {
+        let std_dev_factor = self.time.sqrt();
+        let d_plus = |strike: f64, volatility: f64| {
+            let std_dev = volatility * std_dev_factor;
+            (self.forward / strike).ln() / std_dev + 0.5 * std_dev
+        };
+
+        match interpolation {
+            FxSmileInterpolation::VannaVolga => {
+                let middle = self.strikes.len() / 2;
+                let (k1, k2, k3) = (
+                    self.strikes[middle - 1],
+                    self.strikes[middle],
+                    self.strikes[middle + 1],
+                );
+                let (s1, s2, s3) = (
+                    self.volatilities[middle - 1],
+                    self.volatilities[middle],
+                    self.volatilities[middle + 1],
+                );
+
+                let y1 =
+                    (k2 / strike).ln() * (k3 / strike).ln() / ((k2 / k1).ln() * (k3 / k1).ln());
+                let y2 =
+                    (strike / k1).ln() * (k3 / strike).ln() / ((k2 / k1).ln() * (k3 / k2).ln());
+                let y3 =
+                    (strike / k1).ln() * (strike / k2).ln() / ((k3 / k1).ln() * (k3 / k2).ln());
+
+                // Products d_+ d_- at the ATM volatility.
+                let d_product = |x: f64| {
+                    let d = d_plus(x, s2);
+                    d * s2.mul_add(-std_dev_factor, d)
+                };
+
+                let first_order = y3.mul_add(s3, y1.mul_add(s1, y2 * s2)) - s2;
+                let second_order = y1 * d_product(k1) * (s1 - s2) * (s1 - s2)
+                    + y3 * d_product(k3) * (s3 - s2) * (s3 - s2);
+
+                let d = d_product(strike);
+                let radicand = d.mul_add(2.0f64.mul_add(s2 * first_order, second_order), s2 * s2);
+                if d.abs() < 1e-12 || radicand < 0.0 {
+                    Some(s2 + first_order)
+                } else {
+                    Some(s2 + (radicand.sqrt() - s2) / d)
+                }
+            }
+            FxSmileInterpolation::Malz => {
+                let norm = Normal::standard();
+                let deltas: Vec<f64> = self
+                    .strikes
+                    .iter()
+                    .zip(&self.volatilities)
+                    .map(|(k, v)| norm.cdf(d_plus(*k, *v)))
+                    .collect();
+                let polynomial = |delta: f64| lagrange(&deltas, &self.volatilities, delta);
+
+                // The delta depends on the volatility itself: solve sigma = P(N(d_+(K, sigma))).
+                Brent::default()
+                    .solve(
+                        |volatility| volatility - polynomial(norm.cdf(d_plus(strike, volatility))),
+                        1e-8,
+                        5.0,
+                    )
+                    .ok()
+            }
+        }
+    }
+}
+
+/// Lagrange polynomial through `(xs, ys)` evaluated at `x`.
+fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    xs.iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(ys[i], |acc, (_, x_j)| acc * (x - x_j) / (x_i - x_j))
+        })
+        .sum()
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<D> TermStructure<D> for FxVolatilitySurface<D>
+where
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<D> VolatilityTermStructure<D> for FxVolatilitySurface<D>
+where
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        (OrderedFloat(f64::MIN_POSITIVE), OrderedFloat(f64::MAX))
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        if *strike > 0.0 {
+            TermStructureStrikeValidity::Valid
+        } else {
+            TermStructureStrikeValidity::Invalid
+        }
+    }
+}
+
+impl<D> BlackVolatilityTermStructure<D> for FxVolatilitySurface<D>
+where
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        if !self.is_datetime_valid(maturity)
+            || self.validate_strike(strike) == TermStructureStrikeValidity::Invalid
+        {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        }
+
+        // At the reference date use the limit of the first smile.
+        let t = self
+            .time_from_reference(maturity)
+            .max(self.slices[0].time * 1e-6);
+        let k = (*strike / self.forward(t)).ln();
+        let Some(total_variance) = self.total_variance(t, k) else {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        };
+        let volatility = OrderedFloat((total_variance / t).sqrt());
+
+        if self.slices.iter().any(|slice| slice.expiry == maturity) {
+            BlackVolatilityTermStructureResult::ExistingValue(volatility)
+        } else {
+            BlackVolatilityTermStructureResult::InterpolatedValue(volatility)
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        money::currency::{EUR, USD},
+        term_structures::flat_curve,
+        Compounding,
+    };
+
+    fn value(result: BlackVolatilityTermStructureResult) -> f64 {
+        match result {
+            BlackVolatilityTermStructureResult::ExistingValue(v)
+            | BlackVolatilityTermStructureResult::InterpolatedValue(v) => *v,
+            _ => panic!("Expected a volatility."),
+        }
+    }
+
+    #[test]
+    fn test_fx_smile_malz() {
+        let expiry = DateTime::new_from_ymd(2023, 7, 2);
+        let spot: ExchangeRate<EUR, USD> = ExchangeRate::new(1.10);
+        let domestic = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let foreign = flat_curve::<EUR, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+
+        let (atm, risk_reversal, butterfly) = (0.08, -0.01, 0.003);
+        let surface = FxVolatilitySurfaceBuilder::new(&spot, &domestic, &foreign)
+            .quote(expiry, FxSmileQuote::new(atm, risk_reversal, butterfly))
+            .delta_type(FxDeltaType::Forward)
+            .interpolation(FxSmileInterpolation::Malz)
+            .build()
+            .unwrap();
+
+        let t = 182.0 / 365.0;
+        let forward = 1.10 * (0.02f64 * t).exp();
+        let forward_delta = |strike: f64, volatility: f64| {
+            let std_dev = volatility * f64::sqrt(t);
+            Normal::standard().cdf((forward / strike).ln() / std_dev + 0.5 * std_dev)
+        };
+
+        // The pillars have the quoted forward deltas.
+        let pillars = &surface.get_pillars()[0].1;
+        let expected = [
+            (0.75, atm + butterfly - 0.5 * risk_reversal),
+            (0.5, atm),
+            (0.25, atm + butterfly + 0.5 * risk_reversal),
+        ];
+        for ((strike, volatility), (delta, pillar_volatility)) in pillars.iter().zip(expected) {
+            assert_approx_eq!(**volatility, pillar_volatility, 10e-12);
+            assert_approx_eq!(forward_delta(**strike, **volatility), delta, 10e-10);
+        }
+
+        // Malz's quadratic smile in the forward delta.
+        for strike in [0.95, 1.05, 1.12, 1.2, 1.35] {
+            let volatility = value(surface.black_volatility(expiry, OrderedFloat(strike)));
+            let x = forward_delta(strike, volatility) - 0.5;
+            let malz = (16.0 * butterfly).mul_add(x * x, (-2.0 * risk_reversal).mul_add(x, atm));
+            assert_approx_eq!(volatility, malz, 10e-10);
+        }
+    }
+
+    #[test]
+    fn test_fx_smile_premium_adjusted() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let spot: ExchangeRate<USD, EUR> = ExchangeRate::new(0.91);
+        let domestic = flat_curve::<EUR, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+        let foreign = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+
+        let quote = FxSmileQuote::new(0.09, -0.012, 0.0035).with_10_delta(-0.022, 0.011);
+        let mut builder = FxVolatilitySurfaceBuilder::new(&spot, &domestic, &foreign);
+        builder
+            .quote(expiry, quote)
+            .delta_type(FxDeltaType::PremiumAdjustedSpot)
+            .interpolation(FxSmileInterpolation::Malz);
+        let surface = builder.build().unwrap();
+
+        let foreign_discount = (-0.05f64).exp();
+        let forward = 0.91 * (-0.02f64).exp();
+        let premium_adjusted_delta = |strike: f64, volatility: f64, omega: f64| {
+            let d_minus = (forward / strike).ln() / volatility - 0.5 * volatility;
+            omega * foreign_discount * strike / forward * Normal::standard().cdf(omega * d_minus)
+        };
+
+        let pillars = &surface.get_pillars()[0].1;
+        assert_eq!(pillars.len(), 5);
+        let deltas: [f64; 5] = [-0.10, -0.25, 0.0, 0.25, 0.10];
+        for (i, ((strike, volatility), delta)) in pillars.iter().zip(deltas).enumerate() {
+            // Delta-neutral straddle: call and put deltas sum to zero.
+            if i == 2 {
+                assert_approx_eq!(
+                    premium_adjusted_delta(**strike, **volatility, 1.0)
+                        + premium_adjusted_delta(**strike, **volatility, -1.0),
+                    0.0,
+                    10e-10
+                );
+            } else {
+                let omega = delta.signum();
+                assert_approx_eq!(
+                    premium_adjusted_delta(**strike, **volatility, omega),
+                    delta,
+                    10e-10
+                );
+            }
+        }
+
+        // Malz interpolation reproduces every pillar.
+        for (strike, volatility) in pillars {
+            assert_approx_eq!(
+                value(surface.black_volatility(expiry, *strike)),
+                **volatility,
+                10e-10
+            );
+        }
+
+        // Vanna-Volga interpolation has no 10-delta pillars, and reproduces the others.
+        builder.interpolation(FxSmileInterpolation::VannaVolga);
+        assert_eq!(
+            builder.build().err(),
+            Some(FxVolatilitySurfaceBuilderError::UnsupportedPillars)
+        );
+
+        let surface = FxVolatilitySurfaceBuilder::new(&spot, &domestic, &foreign)
+            .quote(expiry, FxSmileQuote::new(0.09, -0.012, 0.0035))
+            .delta_type(FxDeltaType::PremiumAdjustedSpot)
+            .build()
+            .unwrap();
+        let vanna_volga_pillars = &surface.get_pillars()[0].1;
+        for ((strike, volatility), pillar) in vanna_volga_pillars.iter().zip(&pillars[1..4]) {
+            assert_approx_eq!(**strike, *pillar.0, 10e-12);
+            assert_approx_eq!(
+                value(surface.black_volatility(expiry, *strike)),
+                **volatility,
+                10e-10
+            );
+        }
+        assert!(
+            value(surface.black_volatility(expiry, pillars[0].0))
+                > value(surface.black_volatility(expiry, pillars[1].0))
+        );
+    }
+
+    #[test]
+    fn test_fx_smile_broker_strangle() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let spot: ExchangeRate<EUR, USD> = ExchangeRate::new(1.10);
+        let domestic = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let foreign = flat_curve::<EUR, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+
+        let forward = 1.10 * 0.02f64.exp();
+        let foreign_discount = (-0.03f64).exp();
+        let strangle = |volatility: &dyn Fn(f64) -> f64, delta: f64, broker_volatility: f64| {
+            let [put, call] = [OptionType::PUT, OptionType::CALL].map(|option_type| {
+                delta_strike(
+                    FxDeltaType::Spot,
+                    option_type,
+                    delta,
+                    forward,
+                    broker_volatility,
+                    foreign_discount,
+                )
+                .unwrap()
+            });
+            black_formula(OptionType::PUT, put, forward, volatility(put), 1.0)
+                + black_formula(OptionType::CALL, call, forward, volatility(call), 1.0)
+        };
+
+        let (atm, risk_reversal_25, butterfly_25) = (0.09, -0.02, 0.004);
+        let (risk_reversal_10, butterfly_10) = (-0.04, 0.014);
+        for (interpolation, quote) in [
+            (
+                FxSmileInterpolation::VannaVolga,
+                FxSmileQuote::new(atm, risk_reversal_25, butterfly_25),
+            ),
+            (
+                FxSmileInterpolation::Malz,
+                FxSmileQuote::new(atm, risk_reversal_25, butterfly_25)
+                    .with_10_delta(risk_reversal_10, butterfly_10),
+            ),
+        ] {
+            let surface = FxVolatilitySurfaceBuilder::new(&spot, &domestic, &foreign)
+                .quote(expiry, quote)
+                .butterfly_type(FxButterflyType::Broker)
+                .interpolation(interpolation)
+                .build()
+                .unwrap();
+            let volatility =
+                |strike: f64| value(surface.black_volatility(expiry, OrderedFloat(strike)));
+
+            // The smile reprices the broker strangles struck with the single volatility atm + bf.
+            let mut wings = vec![(0.25, butterfly_25)];
+            if quote.butterfly_10.is_some() {
+                wings.push((0.10, butterfly_10));
+            }
+            for (delta, butterfly) in wings {
+                let broker_volatility = atm + butterfly;
+                assert_approx_eq!(
+                    strangle(&volatility, delta, broker_volatility),
+                    strangle(&|_| broker_volatility, delta, broker_volatility),
+                    10e-10
+                );
+            }
+
+            // With a skew the smile butterfly differs from the broker butterfly.
+            let pillars = &surface.get_pillars()[0].1;
+            let n = pillars.len();
+            let smile_butterfly = 0.5 * (*pillars[n / 2 - 1].1 + *pillars[n / 2 + 1].1) - atm;
+            assert!((smile_butterfly - butterfly_25).abs() > 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_fx_surface_term_structure() {
+        let spot: ExchangeRate<EUR, USD> = ExchangeRate::new(1.10);
+        let domestic = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.05,
+            Compounding::Continuous,
+        );
+        let foreign = flat_curve::<EUR, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+
+        let mut builder = FxVolatilitySurfaceBuilder::new(&spot, &domestic, &foreign);
+        builder
+            .quote(
+                DateTime::new_from_ymd(2024, 1, 1),
+                FxSmileQuote::new(0.10, -0.01, 0.004),
+            )
+            .quote(
+                DateTime::new_from_ymd(2023, 7, 2),
+                FxSmileQuote::new(0.08, -0.008, 0.003),
+            );
+        let surface = builder.build().unwrap();
+
+        // At-the-money forward total variance is linear in time between the tenors.
+        let t: f64 = 273.0 / 365.0;
+        let forward = 1.10 * (0.02 * t).exp();
+        let atm_forward_variance = |date: DateTime, time: f64| {
+            let forward = 1.10 * (0.02 * time).exp();
+            let volatility = value(surface.black_volatility(date, OrderedFloat(forward)));
+            volatility * volatility * time
+        };
+        let w1 = atm_forward_variance(DateTime::new_from_ymd(2023, 7, 2), 182.0 / 365.0);
+        let w2 = atm_forward_variance(DateTime::new_from_ymd(2024, 1, 1), 1.0);
+        let volatility = value(
+            surface.black_volatility(DateTime::new_from_ymd(2023, 10, 1), OrderedFloat(forward)),
+        );
+        assert_approx_eq!(
+            volatility * volatility * t,
+            (w2 - w1).mul_add((t - 182.0 / 365.0) / (1.0 - 182.0 / 365.0), w1),
+            10e-10
+        );
+
+        builder.quote(
+            DateTime::new_from_ymd(2024, 1, 1),
+            FxSmileQuote::new(0.1, 0.0, 0.0),
+        );
+        assert_eq!(
+            builder.build().err(),
+            Some(FxVolatilitySurfaceBuilderError::DuplicateExpiry)
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use day_count_conventions::DayCounter;
+
+use crate::{time::DateTime, types::Volatility};
+
+use super::VolatilityTermStructure;
+
+pub trait LocalVolTermStructure<D>: VolatilityTermStructure<D>
+where
+    D: DayCounter,
+{
+    /// Local volatility $\sigma(t, S)$ at a datetime and level of the underlying.
+    fn local_volatility(
+        &self,
+        datetime: DateTime,
+        underlying_level: f64,
+    ) -> LocalVolTermStructureResult;
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone)]
+pub enum LocalVolTermStructureResult {
+    Value(Volatility),
+    OutOfRange,
+    /// The local variance implied by the term structure is negative (or undefined), which signals
+    /// arbitrage in the underlying implied volatilities.
+    NegativeLocalVariance,
+}
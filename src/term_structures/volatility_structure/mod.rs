@@ -14,9 +14,12 @@ where
 
 mod black_volatility_term_structure;
 pub use black_volatility_term_structure::{
-    BlackVolatilityTermStructure, BlackVolatilityTermStructureResult,
+    BlackForwardVolatilityResult, BlackVolatilityTermStructure, BlackVolatilityTermStructureResult,
 };
 
+mod local_vol_term_structure;
+pub use local_vol_term_structure::{LocalVolTermStructure, LocalVolTermStructureResult};
+
 mod constant_vol_term_structure;
 pub use constant_vol_term_structure::{
     ConstantVolTermStructure, ConstantVolTermStructureBuilder, ConstantVolTermStructureBuilderError,
@@ -24,3 +27,42 @@ pub use constant_vol_term_structure::{
 
 mod black_volatility_curve;
 pub use black_volatility_curve::{BlackVolatilityCurve, BlackVolatilityCurveBuilder};
+
+mod black_variance_surface;
+pub use black_variance_surface::{
+    BlackVarianceSurface, BlackVarianceSurfaceBuilder, BlackVarianceSurfaceBuilderError,
+    BlackVarianceSurfaceInterpolation, VolatilityExtrapolation,
+};
+
+mod sabr_smile_section;
+pub use sabr_smile_section::{
+    SabrSmileSection, SabrSmileSectionBuilder, SabrSmileSectionBuilderError,
+};
+
+mod forward_interpolation;
+
+mod svi;
+pub use svi::{JumpWingsSvi, NaturalSvi, RawSvi, SviCalibrationError};
+
+mod svi_surface;
+pub use svi_surface::{SviSurface, SviSurfaceBuilder, SviSurfaceBuilderError};
+
+mod ssvi_surface;
+pub use ssvi_surface::{SsviSurface, SsviSurfaceBuilder, SsviSurfaceBuilderError};
+
+mod dupire_local_vol_term_structure;
+pub use dupire_local_vol_term_structure::DupireLocalVolTermStructure;
+
+mod option_chain_surface;
+pub use option_chain_surface::{
+    OptionChainSlice, OptionChainSurfaceBuilder, OptionChainSurfaceBuilderError, OptionQuote,
+};
+
+mod smile_dynamics;
+pub use smile_dynamics::{SmileDynamics, SmileDynamicsSurface};
+
+mod fx_volatility_surface;
+pub use fx_volatility_surface::{
+    FxAtmType, FxButterflyType, FxDeltaType, FxSmileInterpolation, FxSmileQuote,
+    FxVolatilitySurface, FxVolatilitySurfaceBuilder, FxVolatilitySurfaceBuilderError,
+};
@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::instruments::{EuropeanOption, Exercise, Option as _, OptionType};
+use crate::money::{Currency, Money};
+use crate::pricers::black_implied_std_dev;
+use crate::term_structures::volatility_structure::{
+    SviSurface, SviSurfaceBuilder, SviSurfaceBuilderError,
+};
+use crate::term_structures::YieldTermStructure;
+use crate::time::DateTime;
+use crate::types::{Strike, Volatility};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Bid and ask prices of a European option.
+#[derive(Debug, Copy, Clone)]
+pub struct OptionQuote<C>
+where
+    C: Currency,
+{
+    pub option: EuropeanOption<C>,
+    pub bid: Money<C>,
+    pub ask: Money<C>,
+}
+
+/// Implied volatilities extracted from an option chain at one expiry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionChainSlice {
+    pub expiry: DateTime,
+
+    /// Forward implied by put-call parity, or from the curves if no strike has both a call and a
+    /// put quote.
+    pub forward: f64,
+
+    /// Implied volatilities of the out-of-the-money options, sorted by strike.
+    pub volatilities: Vec<(Strike, Volatility)>,
+}
+
+/// Builds a volatility surface from an option chain.
+///
+/// For each expiry:
+/// 1. quotes with a non-positive bid, an ask below the bid, or a bid-ask spread wider than the
+///    maximum relative spread (as a fraction of the mid price) are discarded;
+/// 2. the forward is inferred from put-call parity, $F = K + \frac{C - P}{D}$, at the strike where
+///    call and put mid prices are closest, falling back to $S \frac{D_q}{D}$ from the curves;
+/// 3. Black implied volatilities are computed from the mid prices of out-of-the-money options only
+///    (puts below the forward, calls at and above it), discarding prices outside the no-arbitrage
+///    bounds.
+///
+/// Expiries with at least five implied volatilities are then fitted with SVI slices, giving an
+/// [`SviSurface`] free of calendar arbitrage.
+pub struct OptionChainSurfaceBuilder<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    underlying_spot: Money<C>,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    quotes: Vec<OptionQuote<C>>,
+    max_relative_spread: f64,
+}
+
+/// Call and put mid prices at one strike.
+type MidPrices = (Option<f64>, Option<f64>);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OptionChainSurfaceBuilderError {
+    /// No expiry had enough valid quotes to fit a smile.
+    NotEnoughQuotes,
+    Surface(SviSurfaceBuilderError),
+}
+
+impl<'a, C, D> OptionChainSurfaceBuilder<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    pub fn new(
+        underlying_spot: Money<C>,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+    ) -> Self {
+        Self {
+            underlying_spot,
+            yield_curve,
+            dividend_curve,
+            quotes: Vec::new(),
+            max_relative_spread: 0.5,
+        }
+    }
+
+    /// Add the bid and ask prices of an option.
+    pub fn add_quote(
+        &mut self,
+        option: EuropeanOption<C>,
+        bid: Money<C>,
+        ask: Money<C>,
+    ) -> &mut Self {
+        self.quotes.push(OptionQuote { option, bid, ask });
+        self
+    }
+
+    /// Add the bid and ask prices of several options.
+    pub fn add_quotes(&mut self, quotes: &[OptionQuote<C>]) -> &mut Self {
+        self.quotes.extend_from_slice(quotes);
+        self
+    }
+
+    /// Set the widest accepted bid-ask spread, as a fraction of the mid price. Defaults to 0.5.
+    pub fn max_relative_spread(&mut self, max_relative_spread: f64) -> &mut Self {
+        self.max_relative_spread = max_relative_spread;
+        self
+    }
+
+    /// Implied volatilities of the valid out-of-the-money quotes, per expiry.
+    #[must_use]
+    pub fn implied_volatilities(&self) -> Vec<OptionChainSlice> {
+        let mut expiries: BTreeMap<DateTime, BTreeMap<Strike, MidPrices>> = BTreeMap::new();
+        for quote in &self.quotes {
+            let (bid, ask) = (*quote.bid.amount(), *quote.ask.amount());
+            let mid = 0.5 * (bid + ask);
+            if bid <= 0.0 || ask < bid || ask - bid > self.max_relative_spread * mid {
+                continue;
+            }
+
+            let prices = expiries
+                .entry(quote.option.get_exercise().get_last_date())
+                .or_default()
+                .entry(quote.option.get_strike().into())
+                .or_default();
+            match quote.option.get_option_type() {
+                OptionType::CALL => prices.0 = Some(mid),
+                OptionType::PUT => prices.1 = Some(mid),
+            }
+        }
+
+        let reference_date = self.yield_curve.get_reference_date();
+        let day_counter = self.yield_curve.get_day_counter();
+
+        expiries
+            .into_iter()
+            .filter_map(|(expiry, prices)| {
+                let d = *self.yield_curve.discount_factor(expiry).ok()?;
+                let q = *self.dividend_curve.discount_factor(expiry).ok()?;
+                let time = day_counter
+                    .day_count_fraction(&reference_date, &expiry)
+                    .get_fraction();
+                if time <= 0.0 {
+                    return None;
+                }
+
+                let forward = prices
+                    .iter()
+                    .filter_map(|(strike, prices)| match prices {
+                        (Some(call), Some(put)) => Some((**strike, call - put)),
+                        _ => None,
+                    })
+                    .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+                    .map_or(
+                        *self.underlying_spot.amount() * q / d,
+                        |(strike, parity)| strike + parity / d,
+                    );
+
+                let volatilities: Vec<(Strike, Volatility)> = prices
+                    .iter()
+                    .filter_map(|(strike, (call, put))| {
+                        let (option_type, price) = if **strike < forward {
+                            (OptionType::PUT, (*put)?)
+                        } else {
+                            (OptionType::CALL, (*call)?)
+                        };
+                        let std_dev =
+                            black_implied_std_dev(option_type, **strike, forward, price, d).ok()?;
+                        (std_dev > 0.0).then(|| (*strike, OrderedFloat(std_dev / time.sqrt())))
+                    })
+                    .collect();
+
+                Some(OptionChainSlice {
+                    expiry,
+                    forward,
+                    volatilities,
+                })
+            })
+            .collect()
+    }
+
+    /// Fit the implied volatilities with an SVI surface.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if no expiry has at least five implied volatilities, and
+    /// `Surface` if the SVI surface could not be built.
+    pub fn build(&self) -> Result<SviSurface<D>, OptionChainSurfaceBuilderError> {
+        let slices: Vec<OptionChainSlice> = self
+            .implied_volatilities()
+            .into_iter()
+            .filter(|slice| slice.volatilities.len() >= 5)
+            .collect();
+        if slices.is_empty() {
+            return Err(OptionChainSurfaceBuilderError::NotEnoughQuotes);
+        }
+
+        let mut builder = SviSurfaceBuilder::new();
+        builder
+            .spot(*self.underlying_spot.amount())
+            .reference_date(self.yield_curve.get_reference_date())
+            .day_count_convention(self.yield_curve.get_day_counter());
+        for slice in &slices {
+            builder.quotes(slice.expiry, slice.forward, &slice.volatilities);
+        }
+
+        builder
+            .build()
+            .map_err(OptionChainSurfaceBuilderError::Surface)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, VanillaPayoff},
+        money::currency::USD,
+        pricers::black_formula,
+        term_structures::{
+            flat_curve, BlackVolatilityTermStructure, BlackVolatilityTermStructureResult, RawSvi,
+        },
+        Compounding,
+    };
+
+    #[test]
+    fn test_option_chain_surface() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.04,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.01,
+            Compounding::Continuous,
+        );
+        let d = *yield_curve.discount_factor(expiry).unwrap();
+
+        // The market forward differs from the one implied by the curves (e.g. a borrow cost).
+        let forward = 102.0;
+        let smile = RawSvi::new(0.02, 0.1, -0.6, 0.05, 0.2);
+        let volatility = |strike: f64| smile.total_variance((strike / forward).ln()).sqrt();
+
+        let mut builder =
+            OptionChainSurfaceBuilder::new(Money::new(100.0), &yield_curve, &dividend_curve);
+        for strike in (14..=26).map(|i| f64::from(i) * 5.0) {
+            for option_type in [OptionType::CALL, OptionType::PUT] {
+                let price = black_formula(option_type, strike, forward, volatility(strike), d);
+                builder.add_quote(
+                    EuropeanOption::new(
+                        VanillaPayoff::new(Money::new(strike), option_type),
+                        EuropeanExercise::new(expiry),
+                    ),
+                    Money::new(price * 0.999),
+                    Money::new(price * 1.001),
+                );
+            }
+        }
+
+        // Crossed quotes and prices above the no-arbitrage bound are discarded.
+        let option = |strike: f64, option_type| {
+            EuropeanOption::new(
+                VanillaPayoff::new(Money::new(strike), option_type),
+                EuropeanExercise::new(expiry),
+            )
+        };
+        builder.add_quote(
+            option(97.0, OptionType::PUT),
+            Money::new(200.0),
+            Money::new(200.1),
+        );
+        builder.add_quote(
+            option(103.0, OptionType::CALL),
+            Money::new(8.0),
+            Money::new(7.0),
+        );
+
+        let slices = builder.implied_volatilities();
+        assert_eq!(slices.len(), 1);
+        assert_approx_eq!(slices[0].forward, forward, 10e-10);
+        assert_eq!(slices[0].volatilities.len(), 13);
+        for (strike, implied) in &slices[0].volatilities {
+            assert_approx_eq!(**implied, volatility(**strike), 10e-8);
+        }
+
+        let surface = builder.build().unwrap();
+        for strike in [75.0, 102.0, 125.0] {
+            match surface.black_volatility(expiry, OrderedFloat(strike)) {
+                BlackVolatilityTermStructureResult::ExistingValue(v) => {
+                    assert_approx_eq!(*v, volatility(strike), 10e-5);
+                }
+                _ => panic!("Expected an existing value."),
+            }
+        }
+    }
+}
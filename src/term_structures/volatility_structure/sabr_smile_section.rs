@@ -0,0 +1,312 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::models::{SabrModel, SabrVolatilityFormula};
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, VolatilityTermStructure,
+};
+use crate::term_structures::TermStructure;
+use crate::term_structures::TermStructureStrikeValidity;
+use crate::time::DateTime;
+use crate::types::Strike;
+
+use super::black_volatility_term_structure::BlackVolatilityTermStructureResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Black volatility smile at a single expiry generated by a [`SabrModel`].
+///
+/// Volatilities are valid for maturities up to the expiry of the section, and for strictly
+/// positive strikes.
+pub struct SabrSmileSection<D>
+where
+    D: DayCounter,
+{
+    model: SabrModel,
+
+    forward: f64,
+
+    expiry: DateTime,
+
+    formula: SabrVolatilityFormula,
+
+    reference_date: DateTime,
+
+    day_count_convention: D,
+}
+
+#[derive(Debug)]
+pub struct SabrSmileSectionBuilder<D>
+where
+    D: DayCounter,
+{
+    model: Option<SabrModel>,
+    forward: Option<f64>,
+    expiry: Option<DateTime>,
+    formula: Option<SabrVolatilityFormula>,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum SabrSmileSectionBuilderError {
+    NoModelProvided,
+    NoForwardProvided,
+    NonPositiveForward,
+    NoExpiryProvided,
+    ExpiryBeforeReferenceDate,
+}
+
+impl<D> SabrSmileSectionBuilder<D>
+where
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            model: None,
+            forward: None,
+            expiry: None,
+            formula: None,
+            reference_date: None,
+            day_count_convention: None,
+        }
+    }
+
+    pub fn model(&mut self, model: SabrModel) -> &mut Self {
+        self.model = Some(model);
+        self
+    }
+
+    pub fn forward(&mut self, forward: f64) -> &mut Self {
+        self.forward = Some(forward);
+        self
+    }
+
+    pub fn expiry(&mut self, expiry: DateTime) -> &mut Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// The implied volatility expansion; defaults to Hagan's lognormal formula.
+    pub fn formula(&mut self, formula: SabrVolatilityFormula) -> &mut Self {
+        self.formula = Some(formula);
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    pub fn build(&self) -> Result<SabrSmileSection<D>, SabrSmileSectionBuilderError> {
+        let model = self
+            .model
+            .ok_or(SabrSmileSectionBuilderError::NoModelProvided)?;
+        let forward = self
+            .forward
+            .ok_or(SabrSmileSectionBuilderError::NoForwardProvided)?;
+        let expiry = self
+            .expiry
+            .ok_or(SabrSmileSectionBuilderError::NoExpiryProvided)?;
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+
+        if forward <= 0.0 {
+            return Err(SabrSmileSectionBuilderError::NonPositiveForward);
+        }
+        if expiry < reference_date {
+            return Err(SabrSmileSectionBuilderError::ExpiryBeforeReferenceDate);
+        }
+
+        Ok(SabrSmileSection {
+            model,
+            forward,
+            expiry,
+            formula: self
+                .formula
+                .unwrap_or(SabrVolatilityFormula::HaganLognormal),
+            reference_date,
+            day_count_convention: self.day_count_convention.unwrap_or_default(),
+        })
+    }
+}
+
+impl<D> Default for SabrSmileSectionBuilder<D>
+where
+    D: DayCounter,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> SabrSmileSection<D>
+where
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn get_model(&self) -> SabrModel {
+        self.model
+    }
+
+    #[must_use]
+    pub const fn get_forward(&self) -> f64 {
+        self.forward
+    }
+
+    #[must_use]
+    pub const fn get_expiry(&self) -> DateTime {
+        self.expiry
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<D> TermStructure<D> for SabrSmileSection<D>
+where
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.expiry
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && dt <= self.expiry
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<D> VolatilityTermStructure<D> for SabrSmileSection<D>
+where
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        (OrderedFloat(f64::MIN_POSITIVE), OrderedFloat(f64::MAX))
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        if *strike > 0.0 {
+            TermStructureStrikeValidity::Valid
+        } else {
+            TermStructureStrikeValidity::Invalid
+        }
+    }
+}
+
+impl<D> BlackVolatilityTermStructure<D> for SabrSmileSection<D>
+where
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        if !self.is_datetime_valid(maturity)
+            || self.validate_strike(strike) == TermStructureStrikeValidity::Invalid
+        {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        }
+
+        let time = self.time_from_reference(maturity);
+        BlackVolatilityTermStructureResult::InterpolatedValue(OrderedFloat(self.model.volatility(
+            self.formula,
+            self.forward,
+            *strike,
+            time,
+        )))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, EuropeanOption, OptionType, VanillaPayoff},
+        money::{currency::USD, Money},
+        pricers::{black_formula, AnalyticBlackScholesMerton, Pricer},
+        term_structures::flat_curve,
+        Compounding,
+    };
+
+    #[test]
+    fn test_sabr_smile_section_prices_across_strikes() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let model = SabrModel::new(0.3, 1.0, -0.4, 0.6);
+
+        let smile = SabrSmileSectionBuilder::<Actual365Fixed>::new()
+            .model(model)
+            .forward(100.0)
+            .expiry(expiry)
+            .reference_date(reference_date)
+            .build()
+            .unwrap();
+        let yield_curve =
+            flat_curve::<USD, Actual365Fixed>(reference_date, 0.0, Compounding::Continuous);
+
+        let pricer = AnalyticBlackScholesMerton::new(Money::new(100.0), &smile, &yield_curve);
+
+        for strike in [70.0, 85.0, 100.0, 115.0, 130.0] {
+            let option = EuropeanOption::new(
+                VanillaPayoff::new(Money::new(strike), OptionType::PUT),
+                EuropeanExercise::new(expiry),
+            );
+            let std_dev = model.lognormal_volatility(100.0, strike, 1.0);
+            let expected = black_formula(OptionType::PUT, strike, 100.0, std_dev, 1.0);
+
+            assert_approx_eq!(*pricer.price(&option).amount(), expected, 10e-8);
+        }
+    }
+
+    #[test]
+    fn test_sabr_smile_section_validation() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+
+        let smile = SabrSmileSectionBuilder::<Actual365Fixed>::new()
+            .model(SabrModel::new(0.3, 1.0, -0.4, 0.6))
+            .forward(100.0)
+            .expiry(expiry)
+            .reference_date(reference_date)
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            smile.black_volatility(expiry, OrderedFloat(-1.0)),
+            BlackVolatilityTermStructureResult::OutOfRange
+        ));
+        assert!(matches!(
+            smile.black_volatility(DateTime::new_from_ymd(2025, 1, 1), OrderedFloat(100.0)),
+            BlackVolatilityTermStructureResult::OutOfRange
+        ));
+        assert!(SabrSmileSectionBuilder::<Actual365Fixed>::new()
+            .model(SabrModel::new(0.3, 1.0, -0.4, 0.6))
+            .expiry(expiry)
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,555 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::instruments::{EuropeanOption, Exercise, Option as _};
+use crate::math::solvers::Brent;
+use crate::money::{Currency, Money};
+use crate::pricers::black_formula;
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, BlackVolatilityTermStructureResult, VolatilityTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureStrikeValidity, YieldTermStructure};
+use crate::time::DateTime;
+use crate::types::Strike;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// How the volatility smile moves when the underlying spot moves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SmileDynamics {
+    /// The volatility of a fixed strike does not change.
+    #[default]
+    StickyStrike,
+
+    /// The volatility of a fixed forward moneyness $K / F$ does not change, so the smile
+    /// translates with the forward.
+    StickyMoneyness,
+
+    /// The volatility of a fixed Black forward delta does not change.
+    ///
+    /// Since $d_+$ only depends on the strike through $K / F$ once the volatility is fixed, this
+    /// moves the smile exactly as [`SmileDynamics::StickyMoneyness`] does; the rules differ only
+    /// in the coordinates the smile is quoted in.
+    StickyDelta,
+}
+
+/// A Black volatility surface marked at a reference spot, seen from a (possibly different)
+/// underlying spot.
+///
+/// When the spot moves from $S_0$ to $S$ the forward moves from $F_0$ to $F = F_0 S / S_0$ (the
+/// curves are unchanged), and the volatility at strike $K$ is read from the marked surface at
+/// - $K$ under sticky strike,
+/// - $K F_0 / F = K S_0 / S$ under sticky moneyness and sticky delta.
+///
+/// The surface can also be read in moneyness or delta coordinates, and the spot Greeks computed
+/// by bump-and-reprice include the smile move implied by the chosen dynamics.
+pub struct SmileDynamicsSurface<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    black_volatility_surface: &'a dyn BlackVolatilityTermStructure<D>,
+    reference_spot: Money<C>,
+    underlying_spot: Money<C>,
+    yield_curve: &'a dyn YieldTermStructure<C, D>,
+    dividend_curve: &'a dyn YieldTermStructure<C, D>,
+    dynamics: SmileDynamics,
+    spot_bump: f64,
+}
+
+impl<'a, C, D> SmileDynamicsSurface<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `SmileDynamicsSurface` around a surface marked at `reference_spot`. The
+    /// underlying spot starts at the reference spot.
+    pub fn new(
+        black_volatility_surface: &'a impl BlackVolatilityTermStructure<D>,
+        reference_spot: Money<C>,
+        yield_curve: &'a impl YieldTermStructure<C, D>,
+        dividend_curve: &'a impl YieldTermStructure<C, D>,
+        dynamics: SmileDynamics,
+    ) -> Self {
+        Self {
+            black_volatility_surface,
+            reference_spot,
+            underlying_spot: reference_spot,
+            yield_curve,
+            dividend_curve,
+            dynamics,
+            spot_bump: 1e-4,
+        }
+    }
+
+    /// Move the underlying spot.
+    #[must_use]
+    pub const fn with_underlying_spot(mut self, underlying_spot: Money<C>) -> Self {
+        self.underlying_spot = underlying_spot;
+        self
+    }
+
+    /// Set the relative spot bump used for the Greeks. Defaults to $10^{-4}$.
+    #[must_use]
+    pub const fn with_spot_bump(mut self, spot_bump: f64) -> Self {
+        self.spot_bump = spot_bump;
+        self
+    }
+
+    #[must_use]
+    pub const fn get_underlying_spot(&self) -> Money<C> {
+        self.underlying_spot
+    }
+
+    #[must_use]
+    pub const fn get_reference_spot(&self) -> Money<C> {
+        self.reference_spot
+    }
+
+    #[must_use]
+    pub const fn get_dynamics(&self) -> SmileDynamics {
+        self.dynamics
+    }
+
+    /// Forward of the underlying at a datetime, from the current spot.
+    #[must_use]
+    pub fn forward(&self, datetime: DateTime) -> Option<f64> {
+        let d = *self.yield_curve.discount_factor(datetime).ok()?;
+        let q = *self.dividend_curve.discount_factor(datetime).ok()?;
+        Some(*self.underlying_spot.amount() * q / d)
+    }
+
+    /// Black volatility at a forward moneyness $K / F$.
+    #[must_use]
+    pub fn moneyness_volatility(
+        &self,
+        maturity: DateTime,
+        moneyness: f64,
+    ) -> BlackVolatilityTermStructureResult {
+        match self.forward(maturity) {
+            Some(forward) => self.black_volatility(maturity, OrderedFloat(moneyness * forward)),
+            None => BlackVolatilityTermStructureResult::OutOfRange,
+        }
+    }
+
+    /// Strike whose Black forward call delta $N(d_+)$ is `delta`, with $d_+$ evaluated at the
+    /// smile volatility of the strike itself.
+    ///
+    /// Returns `None` if `delta` is not in $(0, 1)$ or no such strike exists in the range of the
+    /// surface.
+    #[must_use]
+    pub fn delta_strike(&self, maturity: DateTime, delta: f64) -> Option<Strike> {
+        if delta <= 0.0 || delta >= 1.0 {
+            return None;
+        }
+
+        let forward = self.forward(maturity)?;
+        let time = self.time_from_reference(maturity);
+        if time <= 0.0 {
+            return None;
+        }
+
+        let norm = Normal::standard();
+        let objective = |log_moneyness: f64| {
+            let strike = forward * f64::exp(log_moneyness);
+            match self.black_volatility(maturity, OrderedFloat(strike)) {
+                BlackVolatilityTermStructureResult::ExistingValue(v)
+                | BlackVolatilityTermStructureResult::InterpolatedValue(v) => {
+                    let std_dev = *v * time.sqrt();
+                    norm.cdf(-log_moneyness / std_dev + 0.5 * std_dev) - delta
+                }
+                _ => f64::NAN,
+            }
+        };
+
+        let (min_strike, max_strike) = self.get_min_max_strike();
+        let lower = (*min_strike / forward).ln().max(-10.0);
+        let upper = (*max_strike / forward).ln().min(10.0);
+        let log_moneyness = Brent::default().solve(objective, lower, upper).ok()?;
+
+        Some(OrderedFloat(forward * log_moneyness.exp()))
+    }
+
+    /// Black volatility at a Black forward call delta.
+    #[must_use]
+    pub fn delta_volatility(
+        &self,
+        maturity: DateTime,
+        delta: f64,
+    ) -> BlackVolatilityTermStructureResult {
+        match self.delta_strike(maturity, delta) {
+            Some(strike) => self.black_volatility(maturity, strike),
+            None => BlackVolatilityTermStructureResult::OutOfRange,
+        }
+    }
+
+    /// Spot delta of an option, including the smile move implied by the dynamics.
+    ///
+    /// Computed by central differences, repricing with the Black formula on the re-marked smile.
+    ///
+    /// # Panics
+    /// Will panic if the volatility or the discount factor at the option's expiry is unavailable.
+    #[must_use]
+    pub fn delta(&self, option: &EuropeanOption<C>) -> f64 {
+        self.delta_with(|surface| surface.black_price(option))
+    }
+
+    /// Spot gamma of an option, including the smile move implied by the dynamics.
+    ///
+    /// # Panics
+    /// Will panic if the volatility or the discount factor at the option's expiry is unavailable.
+    #[must_use]
+    pub fn gamma(&self, option: &EuropeanOption<C>) -> f64 {
+        self.gamma_with(|surface| surface.black_price(option))
+    }
+
+    /// Spot delta of the price given by a pricer on the surface, including the smile move implied
+    /// by the dynamics.
+    ///
+    /// `price` is called with the surface moved to each bumped spot, and should price off its
+    /// underlying spot and volatilities, e.g. with
+    /// `|surface| AnalyticBlackScholesMerton::new(surface.get_underlying_spot(), surface, &curve)`.
+    pub fn delta_with(&self, price: impl Fn(&Self) -> Money<C>) -> f64 {
+        let spot = *self.underlying_spot.amount();
+        let h = spot * self.spot_bump;
+        *(price(&self.at_spot(spot + h)) - price(&self.at_spot(spot - h))).amount() / (2.0 * h)
+    }
+
+    /// Spot gamma of the price given by a pricer on the surface, including the smile move implied
+    /// by the dynamics. See [`SmileDynamicsSurface::delta_with`].
+    pub fn gamma_with(&self, price: impl Fn(&Self) -> Money<C>) -> f64 {
+        let spot = *self.underlying_spot.amount();
+        let h = spot * self.spot_bump;
+        let (up, mid, down) = (
+            *price(&self.at_spot(spot + h)).amount(),
+            *price(self).amount(),
+            *price(&self.at_spot(spot - h)).amount(),
+        );
+        (2.0f64.mul_add(-mid, up) + down) / (h * h)
+    }
+
+    /// The surface with the underlying at `spot`.
+    fn at_spot(&self, spot: f64) -> Self {
+        Self {
+            underlying_spot: Money::new(spot),
+            ..*self
+        }
+    }
+
+    /// Black price of an option on the forward and volatility of the surface.
+    fn black_price(&self, option: &EuropeanOption<C>) -> Money<C> {
+        use BlackVolatilityTermStructureResult::{ExistingValue, InterpolatedValue};
+
+        let expiry = option.get_exercise().get_last_date();
+        let sigma = match self.black_volatility(expiry, option.get_strike().into()) {
+            ExistingValue(v) | InterpolatedValue(v) => *v,
+            _ => panic!("Out of range."),
+        };
+        let d = *self.yield_curve.discount_factor(expiry).unwrap();
+        let forward = self.forward(expiry).unwrap();
+        let std_dev = sigma * self.time_from_reference(expiry).sqrt();
+
+        Money::new(black_formula(
+            option.get_option_type(),
+            *option.get_strike().amount(),
+            forward,
+            std_dev,
+            d,
+        ))
+    }
+
+    /// Strike of the marked surface that the dynamics map `strike` to.
+    fn marked_strike(&self, strike: Strike) -> Strike {
+        match self.dynamics {
+            SmileDynamics::StickyStrike => strike,
+            SmileDynamics::StickyMoneyness | SmileDynamics::StickyDelta => {
+                strike * (*self.reference_spot.amount() / *self.underlying_spot.amount())
+            }
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<'a, C, D> TermStructure<D> for SmileDynamicsSurface<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.black_volatility_surface.get_reference_date()
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.black_volatility_surface.get_max_datetime()
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        self.black_volatility_surface.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.black_volatility_surface.get_day_counter()
+    }
+}
+
+impl<'a, C, D> VolatilityTermStructure<D> for SmileDynamicsSurface<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        let (min_strike, max_strike) = self.black_volatility_surface.get_min_max_strike();
+        match self.dynamics {
+            SmileDynamics::StickyStrike => (min_strike, max_strike),
+            SmileDynamics::StickyMoneyness | SmileDynamics::StickyDelta => {
+                let ratio = *self.underlying_spot.amount() / *self.reference_spot.amount();
+                (min_strike * ratio, max_strike * ratio)
+            }
+        }
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        self.black_volatility_surface
+            .validate_strike(self.marked_strike(strike))
+    }
+}
+
+impl<'a, C, D> BlackVolatilityTermStructure<D> for SmileDynamicsSurface<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        self.black_volatility_surface
+            .black_volatility(maturity, self.marked_strike(strike))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        instruments::{EuropeanExercise, OptionType, VanillaPayoff},
+        money::currency::USD,
+        pricers::{AnalyticBlackScholesMerton, Pricer},
+        term_structures::{flat_curve, RawSvi, SviSurface, SviSurfaceBuilder},
+        Compounding,
+    };
+
+    fn skewed_surface() -> SviSurface<Actual365Fixed> {
+        SviSurfaceBuilder::new()
+            .spot(100.0)
+            .slice(
+                DateTime::new_from_ymd(2024, 1, 1),
+                100.0,
+                RawSvi::new(0.03, 0.1, -0.7, 0.0, 0.2),
+            )
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build()
+            .unwrap()
+    }
+
+    fn value(result: BlackVolatilityTermStructureResult) -> f64 {
+        match result {
+            BlackVolatilityTermStructureResult::ExistingValue(v)
+            | BlackVolatilityTermStructureResult::InterpolatedValue(v) => *v,
+            _ => panic!("Expected a volatility."),
+        }
+    }
+
+    #[test]
+    fn test_smile_dynamics_spot_move() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let black = skewed_surface();
+        let rates = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+
+        let sticky_strike = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &rates,
+            &rates,
+            SmileDynamics::StickyStrike,
+        )
+        .with_underlying_spot(Money::new(110.0));
+        let sticky_moneyness = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &rates,
+            &rates,
+            SmileDynamics::StickyMoneyness,
+        )
+        .with_underlying_spot(Money::new(110.0));
+
+        assert_approx_eq!(
+            value(sticky_strike.black_volatility(expiry, OrderedFloat(110.0))),
+            value(black.black_volatility(expiry, OrderedFloat(110.0))),
+            10e-12
+        );
+        assert_approx_eq!(
+            value(sticky_moneyness.black_volatility(expiry, OrderedFloat(110.0))),
+            value(black.black_volatility(expiry, OrderedFloat(100.0))),
+            10e-12
+        );
+        assert_approx_eq!(
+            value(sticky_moneyness.moneyness_volatility(expiry, 0.9)),
+            value(black.black_volatility(expiry, OrderedFloat(90.0))),
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_smile_dynamics_delta_coordinates() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let black = skewed_surface();
+        let yield_curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+        let dividend_curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.01,
+            Compounding::Continuous,
+        );
+        let surface = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &yield_curve,
+            &dividend_curve,
+            SmileDynamics::StickyDelta,
+        );
+
+        let forward = surface.forward(expiry).unwrap();
+        for delta in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            let strike = *surface.delta_strike(expiry, delta).unwrap();
+            let sigma = value(surface.delta_volatility(expiry, delta));
+            let d_plus = (forward / strike).ln() / sigma + 0.5 * sigma;
+            assert_approx_eq!(Normal::standard().cdf(d_plus), delta, 10e-10);
+        }
+
+        // The strike at a given delta moves with the spot.
+        let moved = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &yield_curve,
+            &dividend_curve,
+            SmileDynamics::StickyDelta,
+        )
+        .with_underlying_spot(Money::new(90.0));
+        assert_approx_eq!(
+            *moved.delta_strike(expiry, 0.25).unwrap(),
+            *surface.delta_strike(expiry, 0.25).unwrap() * 0.9,
+            10e-8
+        );
+        assert_approx_eq!(
+            value(moved.delta_volatility(expiry, 0.25)),
+            value(surface.delta_volatility(expiry, 0.25)),
+            10e-8
+        );
+        assert!(surface.delta_strike(expiry, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_smile_dynamics_greeks() {
+        let expiry = DateTime::new_from_ymd(2024, 1, 1);
+        let black = skewed_surface();
+        let rates = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.02,
+            Compounding::Continuous,
+        );
+        let option = EuropeanOption::new(
+            VanillaPayoff::new(Money::new(95.0), OptionType::CALL),
+            EuropeanExercise::new(expiry),
+        );
+
+        let sticky_strike = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &rates,
+            &rates,
+            SmileDynamics::StickyStrike,
+        );
+        let sticky_moneyness = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &rates,
+            &rates,
+            SmileDynamics::StickyMoneyness,
+        );
+
+        // Sticky strike gives the Black-Scholes delta at the strike's volatility.
+        let q = *rates.discount_factor(expiry).unwrap();
+        let sigma = value(black.black_volatility(expiry, OrderedFloat(95.0)));
+        let d_plus = (100.0f64 / 95.0).ln() / sigma + 0.5 * sigma;
+        let norm = Normal::standard();
+        assert_approx_eq!(sticky_strike.delta(&option), q * norm.cdf(d_plus), 10e-7);
+        assert_approx_eq!(
+            sticky_strike.gamma(&option),
+            q * (-0.5 * d_plus * d_plus).exp()
+                / (2.0 * std::f64::consts::PI).sqrt()
+                / (100.0 * sigma),
+            10e-5
+        );
+
+        // With a negative skew, the volatility at a fixed strike rises as spot rises under sticky
+        // moneyness, adding vega times the skew to the delta.
+        let bumped = SmileDynamicsSurface::new(
+            &black,
+            Money::new(100.0),
+            &rates,
+            &rates,
+            SmileDynamics::StickyMoneyness,
+        )
+        .with_underlying_spot(Money::new(101.0));
+        assert!(
+            value(bumped.black_volatility(expiry, OrderedFloat(95.0)))
+                > value(sticky_moneyness.black_volatility(expiry, OrderedFloat(95.0)))
+        );
+        assert!(sticky_moneyness.delta(&option) > sticky_strike.delta(&option));
+
+        // A pricer on the wrapper sees the same smile move. Black-Scholes-Merton forwards the
+        // spot on the yield curve alone, matching the Black prices when both curves are equal.
+        let no_dividends = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.0,
+            Compounding::Continuous,
+        );
+        for dynamics in [SmileDynamics::StickyStrike, SmileDynamics::StickyMoneyness] {
+            let surface = SmileDynamicsSurface::new(
+                &black,
+                Money::new(100.0),
+                &rates,
+                &no_dividends,
+                dynamics,
+            );
+            let bsm = |surface: &SmileDynamicsSurface<USD, Actual365Fixed>| {
+                AnalyticBlackScholesMerton::new(surface.get_underlying_spot(), surface, &rates)
+                    .price(&option)
+            };
+            assert_approx_eq!(surface.delta_with(bsm), surface.delta(&option), 10e-8);
+            assert_approx_eq!(surface.gamma_with(bsm), surface.gamma(&option), 10e-5);
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::math::optimization::NelderMead;
+use crate::term_structures::volatility_structure::forward_interpolation::interpolate_forward;
+use crate::term_structures::volatility_structure::svi::RawSvi;
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, NaturalSvi, SviCalibrationError, VolatilityTermStructure,
+};
+use crate::term_structures::TermStructure;
+use crate::term_structures::TermStructureStrikeValidity;
+use crate::time::DateTime;
+use crate::types::{Strike, Volatility};
+
+use super::black_volatility_term_structure::BlackVolatilityTermStructureResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Surface SVI (Gatheral & Jacquier, 2014) with a power-law curvature function.
+///
+/// Total variance at log-moneyness $k = \ln(K / F_\tau)$ is
+/// $$
+///     w(k, \theta_\tau) = \frac{\theta_\tau}{2} \left( 1 + \rho \varphi(\theta_\tau) k
+///         + \sqrt{(\varphi(\theta_\tau) k + \rho)^2 + 1 - \rho^2} \right), \quad
+///     \varphi(\theta) = \frac{\eta}{\theta^\gamma (1 + \theta)^{1 - \gamma}}
+/// $$
+/// where the at-the-money total variance $\theta_\tau$ is interpolated linearly in time between
+/// the given at-the-money points (and scaled proportionally to time outside them).
+///
+/// The surface is free of calendar arbitrage when $\theta_\tau$ is non-decreasing and
+/// $\gamma \in [0, 1]$, which the builder enforces. It is free of butterfly arbitrage when
+/// $\theta \varphi(\theta) (1 + |\rho|) < 4$ and $\theta \varphi(\theta)^2 (1 + |\rho|) \leq 4$,
+/// see [`SsviSurface::is_butterfly_free`].
+pub struct SsviSurface<D>
+where
+    D: DayCounter,
+{
+    spot: f64,
+
+    /// At-the-money points as `(time to expiry, forward, total variance)`, sorted by time.
+    atm_points: Vec<(f64, f64, f64)>,
+
+    rho: f64,
+
+    eta: f64,
+
+    gamma: f64,
+
+    reference_date: DateTime,
+
+    day_count_convention: D,
+}
+
+#[derive(Debug)]
+pub struct SsviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    spot: Option<f64>,
+    atm_points: Vec<(DateTime, f64, Volatility)>,
+    rho: f64,
+    eta: f64,
+    gamma: f64,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SsviSurfaceBuilderError {
+    NoSpotProvided,
+    NoAtmPointsProvided,
+    NonPositiveForward,
+
+    /// An expiry is not after the reference date, or its time to expiry is not positive.
+    ExpiryNotAfterReferenceDate,
+
+    /// Two expiries have the same time to expiry.
+    DuplicateExpiry,
+
+    InvalidParameters,
+    CalendarArbitrage,
+}
+
+impl<D> SsviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    /// Create a new builder, with $\rho = 0$, $\eta = 1$ and $\gamma = \frac{1}{2}$.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            spot: None,
+            atm_points: Vec::new(),
+            rho: 0.0,
+            eta: 1.0,
+            gamma: 0.5,
+            reference_date: None,
+            day_count_convention: None,
+        }
+    }
+
+    pub fn spot(&mut self, spot: f64) -> &mut Self {
+        self.spot = Some(spot);
+        self
+    }
+
+    /// Add the forward and at-the-money forward volatility at an expiry.
+    pub fn atm_point(
+        &mut self,
+        expiry: DateTime,
+        forward: f64,
+        volatility: impl Into<Volatility>,
+    ) -> &mut Self {
+        self.atm_points.push((expiry, forward, volatility.into()));
+        self
+    }
+
+    pub fn rho(&mut self, rho: f64) -> &mut Self {
+        self.rho = rho;
+        self
+    }
+
+    pub fn eta(&mut self, eta: f64) -> &mut Self {
+        self.eta = eta;
+        self
+    }
+
+    pub fn gamma(&mut self, gamma: f64) -> &mut Self {
+        self.gamma = gamma;
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    pub fn build(&self) -> Result<SsviSurface<D>, SsviSurfaceBuilderError> {
+        let spot = self.spot.ok_or(SsviSurfaceBuilderError::NoSpotProvided)?;
+        if self.atm_points.is_empty() {
+            return Err(SsviSurfaceBuilderError::NoAtmPointsProvided);
+        }
+        if self.rho.abs() >= 1.0 || self.eta <= 0.0 || !(0.0..=1.0).contains(&self.gamma) {
+            return Err(SsviSurfaceBuilderError::InvalidParameters);
+        }
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+        let day_count_convention = self.day_count_convention.unwrap_or_default();
+
+        let mut points = self.atm_points.clone();
+        points.sort_by_key(|point| point.0);
+
+        let mut atm_points: Vec<(f64, f64, f64)> = Vec::with_capacity(points.len());
+        for (expiry, forward, volatility) in points {
+            if forward <= 0.0 {
+                return Err(SsviSurfaceBuilderError::NonPositiveForward);
+            }
+            let time = day_count_convention
+                .day_count_fraction(&reference_date, &expiry)
+                .get_fraction();
+            if expiry <= reference_date || time <= 0.0 {
+                return Err(SsviSurfaceBuilderError::ExpiryNotAfterReferenceDate);
+            }
+            if atm_points.last().is_some_and(|last| last.0 >= time) {
+                return Err(SsviSurfaceBuilderError::DuplicateExpiry);
+            }
+            let theta = *volatility * *volatility * time;
+            if atm_points.last().is_some_and(|last| last.2 > theta) {
+                return Err(SsviSurfaceBuilderError::CalendarArbitrage);
+            }
+
+            atm_points.push((time, forward, theta));
+        }
+
+        Ok(SsviSurface {
+            spot,
+            atm_points,
+            rho: self.rho,
+            eta: self.eta,
+            gamma: self.gamma,
+            reference_date,
+            day_count_convention,
+        })
+    }
+}
+
+impl<D> Default for SsviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> SsviSurface<D>
+where
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn get_rho(&self) -> f64 {
+        self.rho
+    }
+
+    #[must_use]
+    pub const fn get_eta(&self) -> f64 {
+        self.eta
+    }
+
+    #[must_use]
+    pub const fn get_gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// The power-law curvature function $\varphi(\theta)$.
+    #[must_use]
+    pub fn phi(&self, theta: f64) -> f64 {
+        self.eta / (theta.powf(self.gamma) * (1.0 + theta).powf(1.0 - self.gamma))
+    }
+
+    /// At-the-money total variance $\theta_\tau$ at a time to expiry.
+    #[must_use]
+    pub fn atm_total_variance(&self, t: f64) -> f64 {
+        let index = self.atm_points.partition_point(|point| point.0 < t);
+
+        if index == 0 {
+            let (time, _, theta) = self.atm_points[0];
+            return theta * t / time;
+        }
+        if index == self.atm_points.len() {
+            let (time, _, theta) = self.atm_points[index - 1];
+            return theta * t / time;
+        }
+
+        let (t1, _, theta1) = self.atm_points[index - 1];
+        let (t2, _, theta2) = self.atm_points[index];
+        (theta2 - theta1).mul_add((t - t1) / (t2 - t1), theta1)
+    }
+
+    /// The SSVI slice at a time to expiry, as a raw SVI slice in log-moneyness.
+    #[must_use]
+    pub fn slice(&self, t: f64) -> RawSvi {
+        let theta = self.atm_total_variance(t);
+        RawSvi::from(NaturalSvi::new(0.0, 0.0, self.rho, theta, self.phi(theta)))
+    }
+
+    /// Whether the surface is free of butterfly arbitrage at every at-the-money point.
+    #[must_use]
+    pub fn is_butterfly_free(&self) -> bool {
+        self.atm_points
+            .iter()
+            .all(|(_, _, theta)| self.butterfly_violation(*theta) <= 0.0)
+    }
+
+    /// How far a slice is from satisfying the butterfly conditions, or a non-positive number if it
+    /// satisfies them.
+    fn butterfly_violation(&self, theta: f64) -> f64 {
+        let phi = self.phi(theta);
+        let scale = theta * phi * (1.0 + self.rho.abs());
+        (scale - 4.0).max(scale * phi - 4.0)
+    }
+
+    /// Recalibrate $\rho$, $\eta$ and $\gamma$ to market `(expiry, strike, volatility)` quotes,
+    /// keeping the at-the-money total variance curve.
+    ///
+    /// Squared total variance errors are minimised with Nelder-Mead over transformed parameters
+    /// ($\rho = \tanh \hat{\rho}$, $\eta = e^{\hat{\eta}}$, $\gamma$ logistic) so that the
+    /// calendar conditions always hold, and butterfly arbitrage is penalised. The current
+    /// parameters are used as the initial guess.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if fewer than three quotes are provided, and `NoConvergence`
+    /// if the optimiser did not converge.
+    pub fn calibrate(
+        &self,
+        quotes: &[(DateTime, Strike, Volatility)],
+    ) -> Result<Self, SviCalibrationError> {
+        if quotes.len() < 3 {
+            return Err(SviCalibrationError::NotEnoughQuotes);
+        }
+
+        let market: Vec<(f64, f64, f64)> = quotes
+            .iter()
+            .map(|(expiry, strike, volatility)| {
+                let t = self.time_from_reference(*expiry);
+                (
+                    t,
+                    (**strike / self.forward(t)).ln(),
+                    **volatility * **volatility * t,
+                )
+            })
+            .collect();
+
+        let to_surface = |x: &[f64]| Self {
+            spot: self.spot,
+            atm_points: self.atm_points.clone(),
+            rho: x[0].tanh(),
+            eta: x[1].exp(),
+            gamma: 1.0 / (1.0 + (-x[2]).exp()),
+            reference_date: self.reference_date,
+            day_count_convention: self.day_count_convention,
+        };
+        let objective = |x: &[f64]| {
+            let surface = to_surface(x);
+            let penalty: f64 = surface
+                .atm_points
+                .iter()
+                .map(|(_, _, theta)| 1e3 * surface.butterfly_violation(*theta).max(0.0).powi(2))
+                .sum();
+            market
+                .iter()
+                .map(|(t, k, w)| (surface.slice(*t).total_variance(*k) - w).powi(2))
+                .sum::<f64>()
+                + penalty
+        };
+
+        let gamma = self.gamma.clamp(1e-6, 1.0 - 1e-6);
+        let initial = [
+            self.rho.atanh(),
+            self.eta.ln(),
+            (gamma / (1.0 - gamma)).ln(),
+        ];
+
+        let result = NelderMead::new(50_000, 1e-20).minimize(objective, &initial, 0.1);
+        if result.converged {
+            Ok(to_surface(&result.x))
+        } else {
+            Err(SviCalibrationError::NoConvergence)
+        }
+    }
+
+    /// Forward at a time to maturity.
+    fn forward(&self, t: f64) -> f64 {
+        let forwards: Vec<(f64, f64)> = self
+            .atm_points
+            .iter()
+            .map(|(time, forward, _)| (*time, *forward))
+            .collect();
+        interpolate_forward(self.spot, &forwards, t)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<D> TermStructure<D> for SsviSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<D> VolatilityTermStructure<D> for SsviSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        (OrderedFloat(f64::MIN_POSITIVE), OrderedFloat(f64::MAX))
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        if *strike > 0.0 {
+            TermStructureStrikeValidity::Valid
+        } else {
+            TermStructureStrikeValidity::Invalid
+        }
+    }
+}
+
+impl<D> BlackVolatilityTermStructure<D> for SsviSurface<D>
+where
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        if !self.is_datetime_valid(maturity)
+            || self.validate_strike(strike) == TermStructureStrikeValidity::Invalid
+        {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        }
+
+        // At the reference date use the limit of the first slice.
+        let t = self
+            .time_from_reference(maturity)
+            .max(self.atm_points[0].0 * 1e-6);
+        let k = (*strike / self.forward(t)).ln();
+
+        BlackVolatilityTermStructureResult::InterpolatedValue(OrderedFloat(
+            (self.slice(t).total_variance(k) / t).sqrt(),
+        ))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual365Fixed, Thirty360};
+
+    use super::*;
+
+    fn surface(rho: f64, eta: f64, gamma: f64) -> SsviSurface<Actual365Fixed> {
+        SsviSurfaceBuilder::new()
+            .spot(100.0)
+            .atm_point(DateTime::new_from_ymd(2023, 7, 2), 101.0, 0.25)
+            .atm_point(DateTime::new_from_ymd(2024, 1, 1), 102.0, 0.22)
+            .atm_point(DateTime::new_from_ymd(2025, 1, 1), 104.0, 0.2)
+            .rho(rho)
+            .eta(eta)
+            .gamma(gamma)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ssvi_atm_volatility() {
+        let surface = surface(-0.7, 1.2, 0.4);
+        assert!(surface.is_butterfly_free());
+
+        match surface.black_volatility(DateTime::new_from_ymd(2024, 1, 1), OrderedFloat(102.0)) {
+            BlackVolatilityTermStructureResult::InterpolatedValue(v) => {
+                assert_approx_eq!(*v, 0.22, 10e-12);
+            }
+            _ => panic!("Expected an interpolated value."),
+        }
+    }
+
+    #[test]
+    fn test_ssvi_arbitrage_checks() {
+        assert!(!surface(-0.7, 20.0, 0.4).is_butterfly_free());
+
+        let decreasing = SsviSurfaceBuilder::<Actual365Fixed>::new()
+            .spot(100.0)
+            .atm_point(DateTime::new_from_ymd(2024, 1, 1), 100.0, 0.3)
+            .atm_point(DateTime::new_from_ymd(2025, 1, 1), 100.0, 0.1)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 1))
+            .build();
+        assert!(matches!(
+            decreasing,
+            Err(SsviSurfaceBuilderError::CalendarArbitrage)
+        ));
+
+        // Under 30/360 the 30th and the 31st of a month are the same time to expiry.
+        let duplicate = SsviSurfaceBuilder::<Thirty360>::new()
+            .spot(100.0)
+            .atm_point(DateTime::new_from_ymd(2024, 1, 30), 100.0, 0.2)
+            .atm_point(DateTime::new_from_ymd(2024, 1, 31), 100.0, 0.2)
+            .reference_date(DateTime::new_from_ymd(2023, 1, 31))
+            .build();
+        assert!(matches!(
+            duplicate,
+            Err(SsviSurfaceBuilderError::DuplicateExpiry)
+        ));
+    }
+
+    #[test]
+    fn test_ssvi_calibration() {
+        let target = surface(-0.6, 1.1, 0.35);
+        let quotes: Vec<(DateTime, Strike, Volatility)> = [
+            DateTime::new_from_ymd(2023, 7, 2),
+            DateTime::new_from_ymd(2024, 1, 1),
+            DateTime::new_from_ymd(2025, 1, 1),
+        ]
+        .iter()
+        .flat_map(|&expiry| {
+            let target = &target;
+            [70.0, 85.0, 100.0, 115.0, 130.0]
+                .into_iter()
+                .map(
+                    move |strike| match target.black_volatility(expiry, OrderedFloat(strike)) {
+                        BlackVolatilityTermStructureResult::InterpolatedValue(v) => {
+                            (expiry, OrderedFloat(strike), v)
+                        }
+                        _ => panic!("Expected an interpolated value."),
+                    },
+                )
+        })
+        .collect();
+
+        let calibrated = surface(0.0, 1.0, 0.5).calibrate(&quotes).unwrap();
+        assert_approx_eq!(calibrated.get_rho(), -0.6, 10e-5);
+        assert_approx_eq!(calibrated.get_eta(), 1.1, 10e-5);
+        assert_approx_eq!(calibrated.get_gamma(), 0.35, 10e-5);
+    }
+}
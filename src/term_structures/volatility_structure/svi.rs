@@ -0,0 +1,452 @@
+use crate::math::optimization::NelderMead;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Gatheral's raw SVI parameterisation of a single smile.
+///
+/// Total implied variance $w = \sigma_{BS}^2 \tau$ as a function of the log-moneyness
+/// $k = \ln(K / F)$ is
+/// $$
+///     w(k) = a + b \left( \rho (k - m) + \sqrt{(k - m)^2 + \sigma^2} \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RawSvi {
+    /// Overall level of variance $a$.
+    a: f64,
+
+    /// Angle between the wings $b \geq 0$.
+    b: f64,
+
+    /// Orientation of the smile $|\rho| < 1$.
+    rho: f64,
+
+    /// Horizontal translation $m$.
+    m: f64,
+
+    /// Smoothness of the vertex $\sigma > 0$.
+    sigma: f64,
+}
+
+/// Natural SVI parameterisation.
+/// $$
+///     w(k) = \Delta + \frac{\omega}{2} \left( 1 + \zeta \rho (k - \mu)
+///         + \sqrt{(\zeta (k - \mu) + \rho)^2 + 1 - \rho^2} \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NaturalSvi {
+    delta: f64,
+    mu: f64,
+    rho: f64,
+    omega: f64,
+    zeta: f64,
+}
+
+/// Jump-wings SVI parameterisation, expressed in terms of observable smile features at an expiry
+/// $\tau$ (Gatheral & Jacquier, 2014).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct JumpWingsSvi {
+    /// At-the-money variance $v_\tau$.
+    v: f64,
+
+    /// At-the-money skew $\psi_\tau$.
+    psi: f64,
+
+    /// Slope of the left (put) wing $p_\tau$.
+    p: f64,
+
+    /// Slope of the right (call) wing $c_\tau$.
+    c: f64,
+
+    /// Minimum implied variance $\tilde{v}_\tau$.
+    v_tilde: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SviCalibrationError {
+    /// At least five quotes are needed to calibrate the five SVI parameters.
+    NotEnoughQuotes,
+
+    /// The optimiser did not converge.
+    NoConvergence,
+}
+
+impl RawSvi {
+    /// Create a new raw SVI slice.
+    #[must_use]
+    pub const fn new(a: f64, b: f64, rho: f64, m: f64, sigma: f64) -> Self {
+        Self {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_a(&self) -> f64 {
+        self.a
+    }
+
+    #[must_use]
+    pub const fn get_b(&self) -> f64 {
+        self.b
+    }
+
+    #[must_use]
+    pub const fn get_rho(&self) -> f64 {
+        self.rho
+    }
+
+    #[must_use]
+    pub const fn get_m(&self) -> f64 {
+        self.m
+    }
+
+    #[must_use]
+    pub const fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Total implied variance $w(k)$.
+    #[must_use]
+    pub fn total_variance(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        self.b
+            .mul_add(self.rho.mul_add(x, x.hypot(self.sigma)), self.a)
+    }
+
+    /// First derivative $w'(k)$.
+    #[must_use]
+    pub fn total_variance_first_derivative(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        self.b * (self.rho + x / x.hypot(self.sigma))
+    }
+
+    /// Second derivative $w''(k)$.
+    #[must_use]
+    pub fn total_variance_second_derivative(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        self.b * self.sigma * self.sigma / x.hypot(self.sigma).powi(3)
+    }
+
+    /// Gatheral's density function
+    /// $$
+    ///     g(k) = \left( 1 - \frac{k w'}{2 w} \right)^2
+    ///         - \frac{w'^2}{4} \left( \frac{1}{w} + \frac{1}{4} \right) + \frac{w''}{2}
+    /// $$
+    /// which must be non-negative for the slice to be free of butterfly arbitrage.
+    #[must_use]
+    pub fn density_function(&self, k: f64) -> f64 {
+        let w = self.total_variance(k);
+        let w1 = self.total_variance_first_derivative(k);
+        let w2 = self.total_variance_second_derivative(k);
+
+        (1.0 - k * w1 / (2.0 * w)).powi(2) - 0.25 * w1 * w1 * (1.0 / w + 0.25) + 0.5 * w2
+    }
+
+    /// Whether the slice satisfies the basic parameter constraints ($b \geq 0$, $|\rho| < 1$,
+    /// $\sigma > 0$ and $a + b \sigma \sqrt{1 - \rho^2} \geq 0$, so that variance is non-negative).
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.b >= 0.0
+            && self.rho.abs() < 1.0
+            && self.sigma > 0.0
+            && (self.b * self.sigma).mul_add(self.rho.mul_add(-self.rho, 1.0).sqrt(), self.a) >= 0.0
+    }
+
+    /// Whether the slice is free of butterfly arbitrage, checked by evaluating $g(k)$ on the
+    /// given log-moneyness points.
+    #[must_use]
+    pub fn is_butterfly_free(&self, log_moneyness: &[f64]) -> bool {
+        self.is_valid()
+            && log_moneyness
+                .iter()
+                .all(|&k| self.density_function(k) >= 0.0)
+    }
+
+    /// Calibrate a raw SVI slice to `(log_moneyness, total_variance)` quotes.
+    ///
+    /// Squared total variance errors are minimised with Nelder-Mead over transformed parameters
+    /// ($b = e^{\hat{b}}$, $\rho = \tanh \hat{\rho}$, $\sigma = e^{\hat{\sigma}}$). Butterfly
+    /// arbitrage at the quoted points is penalised, as is total variance falling below
+    /// `lower_bound` (typically the previous slice, to avoid calendar arbitrage).
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if fewer than five quotes are provided, and `NoConvergence`
+    /// if the optimiser did not converge.
+    pub fn calibrate(
+        quotes: &[(f64, f64)],
+        lower_bound: Option<&Self>,
+    ) -> Result<Self, SviCalibrationError> {
+        if quotes.len() < 5 {
+            return Err(SviCalibrationError::NotEnoughQuotes);
+        }
+
+        let to_slice = |x: &[f64]| Self::new(x[0], x[1].exp(), x[2].tanh(), x[3], x[4].exp());
+        let objective = |x: &[f64]| {
+            let slice = to_slice(x);
+            let mut penalty = 0.0;
+            if !slice.is_valid() {
+                penalty += 1.0;
+            }
+            for (k, w) in quotes {
+                penalty += 1e3 * slice.density_function(*k).min(0.0).powi(2);
+                if let Some(lower) = lower_bound {
+                    penalty += 1e3
+                        * (slice.total_variance(*k) - lower.total_variance(*k))
+                            .min(0.0)
+                            .powi(2);
+                }
+                penalty += (slice.total_variance(*k) - w).powi(2);
+            }
+            penalty
+        };
+
+        let minimum_variance = quotes
+            .iter()
+            .map(|quote| quote.1)
+            .fold(f64::INFINITY, f64::min);
+        let initial = [0.5 * minimum_variance, 0.1f64.ln(), 0.0, 0.0, 0.1f64.ln()];
+
+        let result = NelderMead::new(50_000, 1e-20).minimize(objective, &initial, 0.1);
+        if result.converged {
+            Ok(to_slice(&result.x))
+        } else {
+            Err(SviCalibrationError::NoConvergence)
+        }
+    }
+}
+
+impl NaturalSvi {
+    /// Create a new natural SVI slice.
+    #[must_use]
+    pub const fn new(delta: f64, mu: f64, rho: f64, omega: f64, zeta: f64) -> Self {
+        Self {
+            delta,
+            mu,
+            rho,
+            omega,
+            zeta,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_delta(&self) -> f64 {
+        self.delta
+    }
+
+    #[must_use]
+    pub const fn get_mu(&self) -> f64 {
+        self.mu
+    }
+
+    #[must_use]
+    pub const fn get_rho(&self) -> f64 {
+        self.rho
+    }
+
+    #[must_use]
+    pub const fn get_omega(&self) -> f64 {
+        self.omega
+    }
+
+    #[must_use]
+    pub const fn get_zeta(&self) -> f64 {
+        self.zeta
+    }
+}
+
+impl From<NaturalSvi> for RawSvi {
+    fn from(natural: NaturalSvi) -> Self {
+        let NaturalSvi {
+            delta,
+            mu,
+            rho,
+            omega,
+            zeta,
+        } = natural;
+        let root = rho.mul_add(-rho, 1.0).sqrt();
+
+        Self::new(
+            (0.5 * omega).mul_add(rho.mul_add(-rho, 1.0), delta),
+            0.5 * omega * zeta,
+            rho,
+            mu - rho / zeta,
+            root / zeta,
+        )
+    }
+}
+
+impl From<RawSvi> for NaturalSvi {
+    fn from(raw: RawSvi) -> Self {
+        let RawSvi {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        } = raw;
+        let root = rho.mul_add(-rho, 1.0).sqrt();
+        let zeta = root / sigma;
+        let omega = 2.0 * b * sigma / root;
+
+        Self {
+            delta: (0.5 * omega).mul_add(-rho.mul_add(-rho, 1.0), a),
+            mu: m + rho / zeta,
+            rho,
+            omega,
+            zeta,
+        }
+    }
+}
+
+impl JumpWingsSvi {
+    /// Create a new jump-wings SVI slice.
+    #[must_use]
+    pub const fn new(v: f64, psi: f64, p: f64, c: f64, v_tilde: f64) -> Self {
+        Self {
+            v,
+            psi,
+            p,
+            c,
+            v_tilde,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_v(&self) -> f64 {
+        self.v
+    }
+
+    #[must_use]
+    pub const fn get_psi(&self) -> f64 {
+        self.psi
+    }
+
+    #[must_use]
+    pub const fn get_p(&self) -> f64 {
+        self.p
+    }
+
+    #[must_use]
+    pub const fn get_c(&self) -> f64 {
+        self.c
+    }
+
+    #[must_use]
+    pub const fn get_v_tilde(&self) -> f64 {
+        self.v_tilde
+    }
+
+    /// Express a raw SVI slice at expiry `tau` in jump-wings form.
+    #[must_use]
+    pub fn from_raw(raw: &RawSvi, tau: f64) -> Self {
+        let RawSvi {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        } = *raw;
+        let w = raw.total_variance(0.0);
+        let sqrt_w = w.sqrt();
+
+        Self {
+            v: w / tau,
+            psi: 0.5 * b / sqrt_w * (rho - m / m.hypot(sigma)),
+            p: b * (1.0 - rho) / sqrt_w,
+            c: b * (1.0 + rho) / sqrt_w,
+            v_tilde: (b * sigma).mul_add(rho.mul_add(-rho, 1.0).sqrt(), a) / tau,
+        }
+    }
+
+    /// Convert to a raw SVI slice at expiry `tau`.
+    #[must_use]
+    pub fn to_raw(&self, tau: f64) -> RawSvi {
+        let w = self.v * tau;
+        let sqrt_w = w.sqrt();
+
+        let b = 0.5 * sqrt_w * (self.c + self.p);
+        let rho = 1.0 - self.p * sqrt_w / b;
+        let beta = rho - 2.0 * self.psi * sqrt_w / b;
+        let root = rho.mul_add(-rho, 1.0).sqrt();
+
+        let (m, sigma) = if beta.abs() < f64::EPSILON {
+            // The minimum is at the money: the wings and the minimum variance fix the vertex.
+            (0.0, (self.v - self.v_tilde) * tau / (b * (1.0 - root)))
+        } else {
+            let alpha = beta.signum() * (1.0 / (beta * beta) - 1.0).sqrt();
+            let m = (self.v - self.v_tilde) * tau
+                / (b * (alpha.signum().mul_add(alpha.hypot(1.0), -rho) - alpha * root));
+            (m, alpha * m)
+        };
+
+        RawSvi::new(
+            (b * sigma).mul_add(-root, self.v_tilde * tau),
+            b,
+            rho,
+            m,
+            sigma,
+        )
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    // Axel Vogt's example of a raw SVI slice with butterfly arbitrage (Gatheral & Jacquier, 2014).
+    const VOGT: RawSvi = RawSvi::new(-0.0410, 0.1331, 0.3060, 0.3586, 0.4153);
+
+    #[test]
+    fn test_svi_parameterisations_round_trip() {
+        let raw = RawSvi::new(0.04, 0.4, -0.4, 0.05, 0.2);
+        let tau = 0.5;
+
+        let natural = NaturalSvi::from(raw);
+        let jump_wings = JumpWingsSvi::from_raw(&raw, tau);
+        assert_approx_eq!(jump_wings.get_v() * tau, raw.total_variance(0.0), 10e-12);
+        assert_approx_eq!(natural.get_rho(), raw.get_rho(), 10e-12);
+        for converted in [RawSvi::from(natural), jump_wings.to_raw(tau)] {
+            for k in [-1.0, -0.2, 0.0, 0.3, 1.0] {
+                assert_approx_eq!(converted.total_variance(k), raw.total_variance(k), 10e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_svi_butterfly_arbitrage() {
+        let grid: Vec<f64> = (-30..=30).map(|i| f64::from(i) * 0.05).collect();
+
+        assert!(!VOGT.is_butterfly_free(&grid));
+        assert!(RawSvi::new(0.04, 0.4, -0.4, 0.05, 0.2).is_butterfly_free(&grid));
+    }
+
+    #[test]
+    fn test_svi_calibration() {
+        let raw = RawSvi::new(0.02, 0.15, -0.5, 0.05, 0.15);
+        let quotes: Vec<(f64, f64)> = (-8..=8)
+            .map(|i| f64::from(i) * 0.1)
+            .map(|k| (k, raw.total_variance(k)))
+            .collect();
+
+        let calibrated = RawSvi::calibrate(&quotes, None).unwrap();
+        for (k, w) in &quotes {
+            assert_approx_eq!(calibrated.total_variance(*k), w, 10e-6);
+        }
+
+        assert_eq!(
+            RawSvi::calibrate(&quotes[..4], None),
+            Err(SviCalibrationError::NotEnoughQuotes)
+        );
+    }
+}
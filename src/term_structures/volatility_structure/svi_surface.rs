@@ -0,0 +1,443 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::term_structures::volatility_structure::forward_interpolation::interpolate_forward;
+use crate::term_structures::volatility_structure::svi::RawSvi;
+use crate::term_structures::volatility_structure::{
+    BlackVolatilityTermStructure, SviCalibrationError, VolatilityTermStructure,
+};
+use crate::term_structures::TermStructure;
+use crate::term_structures::TermStructureStrikeValidity;
+use crate::time::DateTime;
+use crate::types::{Strike, Volatility};
+
+use super::black_volatility_term_structure::BlackVolatilityTermStructureResult;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Implied volatility surface made of SVI slices.
+///
+/// Each slice gives total variance as a function of log-moneyness $k = \ln(K / F_\tau)$ at its
+/// expiry. Between slices total variance is interpolated linearly in time at constant
+/// log-moneyness; before the first and after the last slice it is scaled proportionally to time.
+/// Forwards are interpolated log-linearly in time from the spot and the slice forwards.
+pub struct SviSurface<D>
+where
+    D: DayCounter,
+{
+    spot: f64,
+
+    /// Slices as `(expiry, time to expiry, forward, slice)`, sorted by expiry.
+    slices: Vec<(DateTime, f64, f64, RawSvi)>,
+
+    reference_date: DateTime,
+
+    day_count_convention: D,
+}
+
+#[derive(Debug, Clone)]
+enum SviSliceInput {
+    Parameterised(RawSvi),
+    Quoted(Vec<(Strike, Volatility)>),
+}
+
+#[derive(Debug)]
+pub struct SviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    spot: Option<f64>,
+    slices: Vec<(DateTime, f64, SviSliceInput)>,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SviSurfaceBuilderError {
+    NoSpotProvided,
+    NoSlicesProvided,
+    NonPositiveForward,
+
+    /// An expiry is not after the reference date, or its time to expiry is not positive.
+    ExpiryNotAfterReferenceDate,
+
+    /// Two expiries have the same time to expiry.
+    DuplicateExpiry,
+
+    InvalidSlice,
+    Calibration(SviCalibrationError),
+}
+
+impl<D> SviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            spot: None,
+            slices: Vec::new(),
+            reference_date: None,
+            day_count_convention: None,
+        }
+    }
+
+    pub fn spot(&mut self, spot: f64) -> &mut Self {
+        self.spot = Some(spot);
+        self
+    }
+
+    /// Add an SVI slice at an expiry, with the forward for that expiry.
+    ///
+    /// Jump-wings slices depend on the time to expiry and should be converted with
+    /// [`super::JumpWingsSvi::to_raw`] first.
+    pub fn slice(&mut self, expiry: DateTime, forward: f64, slice: impl Into<RawSvi>) -> &mut Self {
+        self.slices
+            .push((expiry, forward, SviSliceInput::Parameterised(slice.into())));
+        self
+    }
+
+    /// Add market `(strike, volatility)` quotes at an expiry, to which a raw SVI slice is
+    /// calibrated when the surface is built.
+    ///
+    /// Slices are calibrated in order of expiry, each one constrained to lie above the previous
+    /// one so that the surface is free of calendar arbitrage.
+    pub fn quotes(
+        &mut self,
+        expiry: DateTime,
+        forward: f64,
+        quotes: &[(Strike, Volatility)],
+    ) -> &mut Self {
+        self.slices
+            .push((expiry, forward, SviSliceInput::Quoted(quotes.to_vec())));
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    pub fn build(&self) -> Result<SviSurface<D>, SviSurfaceBuilderError> {
+        let spot = self.spot.ok_or(SviSurfaceBuilderError::NoSpotProvided)?;
+        if self.slices.is_empty() {
+            return Err(SviSurfaceBuilderError::NoSlicesProvided);
+        }
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+        let day_count_convention = self.day_count_convention.unwrap_or_default();
+
+        let mut inputs = self.slices.clone();
+        inputs.sort_by_key(|input| input.0);
+
+        let mut slices: Vec<(DateTime, f64, f64, RawSvi)> = Vec::with_capacity(inputs.len());
+        for (expiry, forward, input) in inputs {
+            if forward <= 0.0 {
+                return Err(SviSurfaceBuilderError::NonPositiveForward);
+            }
+            let time = day_count_convention
+                .day_count_fraction(&reference_date, &expiry)
+                .get_fraction();
+            if expiry <= reference_date || time <= 0.0 {
+                return Err(SviSurfaceBuilderError::ExpiryNotAfterReferenceDate);
+            }
+            if slices.last().is_some_and(|last| last.1 >= time) {
+                return Err(SviSurfaceBuilderError::DuplicateExpiry);
+            }
+
+            let slice = match input {
+                SviSliceInput::Parameterised(slice) => slice,
+                SviSliceInput::Quoted(quotes) => {
+                    let total_variances: Vec<(f64, f64)> = quotes
+                        .iter()
+                        .map(|(strike, volatility)| {
+                            (
+                                (**strike / forward).ln(),
+                                **volatility * **volatility * time,
+                            )
+                        })
+                        .collect();
+
+                    // Calendar arbitrage is measured at constant log-moneyness.
+                    let lower_bound = slices.last().map(|previous| previous.3);
+
+                    RawSvi::calibrate(&total_variances, lower_bound.as_ref())
+                        .map_err(SviSurfaceBuilderError::Calibration)?
+                }
+            };
+            if !slice.is_valid() {
+                return Err(SviSurfaceBuilderError::InvalidSlice);
+            }
+
+            slices.push((expiry, time, forward, slice));
+        }
+
+        Ok(SviSurface {
+            spot,
+            slices,
+            reference_date,
+            day_count_convention,
+        })
+    }
+}
+
+impl<D> Default for SviSurfaceBuilder<D>
+where
+    D: DayCounter,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> SviSurface<D>
+where
+    D: DayCounter,
+{
+    /// The SVI slices of the surface, as `(expiry, forward, slice)` sorted by expiry.
+    #[must_use]
+    pub fn get_slices(&self) -> Vec<(DateTime, f64, RawSvi)> {
+        self.slices
+            .iter()
+            .map(|(expiry, _, forward, slice)| (*expiry, *forward, *slice))
+            .collect()
+    }
+
+    /// Whether every slice is free of butterfly arbitrage on the given log-moneyness points.
+    #[must_use]
+    pub fn is_butterfly_free(&self, log_moneyness: &[f64]) -> bool {
+        self.slices
+            .iter()
+            .all(|(_, _, _, slice)| slice.is_butterfly_free(log_moneyness))
+    }
+
+    /// Whether total variance is non-decreasing in expiry at the given log-moneyness points, i.e.
+    /// the surface is free of calendar arbitrage.
+    #[must_use]
+    pub fn is_calendar_free(&self, log_moneyness: &[f64]) -> bool {
+        self.slices.windows(2).all(|pair| {
+            log_moneyness
+                .iter()
+                .all(|&k| pair[1].3.total_variance(k) >= pair[0].3.total_variance(k) - f64::EPSILON)
+        })
+    }
+
+    /// Forward at a time to maturity.
+    fn forward(&self, t: f64) -> f64 {
+        let forwards: Vec<(f64, f64)> = self
+            .slices
+            .iter()
+            .map(|(_, time, forward, _)| (*time, *forward))
+            .collect();
+        interpolate_forward(self.spot, &forwards, t)
+    }
+
+    /// Total variance at a time to maturity and log-moneyness.
+    fn total_variance(&self, t: f64, k: f64) -> f64 {
+        let index = self.slices.partition_point(|(_, time, _, _)| *time < t);
+
+        if index == 0 {
+            let (_, time, _, slice) = &self.slices[0];
+            return slice.total_variance(k) * t / time;
+        }
+        if index == self.slices.len() {
+            let (_, time, _, slice) = &self.slices[index - 1];
+            return slice.total_variance(k) * t / time;
+        }
+
+        let (_, t1, _, slice1) = &self.slices[index - 1];
+        let (_, t2, _, slice2) = &self.slices[index];
+        let w1 = slice1.total_variance(k);
+        let w2 = slice2.total_variance(k);
+        (w2 - w1).mul_add((t - t1) / (t2 - t1), w1)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<D> TermStructure<D> for SviSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<D> VolatilityTermStructure<D> for SviSurface<D>
+where
+    D: DayCounter,
+{
+    fn get_min_max_strike(&self) -> (Strike, Strike) {
+        (OrderedFloat(f64::MIN_POSITIVE), OrderedFloat(f64::MAX))
+    }
+
+    fn validate_strike(&self, strike: Strike) -> TermStructureStrikeValidity {
+        if *strike > 0.0 {
+            TermStructureStrikeValidity::Valid
+        } else {
+            TermStructureStrikeValidity::Invalid
+        }
+    }
+}
+
+impl<D> BlackVolatilityTermStructure<D> for SviSurface<D>
+where
+    D: DayCounter,
+{
+    fn black_volatility(
+        &self,
+        maturity: DateTime,
+        strike: Strike,
+    ) -> BlackVolatilityTermStructureResult {
+        if !self.is_datetime_valid(maturity)
+            || self.validate_strike(strike) == TermStructureStrikeValidity::Invalid
+        {
+            return BlackVolatilityTermStructureResult::OutOfRange;
+        }
+
+        // At the reference date use the limit of the first slice.
+        let t = self
+            .time_from_reference(maturity)
+            .max(self.slices[0].1 * 1e-6);
+        let k = (*strike / self.forward(t)).ln();
+        let volatility = OrderedFloat((self.total_variance(t, k) / t).sqrt());
+
+        if self
+            .slices
+            .iter()
+            .any(|(expiry, _, _, _)| *expiry == maturity)
+        {
+            BlackVolatilityTermStructureResult::ExistingValue(volatility)
+        } else {
+            BlackVolatilityTermStructureResult::InterpolatedValue(volatility)
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::term_structures::volatility_structure::NaturalSvi;
+
+    fn grid() -> Vec<f64> {
+        (-30..=30).map(|i| f64::from(i) * 0.05).collect()
+    }
+
+    #[test]
+    fn test_svi_surface_interpolation() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let short = RawSvi::new(0.01, 0.1, -0.4, 0.0, 0.1);
+        let long = RawSvi::new(0.04, 0.2, -0.4, 0.0, 0.2);
+
+        let surface = SviSurfaceBuilder::<Actual365Fixed>::new()
+            .spot(100.0)
+            .slice(DateTime::new_from_ymd(2024, 1, 1), 100.0, short)
+            .slice(
+                DateTime::new_from_ymd(2025, 1, 1),
+                100.0,
+                NaturalSvi::from(long),
+            )
+            .reference_date(reference_date)
+            .build()
+            .unwrap();
+
+        assert!(surface.is_butterfly_free(&grid()));
+        assert!(surface.is_calendar_free(&grid()));
+
+        let volatility = |maturity, strike: f64| match surface
+            .black_volatility(maturity, OrderedFloat(strike))
+        {
+            BlackVolatilityTermStructureResult::ExistingValue(v)
+            | BlackVolatilityTermStructureResult::InterpolatedValue(v) => *v,
+            _ => panic!("Expected a volatility."),
+        };
+
+        let k = 110.0f64.ln() - 100.0f64.ln();
+        assert_approx_eq!(
+            volatility(DateTime::new_from_ymd(2024, 1, 1), 110.0),
+            short.total_variance(k).sqrt(),
+            10e-12
+        );
+
+        // Halfway between the slices (in days) total variance is the average.
+        let t = surface.time_from_reference(DateTime::new_from_ymd(2024, 7, 2));
+        let expected = (0.5 * (short.total_variance(k) + long.total_variance(k)) / t).sqrt();
+        assert_approx_eq!(
+            volatility(DateTime::new_from_ymd(2024, 7, 2), 110.0),
+            expected,
+            10e-12
+        );
+    }
+
+    #[test]
+    fn test_svi_surface_calibration() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let expiries = [
+            (DateTime::new_from_ymd(2023, 7, 1), 101.0),
+            (DateTime::new_from_ymd(2024, 1, 1), 102.0),
+        ];
+        let slices = [
+            RawSvi::new(0.01, 0.08, -0.6, 0.02, 0.1),
+            RawSvi::new(0.02, 0.1, -0.5, 0.03, 0.15),
+        ];
+
+        let mut builder = SviSurfaceBuilder::<Actual365Fixed>::new();
+        builder.spot(100.0).reference_date(reference_date);
+        for ((expiry, forward), slice) in expiries.iter().zip(&slices) {
+            let time = Actual365Fixed
+                .day_count_fraction(&reference_date, expiry)
+                .get_fraction();
+            let quotes: Vec<(Strike, Volatility)> = (-6..=6)
+                .map(|i| forward * (f64::from(i) * 0.1).exp())
+                .map(|strike| {
+                    let w = slice.total_variance((strike / forward).ln());
+                    (OrderedFloat(strike), OrderedFloat((w / time).sqrt()))
+                })
+                .collect();
+            builder.quotes(*expiry, *forward, &quotes);
+        }
+        let surface = builder.build().unwrap();
+
+        for ((expiry, forward), slice) in expiries.iter().zip(&slices) {
+            let time = surface.time_from_reference(*expiry);
+            for strike in [80.0, 100.0, 120.0] {
+                let expected = (slice.total_variance((strike / forward).ln()) / time).sqrt();
+                match surface.black_volatility(*expiry, OrderedFloat(strike)) {
+                    BlackVolatilityTermStructureResult::ExistingValue(v) => {
+                        assert_approx_eq!(*v, expected, 10e-5);
+                    }
+                    _ => panic!("Expected an existing value."),
+                }
+            }
+        }
+        assert!(surface.is_calendar_free(&grid()));
+    }
+}
@@ -0,0 +1,143 @@
+use day_count_conventions::DayCounter;
+
+use crate::interest_rate::InterestRate;
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    implied_forward_rate, implied_zero_rate, YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::DateTime;
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Curve whose continuously-compounded zero rates and instantaneous forwards are the sums of
+/// those of two curves, $D(t) = D_1(t) D_2(t)$.
+///
+/// Typically a base curve and a spread curve, such as a risk-free curve and a credit curve. The
+/// reference date and day count convention are those of the first curve.
+pub struct CompositeTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    first: &'a dyn YieldTermStructure<C, D>,
+    second: &'a dyn YieldTermStructure<C, D>,
+}
+
+impl<'a, C, D> CompositeTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    #[must_use]
+    pub const fn new(
+        first: &'a dyn YieldTermStructure<C, D>,
+        second: &'a dyn YieldTermStructure<C, D>,
+    ) -> Self {
+        Self { first, second }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D> TermStructure<D> for CompositeTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.first.get_reference_date()
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.first
+            .get_max_datetime()
+            .min(self.second.get_max_datetime())
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        self.first.is_datetime_valid(dt) && self.second.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.first.get_day_counter()
+    }
+}
+
+impl<C, D> YieldTermStructure<C, D> for CompositeTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        if !self.is_datetime_valid(t) {
+            return Err(TermStructureError::InvalidDateTime);
+        }
+
+        Ok(self.first.discount_factor(t)? * self.second.discount_factor(t)?)
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::money::currency::USD;
+    use crate::term_structures::flat_curve;
+
+    #[test]
+    fn test_composite_term_structure() {
+        let (base, spread) = (
+            flat_curve::<USD, Actual365Fixed>(
+                DateTime::new_from_ymd(2024, 1, 1),
+                0.03,
+                Compounding::Continuous,
+            ),
+            flat_curve(
+                DateTime::new_from_ymd(2024, 1, 1),
+                0.012,
+                Compounding::Continuous,
+            ),
+        );
+        let curve = CompositeTermStructure::new(&base, &spread);
+
+        let date = DateTime::new_from_ymd(2027, 1, 1);
+        assert_approx_eq!(*curve.zero_rate(date).unwrap().get_rate(), 0.042, 10e-10);
+        assert_approx_eq!(
+            *curve
+                .forward_rate(date, DateTime::new_from_ymd(2030, 1, 1))
+                .unwrap()
+                .get_rate(),
+            0.042,
+            10e-10
+        );
+        assert_eq!(
+            curve.discount_factor(DateTime::new_from_ymd(2023, 1, 1)),
+            Err(TermStructureError::InvalidDateTime)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use day_count_conventions::DayCounter;
+
+use crate::money::Currency;
+use crate::term_structures::yield_structure::YieldTermStructure;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Set of curves for multi-curve pricing in one currency: a discount curve, usually the OIS
+/// curve of the collateral rate (e.g. SOFR or ESTR), and one projection curve per floating
+/// index (e.g. a 3M term rate), keyed by index name.
+///
+/// The discount index is projected off the discount curve. Projection curves for the other
+/// indices are typically bootstrapped with a [`PiecewiseYieldCurveBuilder`] from swap helpers
+/// discounted on the discount curve, see [`SwapRateHelper::with_discount_curve`].
+///
+/// [`PiecewiseYieldCurveBuilder`]: crate::term_structures::PiecewiseYieldCurveBuilder
+/// [`SwapRateHelper::with_discount_curve`]: crate::term_structures::SwapRateHelper::with_discount_curve
+pub struct CurveSet<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    discount_index: String,
+    discount_curve: &'a dyn YieldTermStructure<C, D>,
+    projection_curves: HashMap<String, &'a dyn YieldTermStructure<C, D>>,
+}
+
+impl<'a, C, D> CurveSet<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `CurveSet` discounting on the curve of the given overnight index.
+    #[must_use]
+    pub fn new(discount_index: &str, discount_curve: &'a dyn YieldTermStructure<C, D>) -> Self {
+        Self {
+            discount_index: discount_index.to_string(),
+            discount_curve,
+            projection_curves: HashMap::new(),
+        }
+    }
+
+    /// Add the projection curve of an index, replacing any previous one.
+    pub fn add_projection_curve(
+        &mut self,
+        index: &str,
+        projection_curve: &'a dyn YieldTermStructure<C, D>,
+    ) -> &mut Self {
+        let _ = self
+            .projection_curves
+            .insert(index.to_string(), projection_curve);
+        self
+    }
+
+    #[must_use]
+    pub fn get_discount_index(&self) -> &str {
+        &self.discount_index
+    }
+
+    #[must_use]
+    pub fn get_discount_curve(&self) -> &'a dyn YieldTermStructure<C, D> {
+        self.discount_curve
+    }
+
+    /// The projection curve of an index, if any.
+    #[must_use]
+    pub fn get_projection_curve(&self, index: &str) -> Option<&'a dyn YieldTermStructure<C, D>> {
+        if index == self.discount_index {
+            return Some(self.discount_curve);
+        }
+        self.projection_curves.get(index).copied()
+    }
+
+    /// The indices with a projection curve, including the discount index, sorted.
+    #[must_use]
+    pub fn get_indices(&self) -> Vec<&str> {
+        let mut indices: Vec<&str> = self
+            .projection_curves
+            .keys()
+            .map(String::as_str)
+            .chain(std::iter::once(self.discount_index.as_str()))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed};
+
+    use super::*;
+    use crate::{
+        math::interpolation::LinearInterpolator,
+        money::{currency::USD, Money},
+        term_structures::{
+            DepositRateHelper, FraRateHelper, PiecewiseYieldCurveBuilder, RateHelper,
+            SwapRateHelper,
+        },
+        time::{DateTime, Frequency},
+    };
+
+    #[test]
+    fn test_curve_set_dual_curve_bootstrap() {
+        let spot = DateTime::new_from_ymd(2024, 1, 2);
+
+        // SOFR discount curve from overnight-indexed swaps, single curve.
+        let ois_quotes = [(12, 0.050), (24, 0.045), (60, 0.040), (120, 0.038)];
+        let ois_helpers: Vec<SwapRateHelper<USD, Actual365Fixed, _>> = ois_quotes
+            .iter()
+            .map(|(months, rate)| {
+                SwapRateHelper::new(
+                    *rate,
+                    spot,
+                    spot.add_months(*months),
+                    Frequency::Annual,
+                    Actual360,
+                )
+            })
+            .collect();
+        let mut builder = PiecewiseYieldCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .reference_date(spot)
+            .day_count_convention(Actual365Fixed);
+        for helper in &ois_helpers {
+            builder.helper(helper);
+        }
+        let sofr_curve = builder.build().unwrap();
+
+        // 3M term projection curve, with swaps discounted on the SOFR curve.
+        let deposit = DepositRateHelper::new(0.053, spot, spot.add_months(3), Actual360);
+        let term_quotes = [(24, 0.048), (60, 0.043), (120, 0.041)];
+        let term_helpers: Vec<SwapRateHelper<USD, Actual365Fixed, _>> = term_quotes
+            .iter()
+            .map(|(months, rate)| {
+                SwapRateHelper::new(
+                    *rate,
+                    spot,
+                    spot.add_months(*months),
+                    Frequency::Semiannual,
+                    Actual360,
+                )
+                .with_discount_curve(&sofr_curve, Frequency::Quarterly)
+            })
+            .collect();
+        let mut builder = PiecewiseYieldCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .reference_date(spot)
+            .day_count_convention(Actual365Fixed)
+            .helper(&deposit);
+        for helper in &term_helpers {
+            builder.helper(helper);
+        }
+        let term_curve = builder.build().unwrap();
+
+        let mut curves = CurveSet::new("SOFR", &sofr_curve);
+        curves.add_projection_curve("TERM-SOFR-3M", &term_curve);
+        assert_eq!(curves.get_indices(), ["SOFR", "TERM-SOFR-3M"]);
+        assert!(curves.get_projection_curve("ESTR").is_none());
+
+        // Par swaps are worth zero with projection and discounting on separate curves.
+        let notional = Money::<USD>::new(1_000_000.0);
+        let projection = curves.get_projection_curve("TERM-SOFR-3M").unwrap();
+        for helper in &term_helpers {
+            assert_approx_eq!(
+                helper.implied_quote(projection).unwrap(),
+                helper.quote(),
+                10e-10
+            );
+            assert_approx_eq!(
+                *helper
+                    .npv(notional, projection, curves.get_discount_curve())
+                    .unwrap()
+                    .amount(),
+                0.0,
+                10e-4
+            );
+        }
+
+        // The same swaps repriced on a single curve are off market.
+        let single_curve_swap = SwapRateHelper::new(
+            0.043,
+            spot,
+            spot.add_months(60),
+            Frequency::Semiannual,
+            Actual360,
+        );
+        assert!(
+            (RateHelper::<USD, Actual365Fixed>::implied_quote(&single_curve_swap, projection)
+                .unwrap()
+                - 0.043)
+                .abs()
+                > 10e-6
+        );
+
+        // A FRA at the projected forward is worth zero whatever the discount curve.
+        let (start, end) = (spot.add_months(6), spot.add_months(9));
+        let forward = RateHelper::<USD, Actual365Fixed>::implied_quote(
+            &FraRateHelper::new(0.0, start, end, Actual360),
+            projection,
+        )
+        .unwrap();
+        let fra = FraRateHelper::new(forward, start, end, Actual360);
+        assert_approx_eq!(
+            *fra.npv(notional, projection, curves.get_discount_curve())
+                .unwrap()
+                .amount(),
+            0.0,
+            10e-8
+        );
+        let off_market = FraRateHelper::new(forward - 0.01, start, end, Actual360);
+        let tau = Actual360.day_count_fraction(&start, &end).get_fraction();
+        assert_approx_eq!(
+            *off_market
+                .npv(notional, projection, curves.get_discount_curve())
+                .unwrap()
+                .amount(),
+            1_000_000.0 * tau * 0.01 / tau.mul_add(forward, 1.0)
+                * *sofr_curve.discount_factor(start).unwrap(),
+            10e-6
+        );
+    }
+}
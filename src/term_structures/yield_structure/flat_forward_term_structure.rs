@@ -6,7 +6,6 @@ use crate::time::DateTime;
 use crate::types::DiscountFactor;
 
 use day_count_conventions::{DayCountFraction, DayCounter};
-use ordered_float::OrderedFloat;
 
 pub struct FlatForwardTermStructure<C, D>
 where
@@ -112,15 +111,7 @@ where
             return Err(TermStructureError::InvalidDateTime);
         }
 
-        let mut year_fraction = self
-            .rate
-            .get_day_counter()
-            .day_count_fraction(&self.reference_date, &t);
-        if year_fraction.get_fraction() == 0.0 {
-            year_fraction = DayCountFraction::new(10e-8);
-        }
-
-        Ok(self.rate.discount_factor(&year_fraction))
+        Ok(self.rate.discount_factor_between(self.reference_date, t))
     }
 
     fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
@@ -138,7 +129,7 @@ where
         if day_count_fraction.get_fraction() == 0.0 {
             day_count_fraction = DayCountFraction::new(10e-8);
         }
-        let compound_factor = OrderedFloat(1.0) / self.discount_factor(t).unwrap();
+        let compound_factor = self.rate.compound_factor(&day_count_fraction);
 
         let compounding = self.rate.get_compounding();
 
@@ -191,6 +182,24 @@ where
     }
 }
 
+/// Flat curve at a rate in the day count convention `D`, as a fixture for tests.
+#[cfg(test)]
+pub(crate) fn flat_curve<C, D>(
+    reference_date: DateTime,
+    rate: f64,
+    compounding: crate::Compounding,
+) -> FlatForwardTermStructure<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    FlatForwardTermStructureBuilder::new()
+        .reference_date(reference_date)
+        .rate(InterestRate::new(rate, D::default(), compounding))
+        .build()
+        .unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use assert_approx_eq::assert_approx_eq;
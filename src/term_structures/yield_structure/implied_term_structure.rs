@@ -0,0 +1,152 @@
+use day_count_conventions::DayCounter;
+
+use crate::interest_rate::InterestRate;
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    implied_forward_rate, implied_zero_rate, YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::DateTime;
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Curve implied by an underlying curve at a later reference date $t_0$, with the forward
+/// discount factors $D(t) = D_0(t) / D_0(t_0)$.
+///
+/// This is the curve expected at $t_0$ if the forward rates of the underlying curve are
+/// realised.
+pub struct ImpliedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    curve: &'a dyn YieldTermStructure<C, D>,
+    reference_date: DateTime,
+}
+
+impl<'a, C, D> ImpliedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `ImpliedTermStructure`, rebasing a curve to a reference date that should be
+    /// valid for it.
+    #[must_use]
+    pub const fn new(curve: &'a dyn YieldTermStructure<C, D>, reference_date: DateTime) -> Self {
+        Self {
+            curve,
+            reference_date,
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D> TermStructure<D> for ImpliedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.curve.get_max_datetime()
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && self.curve.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.curve.get_day_counter()
+    }
+}
+
+impl<C, D> YieldTermStructure<C, D> for ImpliedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        if !self.is_datetime_valid(t) {
+            return Err(TermStructureError::InvalidDateTime);
+        }
+
+        Ok(self.curve.discount_factor(t)? / self.curve.discount_factor(self.reference_date)?)
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        math::interpolation::LinearInterpolator,
+        money::currency::USD,
+        term_structures::{InterpolatedZeroCurve, InterpolatedZeroCurveBuilder},
+    };
+
+    #[test]
+    fn test_implied_term_structure() {
+        let reference_date = DateTime::new_from_ymd(2024, 1, 1);
+        let curve: InterpolatedZeroCurve<USD, Actual365Fixed, _> =
+            InterpolatedZeroCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[
+                    (DateTime::new_from_ymd(2025, 1, 1), 0.03),
+                    (DateTime::new_from_ymd(2034, 1, 1), 0.04),
+                ])
+                .reference_date(reference_date)
+                .build()
+                .unwrap();
+
+        let rebased_date = DateTime::new_from_ymd(2026, 1, 1);
+        let implied = ImpliedTermStructure::new(&curve, rebased_date);
+        assert_approx_eq!(*implied.discount_factor(rebased_date).unwrap(), 1.0);
+
+        // Forward rates are unchanged, and zero rates are the original forwards.
+        let (t1, t2) = (
+            DateTime::new_from_ymd(2028, 1, 1),
+            DateTime::new_from_ymd(2031, 1, 1),
+        );
+        assert_approx_eq!(
+            *implied.forward_rate(t1, t2).unwrap().get_rate(),
+            *curve.forward_rate(t1, t2).unwrap().get_rate(),
+            10e-12
+        );
+        assert_approx_eq!(
+            *implied.zero_rate(t2).unwrap().get_rate(),
+            *curve.forward_rate(rebased_date, t2).unwrap().get_rate(),
+            10e-12
+        );
+        assert_eq!(
+            implied.discount_factor(DateTime::new_from_ymd(2025, 1, 1)),
+            Err(TermStructureError::InvalidDateTime)
+        );
+    }
+}
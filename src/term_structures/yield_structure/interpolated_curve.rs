@@ -0,0 +1,610 @@
+use std::marker::PhantomData;
+
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::interest_rate::InterestRate;
+use crate::math::interpolation::{InterpolationResult, Interpolator};
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    extrapolate_log_discount, implied_forward_rate, implied_zero_rate, YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::{DateTime, Duration};
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Yield curve interpolating a quantity `Q` in time between pillar dates: discount factors
+/// ([`Discount`]), zero rates ([`ZeroYield`]) or instantaneous forward rates ([`ForwardRate`]).
+pub struct InterpolatedCurve<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+    Q: InterpolatedQuantity,
+{
+    interpolator: I,
+
+    /// Pillar dates, sorted.
+    pillars: Vec<DateTime>,
+
+    reference_date: DateTime,
+    day_count_convention: D,
+    _currency: PhantomData<C>,
+    _quantity: PhantomData<Q>,
+}
+
+/// Yield curve interpolating the log discount factors $\ln D(t)$, so that a
+/// [`LinearInterpolator`] gives log-linear discount factors (piecewise flat forwards).
+///
+/// The reference date is a node with $D = 1$. Beyond the last pillar the instantaneous forward
+/// rate at the last pillar is extended flat, as for the other interpolated curves.
+///
+/// [`LinearInterpolator`]: crate::math::interpolation::LinearInterpolator
+pub type InterpolatedDiscountCurve<C, D, I> = InterpolatedCurve<C, D, I, Discount>;
+
+/// Yield curve interpolating continuously-compounded zero rates $r(t)$, with
+/// $D(t) = e^{-r(t) t}$.
+///
+/// Before the first pillar the zero rate is flat. Beyond the last pillar the instantaneous
+/// forward rate at the last pillar is extended flat, as for the other interpolated curves.
+pub type InterpolatedZeroCurve<C, D, I> = InterpolatedCurve<C, D, I, ZeroYield>;
+
+/// Yield curve interpolating continuously-compounded instantaneous forward rates $f(t)$, with
+/// $$
+///     D(t) = \exp\left(-\int_0^t f(s) \, ds\right).
+/// $$
+///
+/// The forward rate is flat before the first pillar and beyond the last one. The integral is
+/// computed with Simpson's rule between consecutive pillars, which is exact for linear and cubic
+/// spline interpolation.
+pub type InterpolatedForwardCurve<C, D, I> = InterpolatedCurve<C, D, I, ForwardRate>;
+
+/// Quantity interpolated between the pillars of an [`InterpolatedCurve`].
+pub trait InterpolatedQuantity: Sized {
+    /// Check a quoted value at a pillar date.
+    ///
+    /// # Errors
+    /// Will return an error if the value is not valid for the quantity.
+    fn validate(
+        _date: DateTime,
+        _value: f64,
+        _reference_date: DateTime,
+    ) -> Result<(), InterpolatedCurveBuilderError> {
+        Ok(())
+    }
+
+    /// Value interpolated at a node for a quoted value.
+    #[must_use]
+    fn node_value(value: f64) -> f64 {
+        value
+    }
+
+    /// Node value at the reference date, if the quantity has one there.
+    #[must_use]
+    fn reference_node_value() -> Option<f64> {
+        None
+    }
+
+    /// Log discount factor of a curve at a datetime not before its reference date.
+    fn log_discount<C, D, I>(curve: &InterpolatedCurve<C, D, I, Self>, dt: DateTime) -> f64
+    where
+        C: Currency,
+        D: DayCounter,
+        I: Interpolator<DateTime, OrderedFloat<f64>>;
+}
+
+/// Discount factors, interpolated as $\ln D(t)$.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Discount;
+
+/// Continuously-compounded zero rates.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ZeroYield;
+
+/// Continuously-compounded instantaneous forward rates.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ForwardRate;
+
+pub struct InterpolatedCurveBuilder<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+    Q: InterpolatedQuantity,
+{
+    interpolator: I,
+    points: Vec<(DateTime, f64)>,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+    _currency: PhantomData<C>,
+    _quantity: PhantomData<Q>,
+}
+
+/// Builder of an [`InterpolatedDiscountCurve`].
+pub type InterpolatedDiscountCurveBuilder<C, D, I> = InterpolatedCurveBuilder<C, D, I, Discount>;
+
+/// Builder of an [`InterpolatedZeroCurve`].
+pub type InterpolatedZeroCurveBuilder<C, D, I> = InterpolatedCurveBuilder<C, D, I, ZeroYield>;
+
+/// Builder of an [`InterpolatedForwardCurve`].
+pub type InterpolatedForwardCurveBuilder<C, D, I> = InterpolatedCurveBuilder<C, D, I, ForwardRate>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InterpolatedCurveBuilderError {
+    NoPointsProvided,
+    PointBeforeReferenceDate,
+    NonPositiveDiscountFactor,
+
+    /// A discount factor at the reference date is not 1.
+    ReferenceDiscountFactorNotOne,
+}
+
+impl<C, D, I, Q> InterpolatedCurveBuilder<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>> + Clone,
+    Q: InterpolatedQuantity,
+{
+    /// Create a new builder, interpolating the quantity in time with the given interpolator.
+    #[must_use]
+    pub const fn new(interpolator: I) -> Self {
+        Self {
+            interpolator,
+            points: Vec::new(),
+            reference_date: None,
+            day_count_convention: None,
+            _currency: PhantomData,
+            _quantity: PhantomData,
+        }
+    }
+
+    /// Add a quoted value at a pillar date: a discount factor, or a continuously-compounded zero
+    /// or instantaneous forward rate.
+    pub fn add_point(&mut self, point: (DateTime, f64)) -> &mut Self {
+        self.points.push(point);
+        self
+    }
+
+    /// Add quoted values at pillar dates.
+    pub fn add_points(&mut self, points: &[(DateTime, f64)]) -> &mut Self {
+        self.points.extend_from_slice(points);
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    /// Build the curve.
+    ///
+    /// # Errors
+    /// Will return `NoPointsProvided` if there are no points, and `PointBeforeReferenceDate` if
+    /// a pillar is before the reference date. Discount factors must be positive
+    /// (`NonPositiveDiscountFactor`), and 1 at the reference date
+    /// (`ReferenceDiscountFactorNotOne`).
+    pub fn build(&self) -> Result<InterpolatedCurve<C, D, I, Q>, InterpolatedCurveBuilderError> {
+        if self.points.is_empty() {
+            return Err(InterpolatedCurveBuilderError::NoPointsProvided);
+        }
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+        if self.points.iter().any(|point| point.0 < reference_date) {
+            return Err(InterpolatedCurveBuilderError::PointBeforeReferenceDate);
+        }
+        for (date, value) in &self.points {
+            Q::validate(*date, *value, reference_date)?;
+        }
+
+        let mut interpolator = self.interpolator.clone();
+        if let Some(value) = Q::reference_node_value() {
+            let _ = interpolator.add_point((reference_date, OrderedFloat(value)));
+        }
+        let _ = interpolator.add_points(
+            self.points
+                .iter()
+                .map(|(date, value)| (*date, OrderedFloat(Q::node_value(*value))))
+                .collect(),
+        );
+        let mut pillars: Vec<DateTime> = self.points.iter().map(|point| point.0).collect();
+        pillars.sort();
+        pillars.dedup();
+
+        Ok(InterpolatedCurve {
+            interpolator,
+            pillars,
+            reference_date,
+            day_count_convention: self.day_count_convention.unwrap_or_default(),
+            _currency: PhantomData,
+            _quantity: PhantomData,
+        })
+    }
+}
+
+impl<C, D, I, Q> InterpolatedCurve<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+    Q: InterpolatedQuantity,
+{
+    /// The pillar dates of the curve, sorted.
+    #[must_use]
+    pub fn get_pillars(&self) -> &[DateTime] {
+        &self.pillars
+    }
+
+    fn first_pillar(&self) -> DateTime {
+        self.pillars[0]
+    }
+
+    fn last_pillar(&self) -> DateTime {
+        self.pillars[self.pillars.len() - 1]
+    }
+
+    /// Interpolated value at a datetime within the interpolator's range.
+    fn interpolated(&self, dt: DateTime) -> f64 {
+        match self.interpolator.interpolate(dt) {
+            InterpolationResult::ExistingValue(v) | InterpolationResult::InterpolatedValue(v) => *v,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Log discount factor from its values up to the last pillar, extending flat the
+    /// instantaneous forward rate at the last pillar beyond it.
+    fn extrapolated_log_discount(
+        &self,
+        log_discount: impl Fn(DateTime) -> f64,
+        dt: DateTime,
+    ) -> f64 {
+        let last = self.last_pillar();
+        if dt <= last {
+            log_discount(dt)
+        } else {
+            extrapolate_log_discount(
+                log_discount,
+                |date| self.time_from_reference(date),
+                last,
+                dt,
+            )
+        }
+    }
+}
+
+impl<C, D, I> InterpolatedCurve<C, D, I, ForwardRate>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    /// Instantaneous forward rate at a datetime, flat outside the pillars.
+    #[must_use]
+    pub fn instantaneous_forward(&self, dt: DateTime) -> f64 {
+        self.interpolated(dt.clamp(self.first_pillar(), self.last_pillar()))
+    }
+
+    /// $\int_a^b f(s) \, ds$ with Simpson's rule, for $a$ and $b$ in the same pillar segment.
+    fn integrate_segment(&self, a: DateTime, b: DateTime) -> f64 {
+        let seconds = |dt: DateTime| -> f64 { *Into::<OrderedFloat<f64>>::into(dt) };
+        let middle = a + Duration::new_from_seconds(0.5 * (seconds(b) - seconds(a)));
+        let length = self.time_from_reference(b) - self.time_from_reference(a);
+        length / 6.0
+            * 4.0f64.mul_add(
+                self.instantaneous_forward(middle),
+                self.instantaneous_forward(a) + self.instantaneous_forward(b),
+            )
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl InterpolatedQuantity for Discount {
+    fn validate(
+        date: DateTime,
+        value: f64,
+        reference_date: DateTime,
+    ) -> Result<(), InterpolatedCurveBuilderError> {
+        if value <= 0.0 {
+            return Err(InterpolatedCurveBuilderError::NonPositiveDiscountFactor);
+        }
+        if date == reference_date && value != 1.0 {
+            return Err(InterpolatedCurveBuilderError::ReferenceDiscountFactorNotOne);
+        }
+        Ok(())
+    }
+
+    fn node_value(value: f64) -> f64 {
+        value.ln()
+    }
+
+    fn reference_node_value() -> Option<f64> {
+        Some(0.0)
+    }
+
+    fn log_discount<C, D, I>(curve: &InterpolatedCurve<C, D, I, Self>, dt: DateTime) -> f64
+    where
+        C: Currency,
+        D: DayCounter,
+        I: Interpolator<DateTime, OrderedFloat<f64>>,
+    {
+        curve.extrapolated_log_discount(|date| curve.interpolated(date), dt)
+    }
+}
+
+impl InterpolatedQuantity for ZeroYield {
+    fn log_discount<C, D, I>(curve: &InterpolatedCurve<C, D, I, Self>, dt: DateTime) -> f64
+    where
+        C: Currency,
+        D: DayCounter,
+        I: Interpolator<DateTime, OrderedFloat<f64>>,
+    {
+        // The zero rate is flat before the first pillar.
+        let log_discount = |date: DateTime| {
+            -curve.interpolated(date.max(curve.first_pillar())) * curve.time_from_reference(date)
+        };
+        curve.extrapolated_log_discount(log_discount, dt)
+    }
+}
+
+impl InterpolatedQuantity for ForwardRate {
+    fn log_discount<C, D, I>(curve: &InterpolatedCurve<C, D, I, Self>, dt: DateTime) -> f64
+    where
+        C: Currency,
+        D: DayCounter,
+        I: Interpolator<DateTime, OrderedFloat<f64>>,
+    {
+        let mut integral = 0.0;
+        let mut start = curve.reference_date;
+        for pillar in curve.pillars.iter().copied().filter(|pillar| *pillar < dt) {
+            integral += curve.integrate_segment(start, pillar);
+            start = pillar;
+        }
+        if start < dt {
+            integral += curve.integrate_segment(start, dt);
+        }
+        -integral
+    }
+}
+
+impl<C, D, I, Q> TermStructure<D> for InterpolatedCurve<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+    Q: InterpolatedQuantity,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && dt <= self.get_max_datetime()
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<C, D, I, Q> YieldTermStructure<C, D> for InterpolatedCurve<C, D, I, Q>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+    Q: InterpolatedQuantity,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        if !self.is_datetime_valid(t) {
+            return Err(TermStructureError::InvalidDateTime);
+        }
+
+        Ok(OrderedFloat(Q::log_discount(self, t).exp()))
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{math::interpolation::LinearInterpolator, money::currency::USD};
+
+    #[test]
+    fn test_interpolated_discount_curve() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let curve: InterpolatedDiscountCurve<USD, Actual365Fixed, _> =
+            InterpolatedDiscountCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[
+                    (reference_date.add_months(12), 0.97),
+                    (reference_date.add_months(36), 0.90),
+                ])
+                .reference_date(reference_date)
+                .build()
+                .unwrap();
+
+        let forward = |t1: DateTime, t2: DateTime| *curve.forward_rate(t1, t2).unwrap().get_rate();
+
+        // Pillars are reproduced and forwards are flat between them.
+        assert_approx_eq!(
+            *curve
+                .discount_factor(reference_date.add_months(36))
+                .unwrap(),
+            0.90,
+            10e-12
+        );
+        let first = -(0.97f64.ln());
+        assert_approx_eq!(
+            forward(reference_date, reference_date.add_months(5)),
+            first,
+            10e-9
+        );
+        let second = (0.97f64 / 0.90).ln() / (731.0 / 365.0);
+        assert_approx_eq!(
+            forward(reference_date.add_months(14), reference_date.add_months(30)),
+            second,
+            10e-9
+        );
+
+        // Beyond the last pillar the last forward continues.
+        assert_approx_eq!(
+            forward(reference_date.add_months(48), reference_date.add_months(72)),
+            second,
+            10e-9
+        );
+
+        let builder = |point: (DateTime, f64)| {
+            InterpolatedDiscountCurveBuilder::<USD, Actual365Fixed, _>::new(
+                LinearInterpolator::new(),
+            )
+            .add_point(point)
+            .reference_date(reference_date)
+            .build()
+            .err()
+        };
+        assert_eq!(
+            builder((reference_date.add_months(12), 0.0)),
+            Some(InterpolatedCurveBuilderError::NonPositiveDiscountFactor)
+        );
+
+        // The reference date is a node with a discount factor of 1.
+        assert_eq!(
+            builder((reference_date, 0.99)),
+            Some(InterpolatedCurveBuilderError::ReferenceDiscountFactorNotOne)
+        );
+        assert_eq!(builder((reference_date, 1.0)), None);
+    }
+
+    #[test]
+    fn test_interpolated_zero_curve() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let curve: InterpolatedZeroCurve<USD, Actual365Fixed, _> =
+            InterpolatedZeroCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[
+                    (reference_date.add_months(12), 0.03),
+                    (reference_date.add_months(24), 0.035),
+                    (reference_date.add_months(60), 0.04),
+                ])
+                .reference_date(reference_date)
+                .build()
+                .unwrap();
+
+        let zero_rate = |date: DateTime| *curve.zero_rate(date).unwrap().get_rate();
+
+        // Flat before the first pillar, linear between pillars.
+        assert_approx_eq!(zero_rate(reference_date.add_months(6)), 0.03, 10e-12);
+        assert_approx_eq!(zero_rate(reference_date.add_months(24)), 0.035, 10e-12);
+        let date = DateTime::new_from_ymd(2024, 7, 2);
+        assert_approx_eq!(zero_rate(date), 0.0325, 10e-12);
+        assert_approx_eq!(
+            *curve.discount_factor(date).unwrap(),
+            (-0.0325f64 * 548.0 / 365.0).exp(),
+            10e-12
+        );
+
+        // Flat instantaneous forward beyond the last pillar: r(T) + T r'(T).
+        let last = reference_date.add_months(60);
+        let t_last = 1826.0 / 365.0;
+        let forward = 0.005f64.mul_add(t_last / (1095.0 / 365.0), 0.04);
+        assert_approx_eq!(
+            *curve
+                .forward_rate(last.add_months(12), last.add_months(36))
+                .unwrap()
+                .get_rate(),
+            forward,
+            10e-6
+        );
+
+        assert_eq!(
+            InterpolatedZeroCurveBuilder::<USD, Actual365Fixed, _>::new(LinearInterpolator::new())
+                .reference_date(reference_date)
+                .build()
+                .err(),
+            Some(InterpolatedCurveBuilderError::NoPointsProvided)
+        );
+    }
+
+    #[test]
+    fn test_interpolated_forward_curve() {
+        let reference_date = DateTime::new_from_ymd(2023, 1, 1);
+        let first = reference_date.add_months(12);
+        let second = reference_date.add_months(36);
+        let curve: InterpolatedForwardCurve<USD, Actual365Fixed, _> =
+            InterpolatedForwardCurveBuilder::new(LinearInterpolator::new())
+                .add_points(&[(first, 0.02), (second, 0.04)])
+                .reference_date(reference_date)
+                .build()
+                .unwrap();
+
+        // Flat 2% for the first year, then a linear ramp to 4% over two years (731 days).
+        assert_approx_eq!(
+            *curve.discount_factor(first).unwrap(),
+            (-0.02f64).exp(),
+            10e-12
+        );
+        let ramp = 0.5 * (0.02 + 0.04) * 731.0 / 365.0;
+        assert_approx_eq!(
+            *curve.discount_factor(second).unwrap(),
+            (-0.02f64 - ramp).exp(),
+            10e-12
+        );
+        assert_approx_eq!(
+            curve.instantaneous_forward(reference_date.add_months(24)),
+            0.02f64.mul_add(366.0 / 731.0, 0.02),
+            10e-12
+        );
+
+        // Flat forward beyond the last pillar.
+        assert_approx_eq!(
+            *curve
+                .forward_rate(second.add_months(12), second.add_months(60))
+                .unwrap()
+                .get_rate(),
+            0.04,
+            10e-9
+        );
+
+        assert_eq!(
+            InterpolatedForwardCurveBuilder::<USD, Actual365Fixed, _>::new(
+                LinearInterpolator::new()
+            )
+            .add_point((DateTime::new_from_ymd(2022, 1, 1), 0.02))
+            .reference_date(reference_date)
+            .build()
+            .err(),
+            Some(InterpolatedCurveBuilderError::PointBeforeReferenceDate)
+        );
+    }
+}
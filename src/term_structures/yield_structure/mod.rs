@@ -1,10 +1,12 @@
-use day_count_conventions::DayCounter;
+use day_count_conventions::{DayCountFraction, DayCounter};
+use ordered_float::OrderedFloat;
 
-use crate::interest_rate::InterestRate;
+use crate::interest_rate::{implied_rate_from_compound_factor, InterestRate};
 use crate::money::Currency;
 use crate::term_structures::{TermStructure, TermStructureError};
-use crate::time::DateTime;
+use crate::time::{DateTime, Duration};
 use crate::types::DiscountFactor;
+use crate::Compounding;
 
 pub trait YieldTermStructure<C, D>: TermStructure<D>
 where
@@ -26,6 +28,130 @@ where
 }
 
 mod flat_forward_term_structure;
+#[cfg(test)]
+pub(crate) use flat_forward_term_structure::flat_curve;
 pub use flat_forward_term_structure::{
     FlatForwardTermStructure, FlatForwardTermStructureBuilder, FlatForwardTermStructureBuilderError,
 };
+
+mod rate_helpers;
+pub use rate_helpers::{
+    DepositRateHelper, FraRateHelper, FuturesRateHelper, RateHelper, SwapRateHelper,
+};
+
+mod interpolated_curve;
+pub use interpolated_curve::{
+    Discount, ForwardRate, InterpolatedCurve, InterpolatedCurveBuilder,
+    InterpolatedCurveBuilderError, InterpolatedDiscountCurve, InterpolatedDiscountCurveBuilder,
+    InterpolatedForwardCurve, InterpolatedForwardCurveBuilder, InterpolatedQuantity,
+    InterpolatedZeroCurve, InterpolatedZeroCurveBuilder, ZeroYield,
+};
+
+mod parametric_yield_curve;
+pub use parametric_yield_curve::{ParametricYieldCurve, ParametricYieldModel, YieldCurveFitError};
+
+mod nelson_siegel;
+pub use nelson_siegel::{NelsonSiegel, Svensson};
+
+mod smith_wilson;
+pub use smith_wilson::SmithWilson;
+
+mod spreaded_term_structure;
+pub use spreaded_term_structure::{ForwardSpreadedTermStructure, ZeroSpreadedTermStructure};
+
+mod composite_term_structure;
+pub use composite_term_structure::CompositeTermStructure;
+
+mod implied_term_structure;
+pub use implied_term_structure::ImpliedTermStructure;
+
+mod curve_set;
+pub use curve_set::CurveSet;
+
+mod piecewise_yield_curve;
+pub use piecewise_yield_curve::{
+    PiecewiseYieldCurve, PiecewiseYieldCurveBuilder, PiecewiseYieldCurveBuilderError,
+};
+
+/// Log discount factor beyond the last pillar of an interpolated curve, extending flat the
+/// instantaneous forward rate at the last pillar (estimated over the last day before it).
+fn extrapolate_log_discount(
+    log_discount: impl Fn(DateTime) -> f64,
+    time_from_reference: impl Fn(DateTime) -> f64,
+    last: DateTime,
+    dt: DateTime,
+) -> f64 {
+    let before = last + Duration::new_from_days(-1.0);
+    let forward = (log_discount(before) - log_discount(last))
+        / (time_from_reference(last) - time_from_reference(before));
+    forward.mul_add(
+        time_from_reference(last) - time_from_reference(dt),
+        log_discount(last),
+    )
+}
+
+/// Zero rate implied by a curve's discount factor at a datetime, in the curve's day counter.
+fn implied_zero_rate<C, D>(
+    curve: &(impl YieldTermStructure<C, D> + ?Sized),
+    t: DateTime,
+    compounding: Compounding,
+) -> Result<InterestRate<C, D>, TermStructureError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    let day_count_convention = curve.get_day_counter();
+    let mut day_count_fraction =
+        day_count_convention.day_count_fraction(&curve.get_reference_date(), &t);
+    if day_count_fraction.get_fraction() == 0.0 {
+        day_count_fraction = DayCountFraction::new(10e-8);
+    }
+    let compound_factor = OrderedFloat(1.0) / curve.discount_factor(t)?;
+
+    Ok(implied_rate_from_compound_factor(
+        compound_factor,
+        &day_count_fraction,
+        day_count_convention,
+        compounding,
+    )
+    .unwrap())
+}
+
+/// Forward rate implied by a curve's discount factors between two datetimes, in the curve's day
+/// counter.
+fn implied_forward_rate<C, D>(
+    curve: &(impl YieldTermStructure<C, D> + ?Sized),
+    t1: DateTime,
+    t2: DateTime,
+    compounding: Compounding,
+) -> Result<InterestRate<C, D>, TermStructureError>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    if !curve.is_datetime_valid(t1) || !curve.is_datetime_valid(t2) {
+        return Err(TermStructureError::InvalidDateTime);
+    }
+    if t2 < t1 {
+        return Err(TermStructureError::T2LessThanT1);
+    }
+
+    let compound_factor = curve.discount_factor(t1)? / curve.discount_factor(t2)?;
+
+    let day_count_convention = curve.get_day_counter();
+    let reference_date = curve.get_reference_date();
+    let yf1 = day_count_convention.day_count_fraction(&reference_date, &t1);
+    let yf2 = day_count_convention.day_count_fraction(&reference_date, &t2);
+    let mut day_count_fraction = DayCountFraction::new(yf2.get_fraction() - yf1.get_fraction());
+    if day_count_fraction.get_fraction() == 0.0 {
+        day_count_fraction = DayCountFraction::new(10e-8);
+    }
+
+    Ok(implied_rate_from_compound_factor(
+        compound_factor,
+        &day_count_fraction,
+        day_count_convention,
+        compounding,
+    )
+    .unwrap())
+}
@@ -0,0 +1,374 @@
+use crate::math::optimization::NelderMead;
+use crate::term_structures::yield_structure::parametric_yield_curve::{
+    solve_linear_system, validate_quotes, ParametricYieldModel, YieldCurveFitError,
+};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Nelson-Siegel parameterisation of the continuously-compounded zero rate,
+/// $$
+///     r(t) = \beta_0 + \beta_1 \frac{1 - e^{-t / \tau}}{t / \tau}
+///         + \beta_2 \left( \frac{1 - e^{-t / \tau}}{t / \tau} - e^{-t / \tau} \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NelsonSiegel {
+    /// Long-term level $\beta_0$.
+    beta0: f64,
+
+    /// Short-term component $\beta_1$, so that $r(0) = \beta_0 + \beta_1$.
+    beta1: f64,
+
+    /// Medium-term hump $\beta_2$.
+    beta2: f64,
+
+    /// Decay time $\tau > 0$.
+    tau: f64,
+}
+
+/// Nelson-Siegel-Svensson parameterisation, adding a second hump with its own decay time to
+/// [`NelsonSiegel`],
+/// $$
+///     r(t) = \beta_0 + \beta_1 \frac{1 - e^{-t / \tau_1}}{t / \tau_1}
+///         + \beta_2 \left( \frac{1 - e^{-t / \tau_1}}{t / \tau_1} - e^{-t / \tau_1} \right)
+///         + \beta_3 \left( \frac{1 - e^{-t / \tau_2}}{t / \tau_2} - e^{-t / \tau_2} \right)
+/// $$
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Svensson {
+    beta0: f64,
+    beta1: f64,
+    beta2: f64,
+    beta3: f64,
+    tau1: f64,
+    tau2: f64,
+}
+
+/// Slope loading $\frac{1 - e^{-t / \tau}}{t / \tau}$.
+fn slope_loading(t: f64, tau: f64) -> f64 {
+    let x = t / tau;
+    if x < 1e-10 {
+        return 1.0 - 0.5 * x;
+    }
+    -(-x).exp_m1() / x
+}
+
+/// Curvature loading $\frac{1 - e^{-t / \tau}}{t / \tau} - e^{-t / \tau}$.
+fn curvature_loading(t: f64, tau: f64) -> f64 {
+    slope_loading(t, tau) - (-t / tau).exp()
+}
+
+/// Linear least-squares fit of the coefficients of loadings to `(time, rate)` quotes, with the
+/// sum of squared errors, or `None` if the loadings are collinear at the quoted times.
+fn fit_coefficients(
+    quotes: &[(f64, f64)],
+    loadings: impl Fn(f64) -> Vec<f64>,
+) -> Option<(Vec<f64>, f64)> {
+    let rows: Vec<Vec<f64>> = quotes.iter().map(|quote| loadings(quote.0)).collect();
+    let n = rows[0].len();
+
+    // Normal equations.
+    let matrix: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| rows.iter().map(|row| row[i] * row[j]).sum())
+                .collect()
+        })
+        .collect();
+    let rhs: Vec<f64> = (0..n)
+        .map(|i| {
+            rows.iter()
+                .zip(quotes)
+                .map(|(row, quote)| row[i] * quote.1)
+                .sum()
+        })
+        .collect();
+    let coefficients = solve_linear_system(matrix, rhs)?;
+
+    let error = rows
+        .iter()
+        .zip(quotes)
+        .map(|(row, quote)| {
+            let fitted: f64 = row.iter().zip(&coefficients).map(|(l, c)| l * c).sum();
+            (fitted - quote.1).powi(2)
+        })
+        .sum();
+    Some((coefficients, error))
+}
+
+impl NelsonSiegel {
+    #[must_use]
+    pub const fn new(beta0: f64, beta1: f64, beta2: f64, tau: f64) -> Self {
+        Self {
+            beta0,
+            beta1,
+            beta2,
+            tau,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_beta0(&self) -> f64 {
+        self.beta0
+    }
+
+    #[must_use]
+    pub const fn get_beta1(&self) -> f64 {
+        self.beta1
+    }
+
+    #[must_use]
+    pub const fn get_beta2(&self) -> f64 {
+        self.beta2
+    }
+
+    #[must_use]
+    pub const fn get_tau(&self) -> f64 {
+        self.tau
+    }
+
+    /// Instantaneous forward rate
+    /// $f(t) = \beta_0 + \beta_1 e^{-t / \tau} + \beta_2 \frac{t}{\tau} e^{-t / \tau}$.
+    #[must_use]
+    pub fn instantaneous_forward(&self, t: f64) -> f64 {
+        let decay = (-t / self.tau).exp();
+        (self.beta2 * t / self.tau).mul_add(decay, self.beta1.mul_add(decay, self.beta0))
+    }
+
+    /// Fit the parameters to `(time, rate)` quotes by least squares on the rates.
+    ///
+    /// Quotes are continuously-compounded zero rates, or bond yields when fitting the yield curve
+    /// directly as central banks often do. For a given decay time the betas are linear, so they
+    /// are solved for exactly and only $\ln \tau$ is minimised over with Nelder-Mead.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if fewer than four quotes are provided,
+    /// `NonPositiveMaturity` or `DuplicateMaturity` for invalid maturities, and `NoConvergence`
+    /// if the optimiser did not converge.
+    pub fn fit(quotes: &[(f64, f64)]) -> Result<Self, YieldCurveFitError> {
+        validate_quotes(quotes, 4)?;
+
+        let loadings =
+            |tau: f64| move |t: f64| vec![1.0, slope_loading(t, tau), curvature_loading(t, tau)];
+        let objective = |x: &[f64]| {
+            fit_coefficients(quotes, loadings(x[0].exp())).map_or(f64::INFINITY, |fit| fit.1)
+        };
+
+        let result = NelderMead::new(10_000, 1e-20).minimize(objective, &[0.0], 0.5);
+        let tau = result.x[0].exp();
+        match fit_coefficients(quotes, loadings(tau)) {
+            Some((betas, _)) if result.converged => {
+                Ok(Self::new(betas[0], betas[1], betas[2], tau))
+            }
+            _ => Err(YieldCurveFitError::NoConvergence),
+        }
+    }
+}
+
+impl Svensson {
+    #[must_use]
+    pub const fn new(beta0: f64, beta1: f64, beta2: f64, beta3: f64, tau1: f64, tau2: f64) -> Self {
+        Self {
+            beta0,
+            beta1,
+            beta2,
+            beta3,
+            tau1,
+            tau2,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_beta0(&self) -> f64 {
+        self.beta0
+    }
+
+    #[must_use]
+    pub const fn get_beta1(&self) -> f64 {
+        self.beta1
+    }
+
+    #[must_use]
+    pub const fn get_beta2(&self) -> f64 {
+        self.beta2
+    }
+
+    #[must_use]
+    pub const fn get_beta3(&self) -> f64 {
+        self.beta3
+    }
+
+    #[must_use]
+    pub const fn get_tau1(&self) -> f64 {
+        self.tau1
+    }
+
+    #[must_use]
+    pub const fn get_tau2(&self) -> f64 {
+        self.tau2
+    }
+
+    /// Instantaneous forward rate.
+    #[must_use]
+    pub fn instantaneous_forward(&self, t: f64) -> f64 {
+        let decay1 = (-t / self.tau1).exp();
+        let decay2 = (-t / self.tau2).exp();
+        (self.beta3 * t / self.tau2).mul_add(
+            decay2,
+            (self.beta2 * t / self.tau1).mul_add(decay1, self.beta1.mul_add(decay1, self.beta0)),
+        )
+    }
+
+    /// Fit the parameters to `(time, rate)` quotes by least squares on the rates, as for
+    /// [`NelsonSiegel::fit`], minimising over $\ln \tau_1$ and $\ln \tau_2$.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if fewer than six quotes are provided,
+    /// `NonPositiveMaturity` or `DuplicateMaturity` for invalid maturities, and `NoConvergence`
+    /// if the optimiser did not converge.
+    pub fn fit(quotes: &[(f64, f64)]) -> Result<Self, YieldCurveFitError> {
+        validate_quotes(quotes, 6)?;
+
+        let loadings = |tau1: f64, tau2: f64| {
+            move |t: f64| {
+                vec![
+                    1.0,
+                    slope_loading(t, tau1),
+                    curvature_loading(t, tau1),
+                    curvature_loading(t, tau2),
+                ]
+            }
+        };
+        let objective = |x: &[f64]| {
+            fit_coefficients(quotes, loadings(x[0].exp(), x[1].exp()))
+                .map_or(f64::INFINITY, |fit| fit.1)
+        };
+
+        // Start with a short and a long hump.
+        let result = NelderMead::new(10_000, 1e-20).minimize(objective, &[0.0, 5.0f64.ln()], 0.5);
+        let (tau1, tau2) = (result.x[0].exp(), result.x[1].exp());
+        match fit_coefficients(quotes, loadings(tau1, tau2)) {
+            Some((betas, _)) if result.converged => Ok(Self::new(
+                betas[0], betas[1], betas[2], betas[3], tau1, tau2,
+            )),
+            _ => Err(YieldCurveFitError::NoConvergence),
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl ParametricYieldModel for NelsonSiegel {
+    fn discount_factor(&self, t: f64) -> f64 {
+        (-self.zero_rate(t) * t).exp()
+    }
+
+    fn zero_rate(&self, t: f64) -> f64 {
+        self.beta2.mul_add(
+            curvature_loading(t, self.tau),
+            self.beta1.mul_add(slope_loading(t, self.tau), self.beta0),
+        )
+    }
+}
+
+impl ParametricYieldModel for Svensson {
+    fn discount_factor(&self, t: f64) -> f64 {
+        (-self.zero_rate(t) * t).exp()
+    }
+
+    fn zero_rate(&self, t: f64) -> f64 {
+        self.beta3.mul_add(
+            curvature_loading(t, self.tau2),
+            self.beta2.mul_add(
+                curvature_loading(t, self.tau1),
+                self.beta1.mul_add(slope_loading(t, self.tau1), self.beta0),
+            ),
+        )
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{
+        money::currency::EUR,
+        term_structures::{ParametricYieldCurve, YieldTermStructure},
+        time::DateTime,
+    };
+
+    const MATURITIES: [f64; 10] = [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0];
+
+    #[test]
+    fn test_nelson_siegel() {
+        let model = NelsonSiegel::new(0.04, -0.02, 0.01, 1.5);
+
+        // Short and long ends, and forwards consistent with zero rates.
+        assert_approx_eq!(model.zero_rate(1e-12), 0.02, 10e-10);
+        assert_approx_eq!(model.zero_rate(1000.0), 0.04, 10e-4);
+        let (t, h) = (3.0, 1e-5);
+        let forward =
+            ((model.zero_rate(t + h) * (t + h)) - (model.zero_rate(t - h) * (t - h))) / (2.0 * h);
+        assert_approx_eq!(model.instantaneous_forward(t), forward, 10e-9);
+
+        // Recovered from its own zero rates.
+        let quotes: Vec<(f64, f64)> = MATURITIES
+            .iter()
+            .map(|t| (*t, model.zero_rate(*t)))
+            .collect();
+        let fitted = NelsonSiegel::fit(&quotes).unwrap();
+        assert_approx_eq!(fitted.get_beta0(), model.get_beta0(), 10e-6);
+        assert_approx_eq!(fitted.get_beta1(), model.get_beta1(), 10e-6);
+        assert_approx_eq!(fitted.get_beta2(), model.get_beta2(), 10e-6);
+        assert_approx_eq!(fitted.get_tau(), model.get_tau(), 10e-4);
+
+        // As a yield curve.
+        let reference_date = DateTime::new_from_ymd(2024, 1, 1);
+        let curve: ParametricYieldCurve<EUR, _, _> =
+            ParametricYieldCurve::new(fitted, reference_date, Actual365Fixed);
+        let date = DateTime::new_from_ymd(2029, 1, 1);
+        let t = 1827.0 / 365.0;
+        assert_approx_eq!(
+            *curve.zero_rate(date).unwrap().get_rate(),
+            model.zero_rate(t),
+            10e-8
+        );
+        assert_approx_eq!(*curve.discount_factor(reference_date).unwrap(), 1.0);
+
+        assert_eq!(
+            NelsonSiegel::fit(&quotes[..3]),
+            Err(YieldCurveFitError::NotEnoughQuotes)
+        );
+        assert_eq!(
+            NelsonSiegel::fit(&[(1.0, 0.01), (1.0, 0.02), (2.0, 0.02), (3.0, 0.03)]),
+            Err(YieldCurveFitError::DuplicateMaturity)
+        );
+    }
+
+    #[test]
+    fn test_svensson() {
+        let model = Svensson::new(0.035, -0.015, 0.02, -0.01, 1.0, 8.0);
+        assert_approx_eq!(model.zero_rate(1e-12), 0.02, 10e-10);
+        let (t, h) = (4.0, 1e-5);
+        let forward =
+            ((model.zero_rate(t + h) * (t + h)) - (model.zero_rate(t - h) * (t - h))) / (2.0 * h);
+        assert_approx_eq!(model.instantaneous_forward(t), forward, 10e-9);
+
+        let quotes: Vec<(f64, f64)> = MATURITIES
+            .iter()
+            .map(|t| (*t, model.zero_rate(*t)))
+            .collect();
+        let fitted = Svensson::fit(&quotes).unwrap();
+        for (t, rate) in &quotes {
+            assert_approx_eq!(fitted.zero_rate(*t), *rate, 10e-7);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::marker::PhantomData;
+
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::interest_rate::InterestRate;
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    implied_forward_rate, implied_zero_rate, YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::DateTime;
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Discount function given in closed form of the time $t$ in years from the reference date.
+pub trait ParametricYieldModel {
+    /// Discount factor $D(t)$.
+    fn discount_factor(&self, t: f64) -> f64;
+
+    /// Continuously-compounded zero rate $r(t) = -\ln D(t) / t$.
+    fn zero_rate(&self, t: f64) -> f64 {
+        -self.discount_factor(t).ln() / t
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum YieldCurveFitError {
+    /// Fewer quotes than the number of parameters of the model.
+    NotEnoughQuotes,
+
+    /// A quote has a maturity that is not positive.
+    NonPositiveMaturity,
+
+    /// Two quotes have the same maturity.
+    DuplicateMaturity,
+
+    /// The optimiser did not converge.
+    NoConvergence,
+}
+
+/// Check that fitting quotes are enough for the number of parameters, at distinct positive
+/// maturities.
+pub(super) fn validate_quotes(
+    quotes: &[(f64, f64)],
+    parameters: usize,
+) -> Result<(), YieldCurveFitError> {
+    if quotes.len() < parameters {
+        return Err(YieldCurveFitError::NotEnoughQuotes);
+    }
+    if quotes.iter().any(|quote| quote.0 <= 0.0) {
+        return Err(YieldCurveFitError::NonPositiveMaturity);
+    }
+    let mut maturities: Vec<f64> = quotes.iter().map(|quote| quote.0).collect();
+    maturities.sort_by(f64::total_cmp);
+    if maturities.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(YieldCurveFitError::DuplicateMaturity);
+    }
+    Ok(())
+}
+
+/// Solve the linear system $A x = b$ by Gaussian elimination with partial pivoting, or `None` if
+/// the matrix is singular.
+pub(super) fn solve_linear_system(
+    mut matrix: Vec<Vec<f64>>,
+    mut rhs: Vec<f64>,
+) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot][column].abs() < 1e-300 {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        let (upper, lower) = matrix.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value = factor.mul_add(-pivot_value, *value);
+            }
+            rhs[column + 1 + offset] = factor.mul_add(-rhs[column], rhs[column + 1 + offset]);
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// Yield curve given by a [`ParametricYieldModel`], with times measured from the reference date
+/// in the curve's day count convention.
+#[derive(Debug, Clone)]
+pub struct ParametricYieldCurve<C, D, M>
+where
+    C: Currency,
+    D: DayCounter,
+    M: ParametricYieldModel,
+{
+    model: M,
+    reference_date: DateTime,
+    day_count_convention: D,
+    _currency: PhantomData<C>,
+}
+
+impl<C, D, M> ParametricYieldCurve<C, D, M>
+where
+    C: Currency,
+    D: DayCounter,
+    M: ParametricYieldModel,
+{
+    #[must_use]
+    pub const fn new(model: M, reference_date: DateTime, day_count_convention: D) -> Self {
+        Self {
+            model,
+            reference_date,
+            day_count_convention,
+            _currency: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn get_model(&self) -> &M {
+        &self.model
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, M> TermStructure<D> for ParametricYieldCurve<C, D, M>
+where
+    C: Currency,
+    D: DayCounter,
+    M: ParametricYieldModel,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && dt <= self.get_max_datetime()
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<C, D, M> YieldTermStructure<C, D> for ParametricYieldCurve<C, D, M>
+where
+    C: Currency,
+    D: DayCounter,
+    M: ParametricYieldModel,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        if !self.is_datetime_valid(t) {
+            return Err(TermStructureError::InvalidDateTime);
+        }
+
+        let time = self
+            .day_count_convention
+            .day_count_fraction(&self.reference_date, &t)
+            .get_fraction();
+        if time == 0.0 {
+            return Ok(OrderedFloat(1.0));
+        }
+        Ok(OrderedFloat(self.model.discount_factor(time)))
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
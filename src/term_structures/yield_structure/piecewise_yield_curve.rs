@@ -0,0 +1,539 @@
+use std::marker::PhantomData;
+
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::interest_rate::InterestRate;
+use crate::math::interpolation::{InterpolationResult, Interpolator};
+use crate::math::solvers::{Brent, SolverError};
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    extrapolate_log_discount, implied_forward_rate, implied_zero_rate, RateHelper,
+    YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::DateTime;
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Yield curve bootstrapped from market instruments.
+///
+/// The curve is defined by the log discount factors $\ln D(t)$ at the pillar dates of its rate
+/// helpers, interpolated in time with the chosen interpolator: a [`LinearInterpolator`] gives
+/// piecewise flat instantaneous forwards, a [`CubicSplineInterpolator`] smooth ones. Beyond the
+/// last pillar the instantaneous forward at the last pillar is extended flat.
+///
+/// Pillars are solved one at a time in order of maturity, each one so that its helper reprices
+/// exactly. Since a non-local interpolator moves the curve between earlier pillars when a new
+/// one is added, the whole bootstrap is repeated until the pillars stop moving.
+///
+/// [`LinearInterpolator`]: crate::math::interpolation::LinearInterpolator
+/// [`CubicSplineInterpolator`]: crate::math::interpolation::CubicSplineInterpolator
+#[derive(Debug, Clone)]
+pub struct PiecewiseYieldCurve<C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    /// Interpolator of the log discount factors, with a point at the reference date.
+    interpolator: I,
+
+    /// Pillar dates, sorted.
+    pillars: Vec<DateTime>,
+
+    reference_date: DateTime,
+    day_count_convention: D,
+    _currency: PhantomData<C>,
+}
+
+pub struct PiecewiseYieldCurveBuilder<'a, C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    interpolator: I,
+    helpers: Vec<&'a dyn RateHelper<C, D>>,
+    reference_date: Option<DateTime>,
+    day_count_convention: Option<D>,
+    accuracy: f64,
+    max_iterations: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PiecewiseYieldCurveBuilderError {
+    NoHelpersProvided,
+    PillarNotAfterReferenceDate,
+    DuplicatePillar,
+
+    /// A pillar could not be solved for.
+    Solver(SolverError),
+
+    /// The pillars kept moving after the maximum number of bootstrap passes.
+    NoConvergence,
+}
+
+impl<'a, C, D, I> PiecewiseYieldCurveBuilder<'a, C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>> + Clone,
+{
+    /// Create a new builder which will interpolate the curve with `interpolator`, which should
+    /// not contain any points.
+    #[must_use]
+    pub const fn new(interpolator: I) -> Self {
+        Self {
+            interpolator,
+            helpers: Vec::new(),
+            reference_date: None,
+            day_count_convention: None,
+            accuracy: 1e-12,
+            max_iterations: 100,
+        }
+    }
+
+    /// Add a rate helper, whose pillar date becomes a node of the curve.
+    pub fn helper(&mut self, helper: &'a dyn RateHelper<C, D>) -> &mut Self {
+        self.helpers.push(helper);
+        self
+    }
+
+    pub fn reference_date(&mut self, reference_date: DateTime) -> &mut Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn day_count_convention(&mut self, day_count_convention: D) -> &mut Self {
+        self.day_count_convention = Some(day_count_convention);
+        self
+    }
+
+    /// Set the accuracy to which pillars are solved. Defaults to $10^{-12}$.
+    pub fn accuracy(&mut self, accuracy: f64) -> &mut Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Set the maximum number of bootstrap passes. Defaults to 100.
+    pub fn max_iterations(&mut self, max_iterations: usize) -> &mut Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Bootstrap the curve.
+    ///
+    /// # Errors
+    /// Will return `NoHelpersProvided` if there are no helpers, `PillarNotAfterReferenceDate` or
+    /// `DuplicatePillar` if the pillar dates are not distinct and after the reference date,
+    /// `Solver` if a helper cannot be repriced, and `NoConvergence` if the bootstrap does not
+    /// converge.
+    pub fn build(&self) -> Result<PiecewiseYieldCurve<C, D, I>, PiecewiseYieldCurveBuilderError> {
+        self.build_with_bumps(&[])
+    }
+
+    /// The pillar dates of the helpers, sorted.
+    pub(crate) fn pillar_dates(&self) -> Vec<DateTime> {
+        let mut pillars: Vec<DateTime> = self
+            .helpers
+            .iter()
+            .map(|helper| helper.pillar_date())
+            .collect();
+        pillars.sort();
+        pillars
+    }
+
+    /// Bootstrap the curve with the quotes of the helpers, in pillar order, bumped by the given
+    /// rates (see [`RateHelper::bumped_quote`]). Missing bumps are zero.
+    pub(crate) fn build_with_bumps(
+        &self,
+        bumps: &[f64],
+    ) -> Result<PiecewiseYieldCurve<C, D, I>, PiecewiseYieldCurveBuilderError> {
+        use PiecewiseYieldCurveBuilderError::{
+            DuplicatePillar, NoConvergence, NoHelpersProvided, PillarNotAfterReferenceDate, Solver,
+        };
+
+        if self.helpers.is_empty() {
+            return Err(NoHelpersProvided);
+        }
+        let reference_date = self.reference_date.unwrap_or_else(DateTime::now);
+        let day_count_convention = self.day_count_convention.unwrap_or_default();
+
+        let mut helpers = self.helpers.clone();
+        helpers.sort_by_key(|helper| helper.pillar_date());
+        if helpers[0].pillar_date() <= reference_date {
+            return Err(PillarNotAfterReferenceDate);
+        }
+        if helpers
+            .windows(2)
+            .any(|pair| pair[0].pillar_date() == pair[1].pillar_date())
+        {
+            return Err(DuplicatePillar);
+        }
+
+        let mut curve = PiecewiseYieldCurve {
+            interpolator: self.interpolator.clone(),
+            pillars: helpers.iter().map(|helper| helper.pillar_date()).collect(),
+            reference_date,
+            day_count_convention,
+            _currency: PhantomData,
+        };
+        curve
+            .interpolator
+            .add_point((reference_date, OrderedFloat(0.0)));
+
+        // Initial guess: flat 2% continuously compounded zero rate.
+        for helper in &helpers {
+            let time = curve.time_from_reference(helper.pillar_date());
+            curve
+                .interpolator
+                .add_point((helper.pillar_date(), OrderedFloat(-0.02 * time)));
+        }
+
+        let quotes: Vec<f64> = helpers
+            .iter()
+            .enumerate()
+            .map(|(i, helper)| helper.bumped_quote(bumps.get(i).copied().unwrap_or(0.0)))
+            .collect();
+
+        let solver = Brent::new(100, self.accuracy);
+        for _ in 0..self.max_iterations {
+            let mut max_change: f64 = 0.0;
+            for (helper, quote) in helpers.iter().zip(&quotes) {
+                let pillar = helper.pillar_date();
+                let time = curve.time_from_reference(pillar);
+                let previous = curve.log_discount(pillar);
+
+                // Solve in terms of the zero rate at the pillar, moving the pillar of the curve
+                // itself, and leave it at the solution, or as it was if there is none.
+                let objective = |zero_rate: f64| {
+                    curve
+                        .interpolator
+                        .add_point((pillar, OrderedFloat(-zero_rate * time)));
+                    helper
+                        .implied_quote(&curve)
+                        .map_or(f64::NAN, |implied| implied - quote)
+                };
+                let solution = solver.solve(objective, -0.5, 2.0);
+
+                let log_discount = solution.map_or(previous, |zero_rate| -zero_rate * time);
+                curve
+                    .interpolator
+                    .add_point((pillar, OrderedFloat(log_discount)));
+                solution.map_err(Solver)?;
+                max_change = max_change.max((log_discount - previous).abs());
+            }
+
+            if max_change <= self.accuracy {
+                return Ok(curve);
+            }
+        }
+
+        Err(NoConvergence)
+    }
+}
+
+impl<C, D, I> PiecewiseYieldCurve<C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    /// The pillars of the curve, as `(date, discount factor)` sorted by date.
+    #[must_use]
+    pub fn get_pillars(&self) -> Vec<(DateTime, DiscountFactor)> {
+        self.pillars
+            .iter()
+            .map(|pillar| (*pillar, OrderedFloat(self.log_discount(*pillar).exp())))
+            .collect()
+    }
+
+    /// Log discount factor at a datetime, with flat forward extrapolation.
+    fn log_discount(&self, dt: DateTime) -> f64 {
+        let interpolated = |dt: DateTime| match self.interpolator.interpolate(dt) {
+            InterpolationResult::ExistingValue(v) | InterpolationResult::InterpolatedValue(v) => *v,
+            _ => f64::NAN,
+        };
+
+        let last = self.pillars[self.pillars.len() - 1];
+        if dt <= last {
+            interpolated(dt)
+        } else {
+            extrapolate_log_discount(
+                interpolated,
+                |date| self.time_from_reference(date),
+                last,
+                dt,
+            )
+        }
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, I> TermStructure<D> for PiecewiseYieldCurve<C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.reference_date
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        DateTime::new_from_ymd(9999, 12, 31)
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        dt >= self.reference_date && dt <= self.get_max_datetime()
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.day_count_convention
+    }
+}
+
+impl<C, D, I> YieldTermStructure<C, D> for PiecewiseYieldCurve<C, D, I>
+where
+    C: Currency,
+    D: DayCounter,
+    I: Interpolator<DateTime, OrderedFloat<f64>>,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        if !self.is_datetime_valid(t) {
+            return Err(TermStructureError::InvalidDateTime);
+        }
+
+        Ok(OrderedFloat(self.log_discount(t).exp()))
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed, Thirty360};
+
+    use super::*;
+    use crate::{
+        math::interpolation::{CubicSplineInterpolator, LinearInterpolator},
+        money::currency::USD,
+        term_structures::{DepositRateHelper, FraRateHelper, FuturesRateHelper, SwapRateHelper},
+        time::Frequency,
+    };
+
+    fn reference_date() -> DateTime {
+        DateTime::new_from_ymd(2023, 1, 1)
+    }
+
+    fn market_helpers() -> Vec<Box<dyn RateHelper<USD, Actual365Fixed>>> {
+        let spot = reference_date();
+        vec![
+            Box::new(DepositRateHelper::new(
+                0.045,
+                spot,
+                spot.add_months(1),
+                Actual360,
+            )),
+            Box::new(DepositRateHelper::new(
+                0.047,
+                spot,
+                spot.add_months(3),
+                Actual360,
+            )),
+            Box::new(FraRateHelper::new(
+                0.049,
+                spot.add_months(3),
+                spot.add_months(6),
+                Actual360,
+            )),
+            Box::new(
+                FuturesRateHelper::new(94.9, spot.add_months(6), spot.add_months(9), Actual360)
+                    .with_convexity_adjustment(
+                        FuturesRateHelper::<Actual360>::ho_lee_convexity_adjustment(
+                            0.01, 0.5, 0.75,
+                        ),
+                    ),
+            ),
+            Box::new(SwapRateHelper::new(
+                0.046,
+                spot,
+                spot.add_months(24),
+                Frequency::Semiannual,
+                Thirty360,
+            )),
+            Box::new(SwapRateHelper::new(
+                0.042,
+                spot,
+                spot.add_months(60),
+                Frequency::Annual,
+                Thirty360,
+            )),
+            Box::new(SwapRateHelper::new(
+                0.040,
+                spot,
+                spot.add_months(120),
+                Frequency::Annual,
+                Thirty360,
+            )),
+        ]
+    }
+
+    #[test]
+    fn test_piecewise_yield_curve_reprices_helpers() {
+        let helpers = market_helpers();
+
+        let mut builder = PiecewiseYieldCurveBuilder::new(LinearInterpolator::new());
+        builder
+            .reference_date(reference_date())
+            .day_count_convention(Actual365Fixed);
+        for helper in &helpers {
+            builder.helper(helper.as_ref());
+        }
+        let curve = builder.build().unwrap();
+
+        for helper in &helpers {
+            assert_approx_eq!(
+                helper.implied_quote(&curve).unwrap(),
+                helper.quote(),
+                10e-10
+            );
+        }
+        assert_eq!(curve.get_pillars().len(), helpers.len());
+        assert_approx_eq!(*curve.discount_factor(reference_date()).unwrap(), 1.0);
+
+        // Piecewise flat forwards, extended flat after the last pillar.
+        let last = reference_date().add_months(120);
+        let forward = |t1: DateTime, t2: DateTime| *curve.forward_rate(t1, t2).unwrap().get_rate();
+        assert_approx_eq!(
+            forward(last, last.add_months(12)),
+            forward(last.add_months(-12), last),
+            10e-10
+        );
+        assert_approx_eq!(
+            forward(
+                reference_date().add_months(1),
+                reference_date().add_months(2)
+            ),
+            forward(
+                reference_date().add_months(2),
+                reference_date().add_months(3)
+            ),
+            10e-10
+        );
+
+        // Smooth interpolation also reprices every helper.
+        let mut builder = PiecewiseYieldCurveBuilder::new(CubicSplineInterpolator::new());
+        builder
+            .reference_date(reference_date())
+            .day_count_convention(Actual365Fixed);
+        for helper in &helpers {
+            builder.helper(helper.as_ref());
+        }
+        let curve = builder.build().unwrap();
+        for helper in &helpers {
+            assert_approx_eq!(
+                helper.implied_quote(&curve).unwrap(),
+                helper.quote(),
+                10e-10
+            );
+        }
+    }
+
+    #[test]
+    fn test_piecewise_yield_curve_flat_market() {
+        // Quotes implied by a flat 3% continuously compounded curve give back that curve.
+        let spot = reference_date();
+        let zero_rate: f64 = 0.03;
+        let discount = |date: DateTime| {
+            (-zero_rate
+                * Actual365Fixed
+                    .day_count_fraction(&spot, &date)
+                    .get_fraction())
+            .exp()
+        };
+        let simple_rate = |start: DateTime, end: DateTime| {
+            (discount(start) / discount(end) - 1.0)
+                / Actual360.day_count_fraction(&start, &end).get_fraction()
+        };
+        let swap_rate = |years: i32| {
+            let annuity: f64 = (1..=years)
+                .map(|year| discount(spot.add_months(12 * year)))
+                .sum();
+            (1.0 - discount(spot.add_months(12 * years))) / annuity
+        };
+
+        let deposit = DepositRateHelper::new(
+            simple_rate(spot, spot.add_months(6)),
+            spot,
+            spot.add_months(6),
+            Actual360,
+        );
+        let swap_2y = SwapRateHelper::new(
+            swap_rate(2),
+            spot,
+            spot.add_months(24),
+            Frequency::Annual,
+            Thirty360,
+        );
+        let swap_5y = SwapRateHelper::new(
+            swap_rate(5),
+            spot,
+            spot.add_months(60),
+            Frequency::Annual,
+            Thirty360,
+        );
+
+        let curve =
+            PiecewiseYieldCurveBuilder::<USD, Actual365Fixed, _>::new(LinearInterpolator::new())
+                .reference_date(spot)
+                .helper(&deposit)
+                .helper(&swap_2y)
+                .helper(&swap_5y)
+                .build()
+                .unwrap();
+
+        for months in [3, 6, 18, 24, 42, 60, 90] {
+            let date = spot.add_months(months);
+            assert_approx_eq!(
+                *curve.discount_factor(date).unwrap(),
+                discount(date),
+                10e-10
+            );
+            assert_approx_eq!(*curve.zero_rate(date).unwrap().get_rate(), zero_rate, 10e-9);
+        }
+
+        assert_eq!(
+            PiecewiseYieldCurveBuilder::<USD, Actual365Fixed, _>::new(LinearInterpolator::new())
+                .reference_date(spot)
+                .helper(&swap_2y)
+                .helper(&swap_2y)
+                .build()
+                .err(),
+            Some(PiecewiseYieldCurveBuilderError::DuplicatePillar)
+        );
+    }
+}
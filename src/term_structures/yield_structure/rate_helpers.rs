@@ -0,0 +1,501 @@
+use day_count_conventions::DayCounter;
+
+use crate::money::{Currency, Money};
+use crate::term_structures::yield_structure::YieldTermStructure;
+use crate::term_structures::TermStructureError;
+use crate::time::{DateTime, Frequency, Schedule};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// A market instrument used to bootstrap a yield curve.
+///
+/// Each helper determines the discount factor at its pillar date: the bootstrapper adjusts the
+/// curve at the pillar until the quote implied by the curve matches the market quote.
+pub trait RateHelper<C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// The latest date whose discount factor the instrument depends on.
+    fn pillar_date(&self) -> DateTime;
+
+    /// The market quote.
+    fn quote(&self) -> f64;
+
+    /// The market quote after bumping the rate it quotes by `rate_bump`.
+    fn bumped_quote(&self, rate_bump: f64) -> f64 {
+        self.quote() + rate_bump
+    }
+
+    /// The quote implied by a curve.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if the curve cannot discount to one of the instrument's
+    /// dates.
+    fn implied_quote(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError>;
+}
+
+/// Simply-compounded forward rate between two dates.
+fn simple_forward_rate<C, D, DC>(
+    curve: &dyn YieldTermStructure<C, D>,
+    start: DateTime,
+    end: DateTime,
+    day_counter: DC,
+) -> Result<f64, TermStructureError>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    let tau = day_counter.day_count_fraction(&start, &end).get_fraction();
+    Ok((*curve.discount_factor(start)? / *curve.discount_factor(end)? - 1.0) / tau)
+}
+
+/// Deposit quoted as a simple rate between a start date (usually spot) and a maturity.
+#[derive(Debug, Copy, Clone)]
+pub struct DepositRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    rate: f64,
+    start: DateTime,
+    maturity: DateTime,
+    day_counter: DC,
+}
+
+impl<DC> DepositRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    #[must_use]
+    pub const fn new(rate: f64, start: DateTime, maturity: DateTime, day_counter: DC) -> Self {
+        Self {
+            rate,
+            start,
+            maturity,
+            day_counter,
+        }
+    }
+}
+
+/// Forward rate agreement quoted as the simple forward rate between two future dates.
+///
+/// The quote only depends on the projection curve; discounting only enters the value of the
+/// contract, see [`FraRateHelper::npv`].
+#[derive(Debug, Copy, Clone)]
+pub struct FraRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    rate: f64,
+    start: DateTime,
+    maturity: DateTime,
+    day_counter: DC,
+}
+
+impl<DC> FraRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    #[must_use]
+    pub const fn new(rate: f64, start: DateTime, maturity: DateTime, day_counter: DC) -> Self {
+        Self {
+            rate,
+            start,
+            maturity,
+            day_counter,
+        }
+    }
+
+    /// Value to the buyer of the FRA at the quoted rate $K$, settled at the start date:
+    /// $$
+    ///     N \frac{\tau (F - K)}{1 + \tau F} D_d(t_s),
+    /// $$
+    /// with the forward rate $F$ from the projection curve and $D_d$ the discount curve.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if a curve cannot discount to the FRA dates.
+    pub fn npv<C, D>(
+        &self,
+        notional: Money<C>,
+        projection_curve: &dyn YieldTermStructure<C, D>,
+        discount_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<Money<C>, TermStructureError>
+    where
+        C: Currency,
+        D: DayCounter,
+    {
+        let tau = self
+            .day_counter
+            .day_count_fraction(&self.start, &self.maturity)
+            .get_fraction();
+        let forward = simple_forward_rate(
+            projection_curve,
+            self.start,
+            self.maturity,
+            self.day_counter,
+        )?;
+        let settlement = tau * (forward - self.rate) / tau.mul_add(forward, 1.0);
+        Ok(notional * settlement * *discount_curve.discount_factor(self.start)?)
+    }
+}
+
+/// Interest-rate future quoted as a price $P = 100 (1 - R)$ on the futures rate $R$ of the
+/// underlying period.
+///
+/// Futures are settled daily, so the futures rate exceeds the forward rate by a convexity
+/// adjustment $c$: the forward rate is $R - c$.
+#[derive(Debug, Copy, Clone)]
+pub struct FuturesRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    price: f64,
+    start: DateTime,
+    maturity: DateTime,
+    day_counter: DC,
+    convexity_adjustment: f64,
+}
+
+impl<DC> FuturesRateHelper<DC>
+where
+    DC: DayCounter,
+{
+    /// Create a new `FuturesRateHelper` with no convexity adjustment.
+    #[must_use]
+    pub const fn new(price: f64, start: DateTime, maturity: DateTime, day_counter: DC) -> Self {
+        Self {
+            price,
+            start,
+            maturity,
+            day_counter,
+            convexity_adjustment: 0.0,
+        }
+    }
+
+    /// Set the convexity adjustment, as a rate.
+    #[must_use]
+    pub const fn with_convexity_adjustment(mut self, convexity_adjustment: f64) -> Self {
+        self.convexity_adjustment = convexity_adjustment;
+        self
+    }
+
+    /// Convexity adjustment under the Ho-Lee model, $c = \frac{1}{2} \sigma^2 t_1 t_2$, with
+    /// $\sigma$ the normal volatility of the short rate and $t_1$, $t_2$ the times to the start
+    /// and end of the underlying period.
+    #[must_use]
+    pub fn ho_lee_convexity_adjustment(volatility: f64, t1: f64, t2: f64) -> f64 {
+        0.5 * volatility * volatility * t1 * t2
+    }
+}
+
+/// Par swap quoted as the fixed rate against a floating leg.
+///
+/// The par rate is
+/// $$
+///     S = \frac{\sum_j \left( \frac{P(s_j)}{P(e_j)} - 1 \right) D(e_j)}{\sum_i \tau_i D(t_i)}
+/// $$
+/// over the floating leg periods $[s_j, e_j]$ and the fixed leg payment dates $t_i$, with $P$ the
+/// projection curve of the floating index and $D$ the discount curve.
+///
+/// By default both are the curve being bootstrapped, and the floating leg is worth
+/// $D(t_s) - D(t_e)$. With [`SwapRateHelper::with_discount_curve`] the discount curve is fixed,
+/// typically an OIS curve, and the bootstrapped curve is the projection curve of the index.
+#[derive(Clone)]
+pub struct SwapRateHelper<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    rate: f64,
+    fixed_schedule: Schedule,
+    fixed_day_counter: DC,
+    floating_schedule: Schedule,
+    discount_curve: Option<&'a dyn YieldTermStructure<C, D>>,
+}
+
+impl<'a, C, D, DC> SwapRateHelper<'a, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    /// Create a new single-curve `SwapRateHelper`.
+    #[must_use]
+    pub fn new(
+        rate: f64,
+        start: DateTime,
+        maturity: DateTime,
+        fixed_frequency: Frequency,
+        fixed_day_counter: DC,
+    ) -> Self {
+        let fixed_schedule = Schedule::new(start, maturity, fixed_frequency);
+        Self {
+            rate,
+            floating_schedule: fixed_schedule.clone(),
+            fixed_schedule,
+            fixed_day_counter,
+            discount_curve: None,
+        }
+    }
+
+    /// Discount on a fixed curve, projecting the floating leg at the given frequency off the
+    /// bootstrapped curve.
+    #[must_use]
+    pub fn with_discount_curve(
+        mut self,
+        discount_curve: &'a dyn YieldTermStructure<C, D>,
+        floating_frequency: Frequency,
+    ) -> Self {
+        let dates = self.fixed_schedule.get_dates();
+        self.floating_schedule =
+            Schedule::new(dates[0], dates[dates.len() - 1], floating_frequency);
+        self.discount_curve = Some(discount_curve);
+        self
+    }
+
+    /// Annuity of the fixed leg, $\sum_i \tau_i D(t_i)$.
+    fn annuity(
+        &self,
+        discount_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        self.fixed_schedule
+            .get_periods()
+            .into_iter()
+            .map(|(start, end)| {
+                let tau = self
+                    .fixed_day_counter
+                    .day_count_fraction(&start, &end)
+                    .get_fraction();
+                Ok(tau * *discount_curve.discount_factor(end)?)
+            })
+            .sum()
+    }
+
+    /// Value of the floating leg per unit notional,
+    /// $\sum_j \left( P(s_j) / P(e_j) - 1 \right) D(e_j)$.
+    fn floating_leg(
+        &self,
+        projection_curve: &dyn YieldTermStructure<C, D>,
+        discount_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        self.floating_schedule
+            .get_periods()
+            .into_iter()
+            .map(|(start, end)| {
+                let growth = *projection_curve.discount_factor(start)?
+                    / *projection_curve.discount_factor(end)?;
+                Ok((growth - 1.0) * *discount_curve.discount_factor(end)?)
+            })
+            .sum()
+    }
+
+    /// Value of the payer swap (paying the quoted fixed rate, receiving floating) with the
+    /// floating leg projected and both legs discounted on the given curves.
+    ///
+    /// # Errors
+    /// Will return a `TermStructureError` if a curve cannot discount to one of the swap dates.
+    pub fn npv(
+        &self,
+        notional: Money<C>,
+        projection_curve: &dyn YieldTermStructure<C, D>,
+        discount_curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<Money<C>, TermStructureError> {
+        let value = self.rate.mul_add(
+            -self.annuity(discount_curve)?,
+            self.floating_leg(projection_curve, discount_curve)?,
+        );
+        Ok(notional * value)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D, DC> RateHelper<C, D> for DepositRateHelper<DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn pillar_date(&self) -> DateTime {
+        self.maturity
+    }
+
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn implied_quote(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        simple_forward_rate(curve, self.start, self.maturity, self.day_counter)
+    }
+}
+
+impl<C, D, DC> RateHelper<C, D> for FraRateHelper<DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn pillar_date(&self) -> DateTime {
+        self.maturity
+    }
+
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn implied_quote(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        simple_forward_rate(curve, self.start, self.maturity, self.day_counter)
+    }
+}
+
+impl<C, D, DC> RateHelper<C, D> for FuturesRateHelper<DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn pillar_date(&self) -> DateTime {
+        self.maturity
+    }
+
+    fn quote(&self) -> f64 {
+        self.price
+    }
+
+    fn bumped_quote(&self, rate_bump: f64) -> f64 {
+        100.0f64.mul_add(-rate_bump, self.price)
+    }
+
+    fn implied_quote(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        let forward = simple_forward_rate(curve, self.start, self.maturity, self.day_counter)?;
+        Ok(100.0 * (1.0 - forward - self.convexity_adjustment))
+    }
+}
+
+impl<C, D, DC> RateHelper<C, D> for SwapRateHelper<'_, C, D, DC>
+where
+    C: Currency,
+    D: DayCounter,
+    DC: DayCounter,
+{
+    fn pillar_date(&self) -> DateTime {
+        *self.fixed_schedule.get_dates().last().unwrap()
+    }
+
+    fn quote(&self) -> f64 {
+        self.rate
+    }
+
+    fn implied_quote(
+        &self,
+        curve: &dyn YieldTermStructure<C, D>,
+    ) -> Result<f64, TermStructureError> {
+        let discount_curve = self.discount_curve.unwrap_or(curve);
+        Ok(self.floating_leg(curve, discount_curve)? / self.annuity(discount_curve)?)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::{Actual360, Actual365Fixed, Thirty360};
+
+    use super::*;
+    use crate::{money::currency::USD, term_structures::flat_curve, Compounding};
+
+    #[test]
+    fn test_rate_helpers_on_flat_curve() {
+        let curve = flat_curve(
+            DateTime::new_from_ymd(2023, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+        // Two days after the reference date.
+        let start = DateTime::new_from_ymd(2023, 1, 3);
+
+        // Simple rate equivalent to 3% continuous over the period.
+        let simple_rate = |t1: DateTime, t2: DateTime, tau: f64| {
+            let time = Actual365Fixed.day_count_fraction(&t1, &t2).get_fraction();
+            ((0.03 * time).exp() - 1.0) / tau
+        };
+
+        let maturity = start.add_months(3);
+        let tau = Actual360
+            .day_count_fraction(&start, &maturity)
+            .get_fraction();
+        let deposit = DepositRateHelper::new(0.0, start, maturity, Actual360);
+        assert_approx_eq!(
+            RateHelper::<USD, _>::implied_quote(&deposit, &curve).unwrap(),
+            simple_rate(start, maturity, tau),
+            10e-12
+        );
+
+        let (fra_start, fra_end) = (start.add_months(6), start.add_months(12));
+        let tau = Actual360
+            .day_count_fraction(&fra_start, &fra_end)
+            .get_fraction();
+        let fra = FraRateHelper::new(0.0, fra_start, fra_end, Actual360);
+        let forward = simple_rate(fra_start, fra_end, tau);
+        assert_approx_eq!(
+            RateHelper::<USD, _>::implied_quote(&fra, &curve).unwrap(),
+            forward,
+            10e-12
+        );
+
+        let adjustment =
+            FuturesRateHelper::<Actual360>::ho_lee_convexity_adjustment(0.01, 0.5, 1.0);
+        assert_approx_eq!(adjustment, 0.000_025, 10e-15);
+        let future = FuturesRateHelper::new(0.0, fra_start, fra_end, Actual360)
+            .with_convexity_adjustment(adjustment);
+        assert_approx_eq!(
+            RateHelper::<USD, _>::implied_quote(&future, &curve).unwrap(),
+            100.0 * (1.0 - forward - adjustment),
+            10e-10
+        );
+
+        // A swap on a flat curve is worth par at the curve rate in its own compounding.
+        let swap = SwapRateHelper::new(
+            0.0,
+            start,
+            start.add_months(60),
+            Frequency::Annual,
+            Thirty360,
+        );
+        let par_rate = RateHelper::<USD, _>::implied_quote(&swap, &curve).unwrap();
+        let annuity: f64 = (1..=5)
+            .map(|year| *curve.discount_factor(start.add_months(12 * year)).unwrap())
+            .sum();
+        assert_approx_eq!(
+            par_rate * annuity,
+            *curve.discount_factor(start).unwrap()
+                - *curve.discount_factor(start.add_months(60)).unwrap(),
+            10e-12
+        );
+        assert_eq!(
+            RateHelper::<USD, Actual365Fixed>::pillar_date(&swap),
+            start.add_months(60)
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::term_structures::yield_structure::parametric_yield_curve::{
+    solve_linear_system, validate_quotes, ParametricYieldModel, YieldCurveFitError,
+};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Smith-Wilson discount function, as used for Solvency II risk-free curves.
+///
+/// The discount function fits the quoted zero-coupon prices $m_j$ at maturities $u_j$ exactly,
+/// and the instantaneous forward rate converges to the ultimate forward rate at a speed set by
+/// $\alpha$:
+/// $$
+///     D(t) = e^{-\omega t} + \sum_j \zeta_j W(t, u_j), \qquad \omega = \ln(1 + \text{UFR}),
+/// $$
+/// with the Wilson function
+/// $$
+///     W(t, u) = e^{-\omega (t + u)} \left( \alpha \min(t, u)
+///         - e^{-\alpha \max(t, u)} \sinh(\alpha \min(t, u)) \right).
+/// $$
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmithWilson {
+    ultimate_forward_rate: f64,
+    alpha: f64,
+    maturities: Vec<f64>,
+    zeta: Vec<f64>,
+}
+
+impl SmithWilson {
+    /// Fit the discount function to `(time, rate)` quotes of continuously-compounded zero rates,
+    /// given the ultimate forward rate (annually compounded, as published by EIOPA) and the
+    /// convergence speed $\alpha > 0$.
+    ///
+    /// # Errors
+    /// Will return `NotEnoughQuotes` if no quotes are provided, `NonPositiveMaturity` or
+    /// `DuplicateMaturity` for invalid maturities, and `NoConvergence` if the linear system for
+    /// the weights $\zeta_j$ cannot be solved.
+    pub fn fit(
+        quotes: &[(f64, f64)],
+        ultimate_forward_rate: f64,
+        alpha: f64,
+    ) -> Result<Self, YieldCurveFitError> {
+        validate_quotes(quotes, 1)?;
+
+        let omega = ultimate_forward_rate.ln_1p();
+        let maturities: Vec<f64> = quotes.iter().map(|quote| quote.0).collect();
+        let matrix: Vec<Vec<f64>> = maturities
+            .iter()
+            .map(|t| {
+                maturities
+                    .iter()
+                    .map(|u| wilson_function(*t, *u, omega, alpha))
+                    .collect()
+            })
+            .collect();
+        let rhs: Vec<f64> = quotes
+            .iter()
+            .map(|(u, rate)| (-rate * u).exp() - (-omega * u).exp())
+            .collect();
+        let zeta = solve_linear_system(matrix, rhs).ok_or(YieldCurveFitError::NoConvergence)?;
+
+        Ok(Self {
+            ultimate_forward_rate,
+            alpha,
+            maturities,
+            zeta,
+        })
+    }
+
+    #[must_use]
+    pub const fn get_ultimate_forward_rate(&self) -> f64 {
+        self.ultimate_forward_rate
+    }
+
+    #[must_use]
+    pub const fn get_alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Continuously-compounded instantaneous forward rate, by central differences.
+    #[must_use]
+    pub fn instantaneous_forward(&self, t: f64) -> f64 {
+        let h = 1e-5_f64.min(0.5 * t).max(1e-8);
+        (self.discount_factor((t - h).max(0.0)).ln() - self.discount_factor(t + h).ln())
+            / (t + h - (t - h).max(0.0))
+    }
+}
+
+fn wilson_function(t: f64, u: f64, omega: f64, alpha: f64) -> f64 {
+    let (low, high) = (t.min(u), t.max(u));
+    (-omega * (t + u)).exp() * alpha.mul_add(low, -(-alpha * high).exp() * (alpha * low).sinh())
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl ParametricYieldModel for SmithWilson {
+    fn discount_factor(&self, t: f64) -> f64 {
+        let omega = self.ultimate_forward_rate.ln_1p();
+        self.maturities
+            .iter()
+            .zip(&self.zeta)
+            .map(|(u, zeta)| zeta * wilson_function(t, *u, omega, self.alpha))
+            .sum::<f64>()
+            + (-omega * t).exp()
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_smith_wilson() {
+        let quotes = [
+            (1.0, 0.030),
+            (2.0, 0.031),
+            (5.0, 0.032),
+            (10.0, 0.031),
+            (20.0, 0.030),
+        ];
+        let model = SmithWilson::fit(&quotes, 0.036, 0.1).unwrap();
+
+        // Exact fit of the liquid part of the curve.
+        for (t, rate) in quotes {
+            assert_approx_eq!(model.zero_rate(t), rate, 10e-12);
+        }
+        assert_approx_eq!(model.discount_factor(0.0), 1.0, 10e-12);
+
+        // Forwards converge to the ultimate forward rate.
+        assert_approx_eq!(model.instantaneous_forward(150.0), 0.036f64.ln_1p(), 10e-5);
+        assert!(
+            (model.instantaneous_forward(60.0) - 0.036f64.ln_1p()).abs()
+                < (model.instantaneous_forward(30.0) - 0.036f64.ln_1p()).abs()
+        );
+
+        assert_eq!(
+            SmithWilson::fit(&[(0.0, 0.03)], 0.036, 0.1),
+            Err(YieldCurveFitError::NonPositiveMaturity)
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use day_count_conventions::DayCounter;
+use ordered_float::OrderedFloat;
+
+use crate::interest_rate::{implied_rate_from_compound_factor, InterestRate};
+use crate::math::interpolation::{InterpolationResult, Interpolator, LinearInterpolator};
+use crate::money::Currency;
+use crate::term_structures::yield_structure::{
+    implied_forward_rate, implied_zero_rate, YieldTermStructure,
+};
+use crate::term_structures::{TermStructure, TermStructureError};
+use crate::time::DateTime;
+use crate::types::DiscountFactor;
+use crate::Compounding;
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Spread over a curve, either parallel or given at dates and interpolated linearly, flat
+/// outside the dates.
+#[derive(Debug, Clone)]
+enum Spread {
+    Parallel(f64),
+    TermStructure(
+        LinearInterpolator<DateTime, OrderedFloat<f64>>,
+        Vec<DateTime>,
+    ),
+}
+
+impl Spread {
+    fn new_term_structure(spreads: &[(DateTime, f64)]) -> Self {
+        if spreads.is_empty() {
+            return Self::Parallel(0.0);
+        }
+        let mut dates: Vec<DateTime> = spreads.iter().map(|spread| spread.0).collect();
+        dates.sort();
+        dates.dedup();
+        Self::TermStructure(
+            LinearInterpolator::new_from_points(
+                spreads
+                    .iter()
+                    .map(|(date, spread)| (*date, OrderedFloat(*spread)))
+                    .collect(),
+            ),
+            dates,
+        )
+    }
+
+    fn value(&self, dt: DateTime) -> f64 {
+        match self {
+            Self::Parallel(spread) => *spread,
+            Self::TermStructure(interpolator, dates) => {
+                match interpolator.interpolate(dt.clamp(dates[0], dates[dates.len() - 1])) {
+                    InterpolationResult::ExistingValue(v)
+                    | InterpolationResult::InterpolatedValue(v) => *v,
+                    _ => f64::NAN,
+                }
+            }
+        }
+    }
+
+    /// $\int_0^t s(u) \, du$, exact for the piecewise linear spread.
+    fn integral(&self, dt: DateTime, time_from_reference: impl Fn(DateTime) -> f64) -> f64 {
+        match self {
+            Self::Parallel(spread) => spread * time_from_reference(dt),
+            Self::TermStructure(_, dates) => {
+                let mut nodes: Vec<DateTime> =
+                    dates.iter().copied().filter(|date| *date < dt).collect();
+                nodes.push(dt);
+                let mut integral = 0.0;
+                let mut start = dates[0].min(dt);
+                integral += self.value(start) * time_from_reference(start);
+                for end in nodes {
+                    if end > start {
+                        integral += 0.5
+                            * (self.value(start) + self.value(end))
+                            * (time_from_reference(end) - time_from_reference(start));
+                        start = end;
+                    }
+                }
+                integral
+            }
+        }
+    }
+}
+
+/// Curve with a spread added to the zero rates of an underlying curve, in a given compounding.
+///
+/// The zero rate $r(t)$ of the underlying curve is expressed in the compounding and day count
+/// convention of the curve, and the discount factor is that of $r(t) + s(t)$.
+pub struct ZeroSpreadedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    curve: &'a dyn YieldTermStructure<C, D>,
+    spread: Spread,
+    compounding: Compounding,
+}
+
+/// Curve with a spread added to the instantaneous forward rates of an underlying curve,
+/// $D(t) = D_0(t) \exp\left(-\int_0^t s(u) \, du\right)$.
+pub struct ForwardSpreadedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    curve: &'a dyn YieldTermStructure<C, D>,
+    spread: Spread,
+}
+
+impl<'a, C, D> ZeroSpreadedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `ZeroSpreadedTermStructure` with a parallel spread.
+    #[must_use]
+    pub const fn new(
+        curve: &'a dyn YieldTermStructure<C, D>,
+        spread: f64,
+        compounding: Compounding,
+    ) -> Self {
+        Self {
+            curve,
+            spread: Spread::Parallel(spread),
+            compounding,
+        }
+    }
+
+    /// Create a new `ZeroSpreadedTermStructure` with spreads at dates, interpolated linearly and
+    /// flat outside the dates. No spreads give a zero spread.
+    #[must_use]
+    pub fn new_with_spreads(
+        curve: &'a dyn YieldTermStructure<C, D>,
+        spreads: &[(DateTime, f64)],
+        compounding: Compounding,
+    ) -> Self {
+        Self {
+            curve,
+            spread: Spread::new_term_structure(spreads),
+            compounding,
+        }
+    }
+
+    /// The spread at a datetime.
+    #[must_use]
+    pub fn get_spread(&self, dt: DateTime) -> f64 {
+        self.spread.value(dt)
+    }
+}
+
+impl<'a, C, D> ForwardSpreadedTermStructure<'a, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    /// Create a new `ForwardSpreadedTermStructure` with a parallel spread.
+    #[must_use]
+    pub const fn new(curve: &'a dyn YieldTermStructure<C, D>, spread: f64) -> Self {
+        Self {
+            curve,
+            spread: Spread::Parallel(spread),
+        }
+    }
+
+    /// Create a new `ForwardSpreadedTermStructure` with spreads at dates, interpolated linearly
+    /// and flat outside the dates. No spreads give a zero spread.
+    #[must_use]
+    pub fn new_with_spreads(
+        curve: &'a dyn YieldTermStructure<C, D>,
+        spreads: &[(DateTime, f64)],
+    ) -> Self {
+        Self {
+            curve,
+            spread: Spread::new_term_structure(spreads),
+        }
+    }
+
+    /// The spread at a datetime.
+    #[must_use]
+    pub fn get_spread(&self, dt: DateTime) -> f64 {
+        self.spread.value(dt)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl<C, D> TermStructure<D> for ZeroSpreadedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.curve.get_reference_date()
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.curve.get_max_datetime()
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        self.curve.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.curve.get_day_counter()
+    }
+}
+
+impl<C, D> YieldTermStructure<C, D> for ZeroSpreadedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        let discount_factor = self.curve.discount_factor(t)?;
+        let day_counter = self.get_day_counter();
+        let day_count_fraction = day_counter.day_count_fraction(&self.get_reference_date(), &t);
+        if day_count_fraction.get_fraction() == 0.0 {
+            return Ok(discount_factor);
+        }
+
+        let rate = implied_rate_from_compound_factor::<C, D>(
+            OrderedFloat(1.0) / discount_factor,
+            &day_count_fraction,
+            day_counter,
+            self.compounding,
+        )
+        .ok_or(TermStructureError::InvalidDateTime)?;
+        let spreaded: InterestRate<C, D> = InterestRate::new(
+            *rate.get_rate() + self.spread.value(t),
+            day_counter,
+            self.compounding,
+        );
+        Ok(spreaded.discount_factor(&day_count_fraction))
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+impl<C, D> TermStructure<D> for ForwardSpreadedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn get_reference_date(&self) -> DateTime {
+        self.curve.get_reference_date()
+    }
+
+    fn get_max_datetime(&self) -> DateTime {
+        self.curve.get_max_datetime()
+    }
+
+    fn is_datetime_valid(&self, dt: DateTime) -> bool {
+        self.curve.is_datetime_valid(dt)
+    }
+
+    fn get_day_counter(&self) -> D {
+        self.curve.get_day_counter()
+    }
+}
+
+impl<C, D> YieldTermStructure<C, D> for ForwardSpreadedTermStructure<'_, C, D>
+where
+    C: Currency,
+    D: DayCounter,
+{
+    fn discount_factor(&self, t: DateTime) -> Result<DiscountFactor, TermStructureError> {
+        let discount_factor = self.curve.discount_factor(t)?;
+        let reference_date = self.get_reference_date();
+        let day_counter = self.get_day_counter();
+        let integral = self.spread.integral(t, |dt| {
+            day_counter
+                .day_count_fraction(&reference_date, &dt)
+                .get_fraction()
+        });
+        Ok(discount_factor * (-integral).exp())
+    }
+
+    fn zero_rate(&self, t: DateTime) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_zero_rate(self, t, Compounding::Continuous)
+    }
+
+    fn forward_rate(
+        &self,
+        t1: DateTime,
+        t2: DateTime,
+    ) -> Result<InterestRate<C, D>, TermStructureError> {
+        implied_forward_rate(self, t1, t2, Compounding::Continuous)
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use day_count_conventions::Actual365Fixed;
+
+    use super::*;
+    use crate::{money::currency::USD, term_structures::flat_curve, time::Frequency};
+
+    #[test]
+    fn test_zero_spreaded_term_structure() {
+        let annual = Compounding::Compounding(Frequency::Annual);
+        let curve =
+            flat_curve::<USD, Actual365Fixed>(DateTime::new_from_ymd(2024, 1, 1), 0.03, annual);
+        let date = DateTime::new_from_ymd(2029, 1, 1);
+
+        // A parallel spread in the curve's own compounding gives the flat curve at the sum.
+        let spreaded = ZeroSpreadedTermStructure::new(&curve, 0.01, annual);
+        assert_approx_eq!(
+            *spreaded.discount_factor(date).unwrap(),
+            *flat_curve::<USD, Actual365Fixed>(DateTime::new_from_ymd(2024, 1, 1), 0.04, annual)
+                .discount_factor(date)
+                .unwrap(),
+            10e-12
+        );
+
+        // A term spread is interpolated linearly, and flat outside its dates.
+        let spreaded = ZeroSpreadedTermStructure::new_with_spreads(
+            &curve,
+            &[
+                (DateTime::new_from_ymd(2025, 1, 1), 0.01),
+                (DateTime::new_from_ymd(2027, 1, 1), 0.02),
+            ],
+            Compounding::Continuous,
+        );
+        assert_approx_eq!(
+            spreaded.get_spread(DateTime::new_from_ymd(2024, 6, 1)),
+            0.01
+        );
+        assert_approx_eq!(
+            spreaded.get_spread(DateTime::new_from_ymd(2026, 1, 1)),
+            0.015
+        );
+        assert_approx_eq!(spreaded.get_spread(date), 0.02);
+        assert_approx_eq!(
+            *spreaded.zero_rate(date).unwrap().get_rate(),
+            0.03f64.ln_1p() + 0.02,
+            10e-10
+        );
+    }
+
+    #[test]
+    fn test_forward_spreaded_term_structure() {
+        let curve = flat_curve::<USD, Actual365Fixed>(
+            DateTime::new_from_ymd(2024, 1, 1),
+            0.03,
+            Compounding::Continuous,
+        );
+        let (start, end) = (
+            DateTime::new_from_ymd(2026, 1, 1),
+            DateTime::new_from_ymd(2028, 1, 1),
+        );
+
+        let spreaded = ForwardSpreadedTermStructure::new(&curve, 0.005);
+        assert_approx_eq!(
+            *spreaded.forward_rate(start, end).unwrap().get_rate(),
+            0.035,
+            10e-10
+        );
+
+        // With a linear spread the forward over a period is the base forward plus the average
+        // spread over the period.
+        let spreaded = ForwardSpreadedTermStructure::new_with_spreads(
+            &curve,
+            &[
+                (DateTime::new_from_ymd(2025, 1, 1), 0.0),
+                (DateTime::new_from_ymd(2029, 1, 1), 0.01),
+            ],
+        );
+        let average = 0.5 * (spreaded.get_spread(start) + spreaded.get_spread(end));
+        assert_approx_eq!(
+            *spreaded.forward_rate(start, end).unwrap().get_rate(),
+            0.03 + average,
+            10e-10
+        );
+        assert_approx_eq!(
+            *spreaded
+                .forward_rate(
+                    DateTime::new_from_ymd(2024, 1, 2),
+                    DateTime::new_from_ymd(2024, 12, 1)
+                )
+                .unwrap()
+                .get_rate(),
+            0.03,
+            10e-10
+        );
+    }
+}
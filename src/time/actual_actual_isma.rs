@@ -0,0 +1,169 @@
+use std::fmt::Display;
+
+use day_count_conventions::{DayCountFraction, DayCounter};
+use hifitime::{Epoch, Unit};
+
+use super::DateTime;
+
+/// Actual/Actual (ISMA), also known as Actual/Actual (ICMA), the convention of most government
+/// and corporate bonds outside of the US money markets.
+///
+/// The year fraction of a period within a regular coupon period, the reference period, of
+/// $n$ months is
+/// $$
+///     \frac{n}{12} \frac{d_2 - d_1}{D}
+/// $$
+/// where $d_2 - d_1$ is the number of days in the period and $D$ the number of days in the
+/// reference period. Irregular periods are split over the regular periods before or after the
+/// reference period, so that a long first coupon is counted on the notional period preceding the
+/// reference period.
+///
+/// Without a reference period, as through [`DayCounter`], reference periods are the years
+/// starting on the first date.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ActualActualIsma;
+
+impl ActualActualIsma {
+    /// Year fraction from `start` to `end` in the reference period `ref_start` to `ref_end`.
+    #[must_use]
+    pub fn day_count_fraction_in_period(
+        &self,
+        start: DateTime,
+        end: DateTime,
+        ref_start: DateTime,
+        ref_end: DateTime,
+    ) -> DayCountFraction<Self> {
+        DayCountFraction::new(year_fraction(start, end, ref_start, ref_end))
+    }
+}
+
+/// Whole days between two dates, which leap seconds would otherwise offset.
+fn days(start: DateTime, end: DateTime) -> f64 {
+    (*end - *start).to_unit(Unit::Day).round()
+}
+
+fn year_fraction(start: DateTime, end: DateTime, ref_start: DateTime, ref_end: DateTime) -> f64 {
+    if end < start {
+        return -year_fraction(end, start, ref_start, ref_end);
+    }
+    if end == start {
+        return 0.0;
+    }
+
+    // Length of the reference period in whole months.
+    #[allow(clippy::cast_possible_truncation)]
+    let months = ((12.0 * days(ref_start, ref_end) / 365.0).round() as i32).max(1);
+
+    if start < ref_start {
+        // Count the part before the reference period on the preceding regular period.
+        let previous = ref_start.add_months(-months);
+        if end <= ref_start {
+            return year_fraction(start, end, previous, ref_start);
+        }
+        return year_fraction(start, ref_start, previous, ref_start)
+            + year_fraction(ref_start, end, ref_start, ref_end);
+    }
+    if start >= ref_end {
+        return year_fraction(start, end, ref_end, ref_end.add_months(months));
+    }
+
+    let period = f64::from(months) / 12.0;
+    if end <= ref_end {
+        period * days(start, end) / days(ref_start, ref_end)
+    } else {
+        // Count the part after the reference period on the following regular periods.
+        period * days(start, ref_end) / days(ref_start, ref_end)
+            + year_fraction(ref_end, end, ref_end, ref_end.add_months(months))
+    }
+}
+
+impl DayCounter for ActualActualIsma {
+    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
+        let start = DateTime::new_from_epoch(start);
+        self.day_count_fraction_in_period(
+            start,
+            DateTime::new_from_epoch(end),
+            start,
+            start.add_months(12),
+        )
+    }
+}
+
+impl Display for ActualActualIsma {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/Actual (ISMA)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_actual_actual_isma() {
+        let date = DateTime::new_from_ymd;
+        let fraction = |start, end, ref_start, ref_end| {
+            ActualActualIsma
+                .day_count_fraction_in_period(start, end, ref_start, ref_end)
+                .get_fraction()
+        };
+
+        // Regular and short first periods, from the ISDA examples.
+        assert_approx_eq!(
+            fraction(
+                date(1999, 7, 30),
+                date(2000, 1, 30),
+                date(1999, 7, 30),
+                date(2000, 1, 30)
+            ),
+            0.5,
+            10e-15
+        );
+        assert_approx_eq!(
+            fraction(
+                date(1999, 2, 1),
+                date(1999, 7, 1),
+                date(1998, 7, 1),
+                date(1999, 7, 1)
+            ),
+            150.0 / 365.0,
+            10e-15
+        );
+
+        // Long first period: 153 days of the 184 day notional period before the first regular
+        // semi-annual period.
+        assert_approx_eq!(
+            fraction(
+                date(2002, 8, 15),
+                date(2003, 7, 15),
+                date(2003, 1, 15),
+                date(2003, 7, 15)
+            ),
+            0.5f64.mul_add(153.0 / 184.0, 0.5),
+            10e-15
+        );
+
+        // Long last period: 62 days of the 184 day notional period after the last regular one.
+        assert_approx_eq!(
+            fraction(
+                date(2023, 1, 15),
+                date(2023, 9, 15),
+                date(2023, 1, 15),
+                date(2023, 7, 15)
+            ),
+            0.5f64.mul_add(62.0 / 184.0, 0.5),
+            10e-15
+        );
+
+        // Without a reference period, on years from the first date.
+        assert_approx_eq!(
+            ActualActualIsma
+                .day_count_fraction(&date(2024, 1, 15), &date(2024, 7, 15))
+                .get_fraction(),
+            182.0 / 366.0,
+            10e-15
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use hifitime::Weekday;
+
+use super::{DateTime, Duration};
+
+//  ------------------------------------------------------------------------------------------------
+//  Definition
+//  ------------------------------------------------------------------------------------------------
+
+/// Rule for moving a date that is not a business day onto one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BusinessDayConvention {
+    /// Keep the date as it is.
+    Unadjusted,
+
+    /// Move to the next business day.
+    Following,
+
+    /// Move to the next business day, unless it is in the next month, in which case move to the
+    /// previous business day.
+    ModifiedFollowing,
+
+    /// Move to the previous business day.
+    Preceding,
+
+    /// Move to the previous business day, unless it is in the previous month, in which case move
+    /// to the next business day.
+    ModifiedPreceding,
+}
+
+/// Calendar of business days, used for fixing, settlement and payment dates.
+pub trait Calendar {
+    /// The name of the calendar.
+    fn name(&self) -> &'static str;
+
+    /// Whether the date is a holiday that does not fall on a weekend.
+    fn is_holiday(&self, date: DateTime) -> bool;
+
+    /// Whether the date falls on a weekend, Saturday or Sunday by default.
+    fn is_weekend(&self, date: DateTime) -> bool {
+        matches!(date.weekday_utc(), Weekday::Saturday | Weekday::Sunday)
+    }
+
+    fn is_business_day(&self, date: DateTime) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Move a date onto a business day with a business day convention.
+    fn adjust(&self, date: DateTime, convention: BusinessDayConvention) -> DateTime {
+        let roll = |step: i32| {
+            let mut adjusted = date;
+            while !self.is_business_day(adjusted) {
+                adjusted = add_days(adjusted, step);
+            }
+            adjusted
+        };
+        match convention {
+            BusinessDayConvention::Unadjusted => date,
+            BusinessDayConvention::Following => roll(1),
+            BusinessDayConvention::Preceding => roll(-1),
+            BusinessDayConvention::ModifiedFollowing => {
+                let adjusted = roll(1);
+                if ymd(adjusted).1 == ymd(date).1 {
+                    adjusted
+                } else {
+                    roll(-1)
+                }
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let adjusted = roll(-1);
+                if ymd(adjusted).1 == ymd(date).1 {
+                    adjusted
+                } else {
+                    roll(1)
+                }
+            }
+        }
+    }
+
+    /// Move a date by a number of business days, which may be negative.
+    ///
+    /// Moving by zero business days rolls the date forward to a business day.
+    fn advance(&self, date: DateTime, business_days: i32) -> DateTime {
+        let mut advanced = self.adjust(date, BusinessDayConvention::Following);
+        let step = business_days.signum();
+        for _ in 0..business_days.abs() {
+            advanced = add_days(advanced, step);
+            while !self.is_business_day(advanced) {
+                advanced = add_days(advanced, step);
+            }
+        }
+        advanced
+    }
+
+    /// The number of business days from `start` included to `end` excluded.
+    fn business_days_between(&self, start: DateTime, end: DateTime) -> i32 {
+        let mut count = 0;
+        let mut date = start;
+        while date < end {
+            if self.is_business_day(date) {
+                count += 1;
+            }
+            date = add_days(date, 1);
+        }
+        count
+    }
+}
+
+/// Calendar whose only non-business days are weekends.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct WeekendsOnly;
+
+/// TARGET calendar of the Eurosystem, used for euro fixings and settlement: New Year's Day, Good
+/// Friday, Easter Monday, Labour Day and the 25th and 26th of December.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Target;
+
+/// Federal Reserve calendar, used for SOFR and US dollar settlement.
+///
+/// Holidays falling on a Sunday are observed on the following Monday, and holidays falling on a
+/// Saturday are not observed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitedStates;
+
+/// Bank holidays of England and Wales, used for SONIA and sterling settlement.
+///
+/// One-off holidays, such as those of coronations and jubilees, are not included.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitedKingdom;
+
+/// Japanese national and bank holidays, used for TONA and yen settlement.
+///
+/// National holidays falling on a Sunday are observed on the following Monday. The equinoxes are
+/// approximated by their usual formula, and one-off holidays are not included.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Japan;
+
+/// The year, month and day of a date.
+fn ymd(date: DateTime) -> (i32, u8, u8) {
+    let (year, month, day, ..) = date.to_gregorian_utc();
+    (year, month, day)
+}
+
+fn add_days(date: DateTime, days: i32) -> DateTime {
+    date + Duration::new_from_days(f64::from(days))
+}
+
+/// Whether the day of the month is the `n`th occurrence of its weekday in the month.
+const fn is_nth_weekday(day: u8, n: u8) -> bool {
+    (day - 1) / 7 + 1 == n
+}
+
+/// Whether the day of the month is the last occurrence of its weekday in a month of 31 days.
+const fn is_last_weekday_of_long_month(day: u8) -> bool {
+    day >= 25
+}
+
+/// Easter Sunday of a year in the Gregorian calendar (anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> DateTime {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    DateTime::new_from_ymd(year, month as u8, day as u8)
+}
+
+/// Whether the date is Easter Sunday shifted by a number of days.
+fn is_easter_offset(date: DateTime, days: i32) -> bool {
+    let (year, ..) = ymd(date);
+    ymd(add_days(easter_sunday(year), days)) == ymd(date)
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Trait implementations
+//  ------------------------------------------------------------------------------------------------
+
+impl Calendar for WeekendsOnly {
+    fn name(&self) -> &'static str {
+        "Weekends only"
+    }
+
+    fn is_holiday(&self, _date: DateTime) -> bool {
+        false
+    }
+}
+
+impl Calendar for Target {
+    fn name(&self) -> &'static str {
+        "TARGET"
+    }
+
+    fn is_holiday(&self, date: DateTime) -> bool {
+        let (_, month, day) = ymd(date);
+        matches!((month, day), (1, 1) | (5, 1) | (12, 25 | 26))
+            || is_easter_offset(date, -2)
+            || is_easter_offset(date, 1)
+    }
+}
+
+impl Calendar for UnitedStates {
+    fn name(&self) -> &'static str {
+        "United States (Federal Reserve)"
+    }
+
+    fn is_holiday(&self, date: DateTime) -> bool {
+        let (year, month, day) = ymd(date);
+        let weekday = date.weekday_utc();
+        let monday = weekday == Weekday::Monday;
+        // A fixed-date holiday, or its observance on the Monday after a Sunday.
+        let observed = |holiday: u8| day == holiday || (monday && day == holiday + 1);
+
+        match month {
+            // New Year's Day, Martin Luther King Jr. Day.
+            1 => observed(1) || (monday && is_nth_weekday(day, 3)),
+            // Washington's Birthday.
+            2 => monday && is_nth_weekday(day, 3),
+            // Memorial Day.
+            5 => monday && is_last_weekday_of_long_month(day),
+            // Juneteenth.
+            6 => year >= 2022 && observed(19),
+            // Independence Day.
+            7 => observed(4),
+            // Labor Day.
+            9 => monday && is_nth_weekday(day, 1),
+            // Columbus Day.
+            10 => monday && is_nth_weekday(day, 2),
+            // Veterans Day, Thanksgiving Day.
+            11 => observed(11) || (weekday == Weekday::Thursday && is_nth_weekday(day, 4)),
+            // Christmas Day.
+            12 => observed(25),
+            _ => false,
+        }
+    }
+}
+
+impl Calendar for UnitedKingdom {
+    fn name(&self) -> &'static str {
+        "United Kingdom"
+    }
+
+    fn is_holiday(&self, date: DateTime) -> bool {
+        let (_, month, day) = ymd(date);
+        let weekday = date.weekday_utc();
+        let monday = weekday == Weekday::Monday;
+        let monday_or_tuesday = monday || weekday == Weekday::Tuesday;
+
+        let holiday = match month {
+            // New Year's Day, moved to Monday if on a weekend.
+            1 => day == 1 || (monday && (day == 2 || day == 3)),
+            // Early May and Spring bank holidays.
+            5 => monday && (is_nth_weekday(day, 1) || is_last_weekday_of_long_month(day)),
+            // Summer bank holiday.
+            8 => monday && is_last_weekday_of_long_month(day),
+            // Christmas Day and Boxing Day, moved past the weekend.
+            12 => day == 25 || day == 26 || (monday_or_tuesday && (day == 27 || day == 28)),
+            _ => false,
+        };
+        // Good Friday, Easter Monday.
+        holiday || is_easter_offset(date, -2) || is_easter_offset(date, 1)
+    }
+}
+
+impl Japan {
+    /// Whether the date is a national or bank holiday, before substitution.
+    fn is_national_holiday(date: DateTime) -> bool {
+        let (year, month, day) = ymd(date);
+        let monday = date.weekday_utc() == Weekday::Monday;
+
+        // Approximation of the equinoxes, valid from 1980 to 2099.
+        let equinox = |base: f64| {
+            let years = year - 1980;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let day =
+                (0.242_194f64.mul_add(f64::from(years), base) - f64::from(years / 4)).floor() as u8;
+            day
+        };
+
+        match month {
+            // New Year bank holidays, Coming of Age Day.
+            1 => day <= 3 || (monday && is_nth_weekday(day, 2)),
+            // National Foundation Day, Emperor's Birthday.
+            2 => day == 11 || (year >= 2020 && day == 23),
+            // Vernal Equinox Day.
+            3 => day == equinox(20.8431),
+            // Showa Day.
+            4 => day == 29,
+            // Constitution Memorial Day, Greenery Day, Children's Day.
+            5 => (3..=5).contains(&day),
+            // Marine Day.
+            7 => monday && is_nth_weekday(day, 3),
+            // Mountain Day.
+            8 => year >= 2016 && day == 11,
+            // Respect for the Aged Day, Autumnal Equinox Day.
+            9 => (monday && is_nth_weekday(day, 3)) || day == equinox(23.2488),
+            // Sports Day.
+            10 => monday && is_nth_weekday(day, 2),
+            // Culture Day, Labour Thanksgiving Day.
+            11 => day == 3 || day == 23,
+            // Bank holiday.
+            12 => day == 31,
+            _ => false,
+        }
+    }
+}
+
+impl Calendar for Japan {
+    fn name(&self) -> &'static str {
+        "Japan"
+    }
+
+    fn is_holiday(&self, date: DateTime) -> bool {
+        let (_, month, day) = ymd(date);
+        let weekday = date.weekday_utc();
+
+        Self::is_national_holiday(date)
+            // Substitute holiday for a national holiday on a Sunday.
+            || (weekday == Weekday::Monday && Self::is_national_holiday(add_days(date, -1)))
+            // Substitute holiday when Constitution Memorial Day or Greenery Day is a Sunday.
+            || (month == 5
+                && day == 6
+                && matches!(weekday, Weekday::Tuesday | Weekday::Wednesday))
+    }
+}
+
+//  ------------------------------------------------------------------------------------------------
+//  Tests
+//  ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holidays(calendar: &dyn Calendar, year: i32) -> Vec<(u8, u8)> {
+        let mut date = DateTime::new_from_ymd(year, 1, 1);
+        let mut holidays = vec![];
+        while ymd(date).0 == year {
+            if !calendar.is_weekend(date) && calendar.is_holiday(date) {
+                let (_, month, day) = ymd(date);
+                holidays.push((month, day));
+            }
+            date = add_days(date, 1);
+        }
+        holidays
+    }
+
+    #[test]
+    fn test_holidays() {
+        assert_eq!(
+            holidays(&Target, 2024),
+            vec![(1, 1), (3, 29), (4, 1), (5, 1), (12, 25), (12, 26)]
+        );
+        assert_eq!(
+            holidays(&UnitedStates, 2023),
+            vec![
+                (1, 2),
+                (1, 16),
+                (2, 20),
+                (5, 29),
+                (6, 19),
+                (7, 4),
+                (9, 4),
+                (10, 9),
+                (11, 23),
+                (12, 25)
+            ]
+        );
+        assert_eq!(
+            holidays(&UnitedKingdom, 2021),
+            vec![
+                (1, 1),
+                (4, 2),
+                (4, 5),
+                (5, 3),
+                (5, 31),
+                (8, 30),
+                (12, 27),
+                (12, 28)
+            ]
+        );
+        assert_eq!(
+            holidays(&Japan, 2024),
+            vec![
+                (1, 1),
+                (1, 2),
+                (1, 3),
+                (1, 8),
+                (2, 12),
+                (2, 23),
+                (3, 20),
+                (4, 29),
+                (5, 3),
+                (5, 6),
+                (7, 15),
+                (8, 12),
+                (9, 16),
+                (9, 23),
+                (10, 14),
+                (11, 4),
+                (12, 31)
+            ]
+        );
+        assert!(holidays(&WeekendsOnly, 2024).is_empty());
+    }
+
+    #[test]
+    fn test_adjust_and_advance() {
+        let calendar = Target;
+        // Good Friday and Easter Monday 2024 surround a weekend.
+        let good_friday = DateTime::new_from_ymd(2024, 3, 29);
+        let thursday = DateTime::new_from_ymd(2024, 3, 28);
+        let tuesday = DateTime::new_from_ymd(2024, 4, 2);
+
+        assert!(!calendar.is_business_day(good_friday));
+        assert_eq!(
+            calendar.adjust(good_friday, BusinessDayConvention::Following),
+            tuesday
+        );
+        assert_eq!(
+            calendar.adjust(good_friday, BusinessDayConvention::Preceding),
+            thursday
+        );
+        assert_eq!(
+            calendar.adjust(good_friday, BusinessDayConvention::Unadjusted),
+            good_friday
+        );
+        assert_eq!(calendar.advance(thursday, 1), tuesday);
+        assert_eq!(calendar.advance(tuesday, -1), thursday);
+        assert_eq!(calendar.advance(good_friday, 0), tuesday);
+        assert_eq!(calendar.business_days_between(thursday, tuesday), 1);
+
+        // Modified conventions stay within the month.
+        let saturday = DateTime::new_from_ymd(2024, 8, 31);
+        assert_eq!(
+            calendar.adjust(saturday, BusinessDayConvention::ModifiedFollowing),
+            DateTime::new_from_ymd(2024, 8, 30)
+        );
+        let sunday = DateTime::new_from_ymd(2024, 9, 1);
+        assert_eq!(
+            calendar.adjust(sunday, BusinessDayConvention::ModifiedPreceding),
+            DateTime::new_from_ymd(2024, 9, 2)
+        );
+    }
+}
@@ -63,6 +63,38 @@ impl DateTime {
     pub fn format_ymd(&self) -> String {
         Formatter::new(**self, format_ymd()).to_string()
     }
+
+    /// Add a number of calendar months, which may be negative.
+    ///
+    /// The day of the month is kept, or moved back to the last day of the month if the resulting
+    /// month is shorter (e.g. January 31st plus one month is February 28th or 29th).
+    #[must_use]
+    pub fn add_months(&self, months: i32) -> Self {
+        let (year, month, day, hour, minute, second, nanos) = self.0.to_gregorian_utc();
+
+        let index = year * 12 + i32::from(month) - 1 + months;
+        let year = index.div_euclid(12);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let month = (index.rem_euclid(12) + 1) as u8;
+
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+
+        Self(Epoch::from_gregorian_utc(
+            year,
+            month,
+            day.min(days_in_month),
+            hour,
+            minute,
+            second,
+            nanos,
+        ))
+    }
 }
 
 /// Create a format for formating datetimes.
@@ -206,4 +238,18 @@ mod test {
 
         *dt = *DateTime::now();
     }
+
+    #[test]
+    fn test_add_months() {
+        let dt = DateTime::new_from_ymd(2024, 1, 31);
+
+        assert_eq!(dt.add_months(1), DateTime::new_from_ymd(2024, 2, 29));
+        assert_eq!(dt.add_months(13), DateTime::new_from_ymd(2025, 2, 28));
+        assert_eq!(dt.add_months(-2), DateTime::new_from_ymd(2023, 11, 30));
+        assert_eq!(dt.add_months(-13), DateTime::new_from_ymd(2022, 12, 31));
+        assert_eq!(
+            DateTime::new_from_ymd_hms(2024, 3, 15, 12, 30, 0).add_months(6),
+            DateTime::new_from_ymd_hms(2024, 9, 15, 12, 30, 0)
+        );
+    }
 }
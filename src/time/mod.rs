@@ -1,3 +1,6 @@
+pub mod actual_actual_isma;
+pub use actual_actual_isma::ActualActualIsma;
+
 pub mod frequency;
 pub use frequency::Frequency;
 
@@ -7,3 +10,14 @@ pub use datetime::DateTime;
 
 pub mod duration;
 pub use duration::Duration;
+
+pub mod schedule;
+pub use schedule::Schedule;
+
+pub mod tenor;
+pub use tenor::Tenor;
+
+pub mod calendar;
+pub use calendar::{
+    BusinessDayConvention, Calendar, Japan, Target, UnitedKingdom, UnitedStates, WeekendsOnly,
+};
@@ -0,0 +1,105 @@
+use crate::time::{DateTime, Duration, Frequency};
+
+/// Schedule of regular payment dates between two dates.
+///
+/// Dates are generated backwards from the end date in steps of the frequency, so that an
+/// irregular period, if any, is a short first period (front stub). No business-day adjustment is
+/// applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    dates: Vec<DateTime>,
+}
+
+impl Schedule {
+    /// Create a new `Schedule` from `start` to `end`, both included.
+    ///
+    /// A frequency of [`Frequency::Once`] gives a single period.
+    #[must_use]
+    pub fn new(start: DateTime, end: DateTime, frequency: Frequency) -> Self {
+        let step = |n: i32| match frequency {
+            Frequency::Once => start,
+            Frequency::Annual
+            | Frequency::Semiannual
+            | Frequency::EveryFourthMonth
+            | Frequency::Quarterly
+            | Frequency::Bimonthly
+            | Frequency::Monthly => end.add_months(-n * 12 / frequency as i32),
+            Frequency::EveryFourthWeek => end + Duration::new_from_days(-28.0 * f64::from(n)),
+            Frequency::Biweekly => end + Duration::new_from_days(-14.0 * f64::from(n)),
+            Frequency::Weekly => end + Duration::new_from_days(-7.0 * f64::from(n)),
+            Frequency::Daily => end + Duration::new_from_days(-f64::from(n)),
+        };
+
+        let mut dates = vec![end];
+        let mut n = 1;
+        loop {
+            let date = step(n);
+            if date <= start {
+                break;
+            }
+            dates.push(date);
+            n += 1;
+        }
+        if start < end {
+            dates.push(start);
+        }
+        dates.reverse();
+
+        Self { dates }
+    }
+
+    /// The dates of the schedule, from the start date to the end date.
+    #[must_use]
+    pub fn get_dates(&self) -> &[DateTime] {
+        &self.dates
+    }
+
+    /// The `(start, end)` pairs of consecutive dates.
+    #[must_use]
+    pub fn get_periods(&self) -> Vec<(DateTime, DateTime)> {
+        self.dates
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule() {
+        let start = DateTime::new_from_ymd(2024, 3, 15);
+
+        let schedule = Schedule::new(
+            start,
+            DateTime::new_from_ymd(2025, 9, 15),
+            Frequency::Semiannual,
+        );
+        assert_eq!(
+            schedule.get_dates(),
+            [
+                start,
+                DateTime::new_from_ymd(2024, 9, 15),
+                DateTime::new_from_ymd(2025, 3, 15),
+                DateTime::new_from_ymd(2025, 9, 15),
+            ]
+        );
+
+        // A short front stub.
+        let schedule = Schedule::new(
+            start,
+            DateTime::new_from_ymd(2025, 1, 1),
+            Frequency::Quarterly,
+        );
+        assert_eq!(
+            schedule.get_periods()[0],
+            (start, DateTime::new_from_ymd(2024, 4, 1))
+        );
+        assert_eq!(schedule.get_periods().len(), 4);
+
+        let schedule = Schedule::new(start, DateTime::new_from_ymd(2025, 1, 1), Frequency::Once);
+        assert_eq!(schedule.get_dates().len(), 2);
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use super::{DateTime, Duration};
+
+/// Length of time of an index or instrument, such as `1D` or `3M`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tenor {
+    Days(i32),
+    Weeks(i32),
+    Months(i32),
+    Years(i32),
+}
+
+impl Tenor {
+    /// The date a tenor after `date`, in calendar time and without business day adjustment.
+    #[must_use]
+    pub fn add_to(&self, date: DateTime) -> DateTime {
+        match *self {
+            Self::Days(days) => date + Duration::new_from_days(f64::from(days)),
+            Self::Weeks(weeks) => date + Duration::new_from_days(7.0 * f64::from(weeks)),
+            Self::Months(months) => date.add_months(months),
+            Self::Years(years) => date.add_months(12 * years),
+        }
+    }
+}
+
+impl Display for Tenor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Days(n) => write!(f, "{n}D"),
+            Self::Weeks(n) => write!(f, "{n}W"),
+            Self::Months(n) => write!(f, "{n}M"),
+            Self::Years(n) => write!(f, "{n}Y"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tenor() {
+        let date = DateTime::new_from_ymd(2024, 1, 31);
+        assert_eq!(
+            Tenor::Days(1).add_to(date),
+            DateTime::new_from_ymd(2024, 2, 1)
+        );
+        assert_eq!(
+            Tenor::Weeks(2).add_to(date),
+            DateTime::new_from_ymd(2024, 2, 14)
+        );
+        assert_eq!(
+            Tenor::Months(1).add_to(date),
+            DateTime::new_from_ymd(2024, 2, 29)
+        );
+        assert_eq!(
+            Tenor::Years(1).add_to(date),
+            DateTime::new_from_ymd(2025, 1, 31)
+        );
+        assert_eq!(Tenor::Months(3).to_string(), "3M");
+        assert_eq!(Tenor::Days(1).to_string(), "1D");
+    }
+}